@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+
+/// Splits a vector into batches whose total weight does not exceed `max_weight`.
+/// Items are added to the current batch in order until the next one would go over
+/// the budget. An item heavier than `max_weight` on its own is placed in a batch by itself.
+///
+/// ```
+/// let payloads = vec!["aaaa", "bb", "ccc", "d", "eeeeee"];
+/// let result = reddish::chunk_by_weight(&payloads, 5, |s| s.len());
+/// assert_eq!(result, vec![vec!["aaaa"], vec!["bb", "ccc"], vec!["d"], vec!["eeeeee"]]);
+/// ```
+///
+/// ```
+/// let sizes = vec![1, 1, 1, 1];
+/// let result = reddish::chunk_by_weight(&sizes, 2, |&n| n);
+/// assert_eq!(result, vec![vec![1, 1], vec![1, 1]]);
+/// ```
+pub fn chunk_by_weight<T, F>(vec: &[T], max_weight: usize, weight_fn: F) -> Vec<Vec<T>>
+where
+    T: Clone,
+    F: Fn(&T) -> usize,
+{
+    let mut result = Vec::new();
+    let mut current = Vec::new();
+    let mut current_weight = 0usize;
+
+    for item in vec {
+        let weight = weight_fn(item);
+
+        if !current.is_empty() && current_weight.saturating_add(weight) > max_weight {
+            result.push(std::mem::take(&mut current));
+            current_weight = 0;
+        }
+
+        current.push(item.clone());
+        current_weight = current_weight.saturating_add(weight);
+    }
+
+    if !current.is_empty() {
+        result.push(current);
+    }
+
+    result
+}
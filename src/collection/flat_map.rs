@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+
+/// Maps each element to an iterable and flattens the results into a single vector.
+///
+/// ```
+/// let numbers = vec![1, 2, 3];
+/// let result = reddish::flat_map(&numbers, |&n| vec![n, n * 10]);
+/// assert_eq!(result, vec![1, 10, 2, 20, 3, 30]);
+/// ```
+///
+/// ```
+/// let sentences = vec!["hello world", "foo"];
+/// let result = reddish::flat_map(&sentences, |s| s.split(' '));
+/// assert_eq!(result, vec!["hello", "world", "foo"]);
+/// ```
+pub fn flat_map<T, U, I, F>(vec: &[T], f: F) -> Vec<U>
+where
+    I: IntoIterator<Item = U>,
+    F: Fn(&T) -> I,
+{
+    vec.iter().flat_map(f).collect()
+}
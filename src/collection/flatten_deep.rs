@@ -0,0 +1,205 @@
+#![allow(dead_code)]
+
+use super::Nested;
+
+/// Types that can be recursively flattened into a sequence of leaf items.
+///
+/// Implemented for primitives, strings, [`Nested`], and any `Vec`, slice, array,
+/// `Option` or `Box` of flattenable values, so nesting can go arbitrarily deep.
+/// An `Option` is a level holding zero or one values, so `None` contributes no items.
+/// Implement it for your own types to treat them as leaves.
+///
+/// ```
+/// use reddish::{FlattenDeep, Nested};
+///
+/// let mut out = Vec::new();
+/// vec![vec![Some(1), None], vec![Some(2)]].flatten_deep_into(&mut out);
+/// assert_eq!(out, vec![1, 2]);
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Point(i32, i32);
+///
+/// impl FlattenDeep for Point {
+///     type Item = Point;
+///
+///     fn flatten_deep_into(&self, out: &mut Vec<Point>) {
+///         out.push(self.clone());
+///     }
+///
+///     fn to_nested(&self) -> Nested<Point> {
+///         Nested::Value(self.clone())
+///     }
+/// }
+///
+/// assert_eq!(reddish::flatten_deep(vec![vec![Point(0, 1)], vec![]]), vec![Point(0, 1)]);
+/// ```
+pub trait FlattenDeep {
+    /// The leaf type produced once every level of nesting is removed.
+    type Item;
+
+    /// Appends every leaf item to `out`, in order.
+    fn flatten_deep_into(&self, out: &mut Vec<Self::Item>);
+
+    /// Converts the value into a [`Nested`] tree with one [`Nested::List`] per level of
+    /// nesting, as used by [`flatten_depth`](crate::flatten_depth).
+    fn to_nested(&self) -> Nested<Self::Item>;
+}
+
+macro_rules! impl_flatten_deep_leaf {
+    ($($t:ty),*) => {
+        $(
+            impl FlattenDeep for $t {
+                type Item = $t;
+
+                fn flatten_deep_into(&self, out: &mut Vec<Self::Item>) {
+                    out.push(self.clone());
+                }
+
+                fn to_nested(&self) -> Nested<Self::Item> {
+                    Nested::Value(self.clone())
+                }
+            }
+        )*
+    };
+}
+
+impl_flatten_deep_leaf!(
+    bool, char, (), u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
+    String
+);
+
+impl<'a> FlattenDeep for &'a str {
+    type Item = &'a str;
+
+    fn flatten_deep_into(&self, out: &mut Vec<Self::Item>) {
+        out.push(self);
+    }
+
+    fn to_nested(&self) -> Nested<Self::Item> {
+        Nested::Value(self)
+    }
+}
+
+impl<T: FlattenDeep + ?Sized> FlattenDeep for &T {
+    type Item = T::Item;
+
+    fn flatten_deep_into(&self, out: &mut Vec<Self::Item>) {
+        (**self).flatten_deep_into(out);
+    }
+
+    fn to_nested(&self) -> Nested<Self::Item> {
+        (**self).to_nested()
+    }
+}
+
+impl<T: FlattenDeep + ?Sized> FlattenDeep for Box<T> {
+    type Item = T::Item;
+
+    fn flatten_deep_into(&self, out: &mut Vec<Self::Item>) {
+        (**self).flatten_deep_into(out);
+    }
+
+    fn to_nested(&self) -> Nested<Self::Item> {
+        (**self).to_nested()
+    }
+}
+
+impl<T: FlattenDeep> FlattenDeep for [T] {
+    type Item = T::Item;
+
+    fn flatten_deep_into(&self, out: &mut Vec<Self::Item>) {
+        for item in self {
+            item.flatten_deep_into(out);
+        }
+    }
+
+    fn to_nested(&self) -> Nested<Self::Item> {
+        Nested::List(self.iter().map(FlattenDeep::to_nested).collect())
+    }
+}
+
+impl<T: FlattenDeep, const N: usize> FlattenDeep for [T; N] {
+    type Item = T::Item;
+
+    fn flatten_deep_into(&self, out: &mut Vec<Self::Item>) {
+        self.as_slice().flatten_deep_into(out);
+    }
+
+    fn to_nested(&self) -> Nested<Self::Item> {
+        self.as_slice().to_nested()
+    }
+}
+
+impl<T: FlattenDeep> FlattenDeep for Vec<T> {
+    type Item = T::Item;
+
+    fn flatten_deep_into(&self, out: &mut Vec<Self::Item>) {
+        self.as_slice().flatten_deep_into(out);
+    }
+
+    fn to_nested(&self) -> Nested<Self::Item> {
+        self.as_slice().to_nested()
+    }
+}
+
+impl<T: FlattenDeep> FlattenDeep for Option<T> {
+    type Item = T::Item;
+
+    fn flatten_deep_into(&self, out: &mut Vec<Self::Item>) {
+        if let Some(value) = self {
+            value.flatten_deep_into(out);
+        }
+    }
+
+    fn to_nested(&self) -> Nested<Self::Item> {
+        Nested::List(self.iter().map(FlattenDeep::to_nested).collect())
+    }
+}
+
+impl<T: Clone> FlattenDeep for Nested<T> {
+    type Item = T;
+
+    fn flatten_deep_into(&self, out: &mut Vec<Self::Item>) {
+        match self {
+            Nested::Value(value) => out.push(value.clone()),
+            Nested::List(items) => {
+                for item in items {
+                    item.flatten_deep_into(out);
+                }
+            }
+        }
+    }
+
+    fn to_nested(&self) -> Nested<Self::Item> {
+        self.clone()
+    }
+}
+
+/// Recursively flattens every level of nesting, like lodash's `flattenDeep`.
+/// Accepts any iterable whose items implement [`FlattenDeep`], including
+/// slices, vectors of options and plain iterators.
+///
+/// ```
+/// let nested = vec![vec![vec![1, 2], vec![3]], vec![vec![4]]];
+/// let result = reddish::flatten_deep(&nested);
+/// assert_eq!(result, vec![1, 2, 3, 4]);
+/// ```
+///
+/// ```
+/// let nested = vec![Some(vec!["a", "b"]), None, Some(vec!["c"])];
+/// let result = reddish::flatten_deep(nested.iter());
+/// assert_eq!(result, vec!["a", "b", "c"]);
+/// ```
+pub fn flatten_deep<I>(items: I) -> Vec<<I::Item as FlattenDeep>::Item>
+where
+    I: IntoIterator,
+    I::Item: FlattenDeep,
+{
+    let mut result = Vec::new();
+
+    for item in items {
+        item.flatten_deep_into(&mut result);
+    }
+
+    result
+}
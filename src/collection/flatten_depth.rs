@@ -0,0 +1,57 @@
+#![allow(dead_code)]
+
+use super::{FlattenDeep, Nested};
+
+/// Flattens nested values up to `depth` levels, like lodash's `flattenDepth`.
+/// Accepts the same inputs as [`flatten_deep`](crate::flatten_deep): nested `Vec`s,
+/// `Option`s, iterators and [`Nested`] values. Since the shape of the result depends on
+/// `depth`, items are returned as [`Nested`]: leaves reached within `depth` levels become
+/// [`Nested::Value`], and anything nested deeper is kept as a [`Nested::List`].
+/// A depth of 0 returns every input item unchanged.
+///
+/// ```
+/// use reddish::Nested::{List, Value};
+///
+/// let nested = vec![vec![vec![1, 2], vec![3]], vec![vec![4]]];
+///
+/// let result = reddish::flatten_depth(&nested, 1);
+/// let expected = vec![List(vec![Value(1), Value(2)]), List(vec![Value(3)]), List(vec![Value(4)])];
+/// assert_eq!(result, expected);
+///
+/// let result = reddish::flatten_depth(&nested, 2);
+/// assert_eq!(result, vec![Value(1), Value(2), Value(3), Value(4)]);
+/// ```
+///
+/// ```
+/// use reddish::Nested::{List, Value};
+///
+/// let nested = vec![Value(1), List(vec![Value(2), List(vec![Value(3), List(vec![Value(4)])])])];
+///
+/// let result = reddish::flatten_depth(&nested, 2);
+/// assert_eq!(result, vec![Value(1), Value(2), Value(3), List(vec![Value(4)])]);
+/// assert_eq!(reddish::flatten_depth(&nested, 0), nested);
+/// ```
+pub fn flatten_depth<I>(items: I, depth: usize) -> Vec<Nested<<I::Item as FlattenDeep>::Item>>
+where
+    I: IntoIterator,
+    I::Item: FlattenDeep,
+{
+    let mut result = Vec::new();
+
+    for item in items {
+        expand(item.to_nested(), depth, &mut result);
+    }
+
+    result
+}
+
+fn expand<T>(node: Nested<T>, depth: usize, out: &mut Vec<Nested<T>>) {
+    match node {
+        Nested::List(items) if depth > 0 => {
+            for item in items {
+                expand(item, depth - 1, out);
+            }
+        }
+        other => out.push(other),
+    }
+}
@@ -1,9 +1,39 @@
 mod chunk;
 pub use chunk::*;
 
+mod windows;
+pub use windows::*;
+
+mod paginate;
+pub use paginate::*;
+
+mod split_into;
+pub use split_into::*;
+
+mod chunk_by_weight;
+pub use chunk_by_weight::*;
+
 mod flatten;
 pub use flatten::*;
 
+mod nested;
+pub use nested::*;
+
+mod flatten_deep;
+pub use flatten_deep::*;
+
+mod flatten_depth;
+pub use flatten_depth::*;
+
+mod flat_map;
+pub use flat_map::*;
+
+mod unflatten;
+pub use unflatten::*;
+
+mod shape_of;
+pub use shape_of::*;
+
 mod group_by;
 pub use group_by::*;
 
@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+
+/// A value that is either a single item or a list of further nested values,
+/// mirroring the arbitrarily nested arrays accepted by lodash's `flattenDepth`.
+///
+/// ```
+/// use reddish::Nested;
+///
+/// let nested = Nested::List(vec![
+///     Nested::Value(1),
+///     Nested::List(vec![Nested::Value(2), Nested::List(vec![Nested::Value(3)])]),
+/// ]);
+/// assert_eq!(nested.depth(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Nested<T> {
+    Value(T),
+    List(Vec<Nested<T>>),
+}
+
+impl<T> Nested<T> {
+    /// Returns the maximum nesting depth. A plain value has depth 0, a list of values depth 1.
+    ///
+    /// ```
+    /// use reddish::Nested;
+    ///
+    /// assert_eq!(Nested::Value(1).depth(), 0);
+    /// assert_eq!(Nested::List(vec![Nested::Value(1)]).depth(), 1);
+    /// assert_eq!(Nested::<i32>::List(vec![]).depth(), 1);
+    /// ```
+    pub fn depth(&self) -> usize {
+        match self {
+            Nested::Value(_) => 0,
+            Nested::List(items) => 1 + items.iter().map(Nested::depth).max().unwrap_or(0),
+        }
+    }
+}
+
+impl<T> From<T> for Nested<T> {
+    fn from(value: T) -> Self {
+        Nested::Value(value)
+    }
+}
+
+impl<T> From<Vec<Nested<T>>> for Nested<T> {
+    fn from(items: Vec<Nested<T>>) -> Self {
+        Nested::List(items)
+    }
+}
@@ -0,0 +1,71 @@
+#![allow(dead_code)]
+
+/// A single page of results returned by [`paginate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    /// The items on this page.
+    pub items: Vec<T>,
+    /// The 1-based page number.
+    pub page: usize,
+    /// The maximum number of items per page.
+    pub per_page: usize,
+    /// The total number of items across all pages.
+    pub total_items: usize,
+    /// The total number of pages.
+    pub total_pages: usize,
+}
+
+impl<T> Page<T> {
+    /// Returns true if there is a page after this one.
+    pub fn has_next(&self) -> bool {
+        self.page < self.total_pages
+    }
+
+    /// Returns true if there is a page before this one.
+    pub fn has_prev(&self) -> bool {
+        self.page > 1
+    }
+}
+
+/// Returns the requested 1-based page of a slice.
+/// Pages past the end are returned with no items.
+/// Returns None if `page` or `per_page` is 0.
+///
+/// ```
+/// let vec: Vec<i32> = (1..=10).collect();
+/// let page = reddish::paginate(&vec, 2, 4).unwrap();
+///
+/// assert_eq!(page.items, vec![5, 6, 7, 8]);
+/// assert_eq!(page.total_pages, 3);
+/// assert!(page.has_next());
+/// assert!(page.has_prev());
+/// ```
+///
+/// ```
+/// let vec = vec!["a", "b", "c"];
+/// let page = reddish::paginate(&vec, 1, 5).unwrap();
+///
+/// assert_eq!(page.items, vec!["a", "b", "c"]);
+/// assert!(!page.has_next());
+/// assert!(!page.has_prev());
+///
+/// assert!(reddish::paginate(&vec, 0, 5).is_none());
+/// ```
+pub fn paginate<T: Clone>(vec: &[T], page: usize, per_page: usize) -> Option<Page<T>> {
+    if page == 0 || per_page == 0 {
+        return None;
+    }
+
+    let total_items = vec.len();
+    let total_pages = total_items / per_page + usize::from(total_items % per_page != 0);
+    let start = (page - 1).saturating_mul(per_page).min(total_items);
+    let end = start.saturating_add(per_page).min(total_items);
+
+    Some(Page {
+        items: vec[start..end].to_vec(),
+        page,
+        per_page,
+        total_items,
+        total_pages,
+    })
+}
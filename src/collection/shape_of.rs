@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+
+/// Returns the length of each inner vector, describing the shape needed by
+/// [`unflatten`](crate::unflatten).
+///
+/// ```
+/// let nested = vec![vec!['a', 'b'], vec![], vec!['c']];
+/// let shape = reddish::shape_of(&nested);
+/// assert_eq!(shape, vec![2, 0, 1]);
+///
+/// let flat = reddish::flatten(&nested);
+/// assert_eq!(reddish::unflatten(&flat, &shape), Some(nested));
+/// ```
+pub fn shape_of<T>(nested: &[Vec<T>]) -> Vec<usize> {
+    nested.iter().map(Vec::len).collect()
+}
@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+/// Splits a vector into exactly `n` parts whose lengths differ by at most one.
+/// Earlier parts receive the extra elements; if `n` exceeds the length,
+/// the trailing parts are empty. Returns an empty vector if `n` is 0.
+///
+/// ```
+/// let vec = vec![1, 2, 3, 4, 5, 6, 7];
+/// let result = reddish::split_into(&vec, 3);
+/// assert_eq!(result, vec![vec![1, 2, 3], vec![4, 5], vec![6, 7]]);
+/// ```
+///
+/// ```
+/// let vec = vec!["a", "b"];
+/// let result = reddish::split_into(&vec, 3);
+/// assert_eq!(result, vec![vec!["a"], vec!["b"], vec![]]);
+/// ```
+pub fn split_into<T: Clone>(vec: &[T], n: usize) -> Vec<Vec<T>> {
+    if n == 0 {
+        return vec![];
+    }
+
+    let base = vec.len() / n;
+    let remainder = vec.len() % n;
+    let mut rest = vec;
+    let mut result = Vec::with_capacity(n);
+
+    for i in 0..n {
+        let len = if i < remainder { base + 1 } else { base };
+        let (head, tail) = rest.split_at(len);
+        result.push(head.to_vec());
+        rest = tail;
+    }
+
+    result
+}
@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+
+/// Splits a flat vector back into nested vectors whose lengths are given by `shape`.
+/// This is the inverse of [`flatten`](crate::flatten) when paired with
+/// [`shape_of`](crate::shape_of). Returns None if the lengths in `shape` don't add up to
+/// the length of the input.
+///
+/// ```
+/// let flat = vec![1, 2, 3, 4, 5, 6];
+/// let result = reddish::unflatten(&flat, &[2, 3, 1]);
+/// assert_eq!(result, Some(vec![vec![1, 2], vec![3, 4, 5], vec![6]]));
+/// ```
+///
+/// ```
+/// let flat = vec![1, 2, 3];
+/// assert_eq!(reddish::unflatten(&flat, &[2, 2]), None);
+/// ```
+pub fn unflatten<T: Clone>(vec: &[T], shape: &[usize]) -> Option<Vec<Vec<T>>> {
+    let total = shape.iter().try_fold(0usize, |total, &len| total.checked_add(len))?;
+    if total != vec.len() {
+        return None;
+    }
+
+    let mut rest = vec;
+    let mut result = Vec::with_capacity(shape.len());
+
+    for &len in shape {
+        let (head, tail) = rest.split_at(len);
+        result.push(head.to_vec());
+        rest = tail;
+    }
+
+    Some(result)
+}
@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+/// Returns windows of `size` elements, starting a new window every `step` elements.
+/// Windows overlap when `step < size` (sliding) and are contiguous when `step == size`
+/// (tumbling). Trailing windows shorter than `size` are dropped.
+/// Returns an empty vector if `size` or `step` is 0.
+///
+/// ```
+/// let vec = vec![1, 2, 3, 4, 5];
+/// let result = reddish::windows(&vec, 3, 1);
+/// assert_eq!(result, vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]);
+/// ```
+///
+/// ```
+/// let vec = vec![1, 2, 3, 4, 5, 6, 7];
+/// let result = reddish::windows(&vec, 2, 3);
+/// assert_eq!(result, vec![vec![1, 2], vec![4, 5]]);
+/// ```
+pub fn windows<T: Clone>(vec: &[T], size: usize, step: usize) -> Vec<Vec<T>> {
+    if size == 0 || step == 0 || size > vec.len() {
+        return vec![];
+    }
+
+    (0..=vec.len() - size)
+        .step_by(step)
+        .map(|start| vec[start..start + size].to_vec())
+        .collect()
+}
@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+const MOD_ADLER: u32 = 65521;
+
+// Largest number of bytes that can be summed before `b` may overflow a u32
+const NMAX: usize = 5552;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Adler32State {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32State {
+    pub(crate) fn new() -> Self {
+        Adler32State { a: 1, b: 0 }
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        for chunk in data.chunks(NMAX) {
+            for &byte in chunk {
+                self.a += byte as u32;
+                self.b += self.a;
+            }
+            self.a %= MOD_ADLER;
+            self.b %= MOD_ADLER;
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
+
+/// Computes the Adler-32 checksum (RFC 1950, as used by zlib) of the data.
+/// This is a fast error-detecting checksum, not a cryptographic hash.
+///
+/// ```
+/// assert_eq!(reddish::adler32(b"Wikipedia"), 0x11e60398);
+/// assert_eq!(reddish::adler32(b""), 1);
+/// ```
+pub fn adler32(data: &[u8]) -> u32 {
+    let mut state = Adler32State::new();
+    state.update(data);
+    state.finish()
+}
@@ -0,0 +1,190 @@
+#![allow(dead_code)]
+
+use super::{DecodeError, Encoding};
+
+const STANDARD_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const HEX_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The Base32 alphabets from RFC 4648 and Douglas Crockford's human-friendly variant.
+///
+/// Crockford Base32 omits `I`, `L`, `O` and `U`; when decoding it accepts lowercase,
+/// reads `O` as `0` and `I`/`L` as `1`, and ignores `-` separators.
+///
+/// ```
+/// use reddish::Base32Variant;
+///
+/// assert_eq!(reddish::base32_encode_with(b"foobar", Base32Variant::Standard), "MZXW6YTBOI======");
+/// assert_eq!(reddish::base32_encode_with(b"foobar", Base32Variant::Hex), "CPNMUOJ1E8======");
+/// assert_eq!(reddish::base32_encode_with(b"foobar", Base32Variant::Crockford), "CSQPYRK1E8");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base32Variant {
+    /// RFC 4648 alphabet with `=` padding.
+    Standard,
+    /// RFC 4648 alphabet without padding.
+    StandardNoPad,
+    /// RFC 4648 "base32hex" alphabet, which preserves sort order, with `=` padding.
+    Hex,
+    /// RFC 4648 "base32hex" alphabet without padding.
+    HexNoPad,
+    /// Crockford's alphabet without padding, for human-readable identifiers.
+    Crockford,
+}
+
+impl Base32Variant {
+    fn alphabet(&self) -> &'static [u8; 32] {
+        match self {
+            Base32Variant::Standard | Base32Variant::StandardNoPad => STANDARD_ALPHABET,
+            Base32Variant::Hex | Base32Variant::HexNoPad => HEX_ALPHABET,
+            Base32Variant::Crockford => CROCKFORD_ALPHABET,
+        }
+    }
+
+    fn is_padded(&self) -> bool {
+        matches!(self, Base32Variant::Standard | Base32Variant::Hex)
+    }
+
+    fn value_of(&self, character: char) -> Option<u8> {
+        let character = character.to_ascii_uppercase();
+        let character = match (self, character) {
+            (Base32Variant::Crockford, 'O') => '0',
+            (Base32Variant::Crockford, 'I') | (Base32Variant::Crockford, 'L') => '1',
+            (_, c) => c,
+        };
+
+        self.alphabet()
+            .iter()
+            .position(|&c| c as char == character)
+            .map(|v| v as u8)
+    }
+}
+
+impl Encoding for Base32Variant {
+    fn encode(&self, data: &[u8]) -> String {
+        base32_encode_with(data, *self)
+    }
+
+    fn decode(&self, data: &str) -> Result<Vec<u8>, DecodeError> {
+        base32_decode_with(data, *self)
+    }
+}
+
+/// Encodes bytes to RFC 4648 Base32 with padding.
+///
+/// ```
+/// assert_eq!(reddish::base32_encode(b"foo"), "MZXW6===");
+/// assert_eq!(reddish::base32_encode(b""), "");
+/// ```
+pub fn base32_encode(data: &[u8]) -> String {
+    base32_encode_with(data, Base32Variant::Standard)
+}
+
+/// Encodes bytes to Base32 using the given variant.
+///
+/// ```
+/// use reddish::Base32Variant;
+///
+/// assert_eq!(reddish::base32_encode_with(b"foo", Base32Variant::StandardNoPad), "MZXW6");
+/// ```
+pub fn base32_encode_with(data: &[u8], variant: Base32Variant) -> String {
+    let alphabet = variant.alphabet();
+    let mut result = String::with_capacity((data.len() + 4) / 5 * 8);
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+
+    for &byte in data {
+        buffer = (buffer << 8) | byte as u16;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            result.push(alphabet[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+
+    if bits > 0 {
+        result.push(alphabet[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+
+    if variant.is_padded() {
+        while result.len() % 8 != 0 {
+            result.push('=');
+        }
+    }
+
+    result
+}
+
+/// Decodes RFC 4648 Base32. Lowercase and whitespace are accepted and padding is optional.
+///
+/// ```
+/// assert_eq!(reddish::base32_decode("MZXW6YTBOI======"), Ok(b"foobar".to_vec()));
+/// assert_eq!(reddish::base32_decode("mzxw6ytboi"), Ok(b"foobar".to_vec()));
+/// ```
+pub fn base32_decode(data: &str) -> Result<Vec<u8>, DecodeError> {
+    base32_decode_with(data, Base32Variant::Standard)
+}
+
+/// Decodes Base32 in the given variant.
+///
+/// ```
+/// use reddish::{Base32Variant, DecodeError};
+///
+/// let result = reddish::base32_decode_with("csqp-yrk1-e8", Base32Variant::Crockford);
+/// assert_eq!(result, Ok(b"foobar".to_vec()));
+///
+/// let result = reddish::base32_decode_with("CSQPU", Base32Variant::Crockford);
+/// assert_eq!(result, Err(DecodeError::InvalidCharacter { character: 'U', position: 4 }));
+/// ```
+pub fn base32_decode_with(data: &str, variant: Base32Variant) -> Result<Vec<u8>, DecodeError> {
+    let mut result = Vec::with_capacity(data.len() * 5 / 8);
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    let mut length = 0;
+    let mut padding_start = None;
+    let mut last_position = 0;
+
+    for (position, character) in data.char_indices() {
+        if character.is_whitespace() || (variant == Base32Variant::Crockford && character == '-') {
+            continue;
+        }
+
+        if character == '=' && variant != Base32Variant::Crockford {
+            padding_start.get_or_insert(position);
+            continue;
+        }
+
+        if padding_start.is_some() {
+            return Err(DecodeError::InvalidPadding { position });
+        }
+
+        let value = variant
+            .value_of(character)
+            .ok_or(DecodeError::InvalidCharacter { character, position })?;
+
+        buffer = (buffer << 5) | value as u16;
+        bits += 5;
+        length += 1;
+        last_position = position;
+
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+        }
+    }
+
+    if matches!(length % 8, 1 | 3 | 6) {
+        return Err(DecodeError::InvalidLength { length });
+    }
+
+    // Leftover bits must be zero in a canonical encoding
+    if buffer & ((1 << bits) - 1) != 0 {
+        return Err(DecodeError::InvalidCharacter {
+            character: data[last_position..].chars().next().unwrap_or('?'),
+            position: last_position,
+        });
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+
+use super::radix::{radix_decode, radix_decode_u64, radix_encode, radix_encode_u64};
+use super::{DecodeError, Encoding};
+
+const ALPHABET: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Base36 (`0-9a-z`) over arbitrary bytes, as an [`Encoding`].
+/// Decoding is case-insensitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base36;
+
+impl Encoding for Base36 {
+    fn encode(&self, data: &[u8]) -> String {
+        radix_encode(data, ALPHABET)
+    }
+
+    fn decode(&self, data: &str) -> Result<Vec<u8>, DecodeError> {
+        radix_decode(&data.to_ascii_lowercase(), ALPHABET)
+    }
+}
+
+/// Encodes an integer in Base36 using `0-9a-z`.
+///
+/// ```
+/// assert_eq!(reddish::base36_encode(0), "0");
+/// assert_eq!(reddish::base36_encode(35), "z");
+/// assert_eq!(reddish::base36_encode(1_000_000), "lfls");
+/// ```
+pub fn base36_encode(value: u64) -> String {
+    radix_encode_u64(value, ALPHABET)
+}
+
+/// Decodes a case-insensitive Base36 integer.
+///
+/// ```
+/// use reddish::DecodeError;
+///
+/// assert_eq!(reddish::base36_decode("LFLS"), Ok(1_000_000));
+/// assert_eq!(reddish::base36_decode("3w5e11264sgsf"), Ok(u64::MAX));
+/// assert_eq!(reddish::base36_decode("3w5e11264sgsg"), Err(DecodeError::Overflow));
+/// ```
+pub fn base36_decode(data: &str) -> Result<u64, DecodeError> {
+    radix_decode_u64(&data.to_ascii_lowercase(), ALPHABET)
+}
@@ -0,0 +1,111 @@
+#![allow(dead_code)]
+
+use super::radix::{radix_decode, radix_encode};
+use super::{hash_bytes, DecodeError, Encoding, HashAlgorithm};
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const CHECKSUM_LEN: usize = 4;
+
+/// Base58 with the Bitcoin alphabet, as an [`Encoding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base58;
+
+/// Base58Check: Base58 with a 4-byte double-SHA256 checksum, as an [`Encoding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base58Check;
+
+impl Encoding for Base58 {
+    fn encode(&self, data: &[u8]) -> String {
+        base58_encode(data)
+    }
+
+    fn decode(&self, data: &str) -> Result<Vec<u8>, DecodeError> {
+        base58_decode(data)
+    }
+}
+
+impl Encoding for Base58Check {
+    fn encode(&self, data: &[u8]) -> String {
+        base58check_encode(data)
+    }
+
+    fn decode(&self, data: &str) -> Result<Vec<u8>, DecodeError> {
+        base58check_decode(data)
+    }
+}
+
+/// Encodes bytes to Base58 using the Bitcoin alphabet, which avoids the
+/// look-alike characters `0`, `O`, `I` and `l`. Leading zero bytes become `1`s.
+///
+/// ```
+/// assert_eq!(reddish::base58_encode(b"Hello World!"), "2NEpo7TZRRrLZSi2U");
+/// assert_eq!(reddish::base58_encode(&[0, 0, 0x28, 0x7f, 0xb4, 0xcd]), "11233QC4");
+/// ```
+pub fn base58_encode(data: &[u8]) -> String {
+    radix_encode(data, ALPHABET)
+}
+
+/// Decodes Bitcoin-alphabet Base58.
+///
+/// ```
+/// use reddish::DecodeError;
+///
+/// assert_eq!(reddish::base58_decode("2NEpo7TZRRrLZSi2U"), Ok(b"Hello World!".to_vec()));
+/// assert_eq!(
+///     reddish::base58_decode("2NEp0"),
+///     Err(DecodeError::InvalidCharacter { character: '0', position: 4 })
+/// );
+/// ```
+pub fn base58_decode(data: &str) -> Result<Vec<u8>, DecodeError> {
+    radix_decode(data, ALPHABET)
+}
+
+/// Encodes bytes to Base58Check, appending the first four bytes of
+/// SHA256(SHA256(data)) before encoding. Include any version byte in `data`.
+///
+/// ```
+/// let payload = hex::decode("00f54a5851e9372b87810a8e60cdd2e7cfd80b6e31").unwrap();
+/// assert_eq!(reddish::base58check_encode(&payload), "1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAs");
+/// ```
+pub fn base58check_encode(data: &[u8]) -> String {
+    let mut payload = data.to_vec();
+    payload.extend_from_slice(&checksum(data));
+    base58_encode(&payload)
+}
+
+/// Decodes Base58Check, verifying and stripping the 4-byte checksum.
+///
+/// ```
+/// use reddish::DecodeError;
+///
+/// let result = reddish::base58check_decode("1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAs").unwrap();
+/// assert_eq!(result[0], 0x00);
+/// assert_eq!(result.len(), 21);
+///
+/// let result = reddish::base58check_decode("1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAt");
+/// assert_eq!(result, Err(DecodeError::InvalidChecksum));
+/// ```
+pub fn base58check_decode(data: &str) -> Result<Vec<u8>, DecodeError> {
+    let mut payload = base58_decode(data)?;
+
+    if payload.len() < CHECKSUM_LEN {
+        return Err(DecodeError::InvalidLength { length: payload.len() });
+    }
+
+    let expected = payload.split_off(payload.len() - CHECKSUM_LEN);
+
+    if checksum(&payload) != expected.as_slice() {
+        return Err(DecodeError::InvalidChecksum);
+    }
+
+    Ok(payload)
+}
+
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let first = hash_bytes(HashAlgorithm::Sha256, data);
+    let second = hash_bytes(HashAlgorithm::Sha256, first.as_bytes());
+
+    let mut result = [0u8; CHECKSUM_LEN];
+    result.copy_from_slice(&second.as_bytes()[..CHECKSUM_LEN]);
+    result
+}
@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+use super::radix::{radix_decode, radix_decode_u64, radix_encode, radix_encode_u64};
+use super::{DecodeError, Encoding};
+
+const ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Base62 (`0-9A-Za-z`) over arbitrary bytes, as an [`Encoding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base62;
+
+impl Encoding for Base62 {
+    fn encode(&self, data: &[u8]) -> String {
+        radix_encode(data, ALPHABET)
+    }
+
+    fn decode(&self, data: &str) -> Result<Vec<u8>, DecodeError> {
+        radix_decode(data, ALPHABET)
+    }
+}
+
+/// Encodes an integer in Base62 using `0-9A-Za-z`, handy for short URLs.
+///
+/// ```
+/// assert_eq!(reddish::base62_encode(0), "0");
+/// assert_eq!(reddish::base62_encode(61), "z");
+/// assert_eq!(reddish::base62_encode(125), "21");
+/// assert_eq!(reddish::base62_encode(u64::MAX), "LygHa16AHYF");
+/// ```
+pub fn base62_encode(value: u64) -> String {
+    radix_encode_u64(value, ALPHABET)
+}
+
+/// Decodes a Base62 integer. Case is significant.
+///
+/// ```
+/// use reddish::DecodeError;
+///
+/// assert_eq!(reddish::base62_decode("21"), Ok(125));
+/// let error = DecodeError::InvalidCharacter { character: '-', position: 1 };
+/// assert_eq!(reddish::base62_decode("2-1"), Err(error));
+/// assert_eq!(reddish::base62_decode("LygHa16AHYG"), Err(DecodeError::Overflow));
+/// ```
+pub fn base62_decode(data: &str) -> Result<u64, DecodeError> {
+    radix_decode_u64(data, ALPHABET)
+}
@@ -2,6 +2,8 @@
 
 use base64::{Engine as _, engine::general_purpose};
 
+use super::{Base64Variant, DecodeError};
+
 /// Decodes a Base64 encoded string. Returns None if the input is invalid.
 ///
 /// ```
@@ -24,3 +26,79 @@ pub fn base64_decode(data: &str) -> Option<String> {
         Err(_) => None,
     }
 }
+
+/// Decodes standard Base64 into raw bytes. Whitespace (including MIME line breaks)
+/// is ignored and padding is optional.
+///
+/// ```
+/// let result = reddish::base64_decode_bytes("iVBO\r\nRw==");
+/// assert_eq!(result, Ok(vec![0x89, 0x50, 0x4e, 0x47]));
+/// ```
+///
+/// ```
+/// use reddish::DecodeError;
+///
+/// let result = reddish::base64_decode_bytes("aGVs bG8*");
+/// assert_eq!(result, Err(DecodeError::InvalidCharacter { character: '*', position: 8 }));
+/// ```
+pub fn base64_decode_bytes(data: &str) -> Result<Vec<u8>, DecodeError> {
+    base64_decode_with(data, Base64Variant::Standard)
+}
+
+/// Decodes Base64 in the given alphabet into raw bytes. Whitespace is ignored and
+/// padding is accepted whether or not the variant emits it.
+///
+/// ```
+/// use reddish::Base64Variant;
+///
+/// let result = reddish::base64_decode_with("c3ViamVjdHM_X2Q", Base64Variant::UrlSafe);
+/// assert_eq!(result, Ok(b"subjects?_d".to_vec()));
+/// ```
+pub fn base64_decode_with(data: &str, variant: Base64Variant) -> Result<Vec<u8>, DecodeError> {
+    let mut cleaned = String::with_capacity(data.len());
+    let mut positions = Vec::with_capacity(data.len());
+    let mut padding_start = None;
+
+    for (position, character) in data.char_indices() {
+        if character.is_whitespace() {
+            continue;
+        }
+
+        if character == '=' {
+            padding_start.get_or_insert(position);
+        } else if padding_start.is_some() {
+            return Err(DecodeError::InvalidPadding { position });
+        } else if !is_base64_char(character, variant) {
+            return Err(DecodeError::InvalidCharacter { character, position });
+        }
+
+        cleaned.push(character);
+        positions.push(position);
+    }
+
+    variant.engine().decode(&cleaned).map_err(|err| match err {
+        base64::DecodeError::InvalidByte(offset, b'=') => DecodeError::InvalidPadding {
+            position: positions[offset],
+        },
+        base64::DecodeError::InvalidByte(offset, byte)
+        | base64::DecodeError::InvalidLastSymbol(offset, byte) => DecodeError::InvalidCharacter {
+            character: byte as char,
+            position: positions[offset],
+        },
+        base64::DecodeError::InvalidLength => DecodeError::InvalidLength {
+            length: cleaned.len(),
+        },
+        base64::DecodeError::InvalidPadding => DecodeError::InvalidPadding {
+            position: padding_start.unwrap_or(data.len()),
+        },
+    })
+}
+
+fn is_base64_char(character: char, variant: Base64Variant) -> bool {
+    match character {
+        'A'..='Z' | 'a'..='z' | '0'..='9' => true,
+        '+' | '/' => !variant.is_url_safe(),
+        '-' | '_' => variant.is_url_safe(),
+        _ => false,
+    }
+}
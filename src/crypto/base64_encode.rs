@@ -2,6 +2,10 @@
 
 use base64::{Engine as _, engine::general_purpose};
 
+use super::Base64Variant;
+
+const MIME_LINE_LENGTH: usize = 76;
+
 /// Encodes the input string to Base64.
 ///
 /// ```
@@ -16,3 +20,51 @@ use base64::{Engine as _, engine::general_purpose};
 pub fn base64_encode(data: &str) -> String {
     general_purpose::STANDARD.encode(data.as_bytes())
 }
+
+/// Encodes arbitrary bytes to standard, padded Base64.
+///
+/// ```
+/// let result = reddish::base64_encode_bytes(&[0x89, 0x50, 0x4e, 0x47]);
+/// assert_eq!(result, "iVBORw==");
+/// ```
+pub fn base64_encode_bytes(data: &[u8]) -> String {
+    base64_encode_with(data, Base64Variant::Standard)
+}
+
+/// Encodes arbitrary bytes to Base64 using the given alphabet and padding variant.
+///
+/// ```
+/// use reddish::Base64Variant;
+///
+/// let result = reddish::base64_encode_with(b"subjects?_d", Base64Variant::UrlSafeNoPad);
+/// assert_eq!(result, "c3ViamVjdHM_X2Q");
+/// ```
+pub fn base64_encode_with(data: &[u8], variant: Base64Variant) -> String {
+    variant.engine().encode(data)
+}
+
+/// Encodes arbitrary bytes to MIME Base64 (RFC 2045): standard alphabet,
+/// wrapped into lines of at most 76 characters separated by CRLF.
+///
+/// ```
+/// let data = vec![0u8; 60];
+/// let result = reddish::base64_encode_mime(&data);
+/// let lines: Vec<&str> = result.split("\r\n").collect();
+/// assert_eq!(lines.len(), 2);
+/// assert_eq!(lines[0].len(), 76);
+/// assert_eq!(lines[1].len(), 4);
+/// ```
+pub fn base64_encode_mime(data: &[u8]) -> String {
+    let encoded = base64_encode_bytes(data);
+    let mut result = String::with_capacity(encoded.len() + encoded.len() / MIME_LINE_LENGTH * 2);
+
+    for (i, line) in encoded.as_bytes().chunks(MIME_LINE_LENGTH).enumerate() {
+        if i > 0 {
+            result.push_str("\r\n");
+        }
+        // Base64 output is ASCII, so every chunk is valid UTF-8
+        result.push_str(std::str::from_utf8(line).unwrap());
+    }
+
+    result
+}
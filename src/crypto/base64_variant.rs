@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+
+use base64::alphabet;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+
+/// The Base64 alphabet and padding combinations from RFC 4648.
+///
+/// ```
+/// use reddish::Base64Variant;
+///
+/// let data = [0xfb, 0xff];
+/// assert_eq!(reddish::base64_encode_with(&data, Base64Variant::Standard), "+/8=");
+/// assert_eq!(reddish::base64_encode_with(&data, Base64Variant::StandardNoPad), "+/8");
+/// assert_eq!(reddish::base64_encode_with(&data, Base64Variant::UrlSafe), "-_8=");
+/// assert_eq!(reddish::base64_encode_with(&data, Base64Variant::UrlSafeNoPad), "-_8");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base64Variant {
+    /// `+` and `/` with `=` padding.
+    Standard,
+    /// `+` and `/` without padding.
+    StandardNoPad,
+    /// `-` and `_` with `=` padding, safe for URLs and file names.
+    UrlSafe,
+    /// `-` and `_` without padding, as used by JWTs.
+    UrlSafeNoPad,
+}
+
+const LENIENT: GeneralPurposeConfig =
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+
+const STANDARD: GeneralPurpose = GeneralPurpose::new(&alphabet::STANDARD, LENIENT);
+const STANDARD_NO_PAD: GeneralPurpose =
+    GeneralPurpose::new(&alphabet::STANDARD, LENIENT.with_encode_padding(false));
+const URL_SAFE: GeneralPurpose = GeneralPurpose::new(&alphabet::URL_SAFE, LENIENT);
+const URL_SAFE_NO_PAD: GeneralPurpose =
+    GeneralPurpose::new(&alphabet::URL_SAFE, LENIENT.with_encode_padding(false));
+
+impl Base64Variant {
+    /// Engine that encodes with this variant's padding and decodes with or without it.
+    pub(crate) fn engine(&self) -> &'static GeneralPurpose {
+        match self {
+            Base64Variant::Standard => &STANDARD,
+            Base64Variant::StandardNoPad => &STANDARD_NO_PAD,
+            Base64Variant::UrlSafe => &URL_SAFE,
+            Base64Variant::UrlSafeNoPad => &URL_SAFE_NO_PAD,
+        }
+    }
+
+    pub(crate) fn is_url_safe(&self) -> bool {
+        matches!(self, Base64Variant::UrlSafe | Base64Variant::UrlSafeNoPad)
+    }
+}
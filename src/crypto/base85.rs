@@ -0,0 +1,191 @@
+#![allow(dead_code)]
+
+use super::{DecodeError, Encoding};
+
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// Ascii85 (as used by PostScript and PDF), as an [`Encoding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ascii85;
+
+/// ZeroMQ's Z85, as an [`Encoding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Z85;
+
+impl Encoding for Ascii85 {
+    fn encode(&self, data: &[u8]) -> String {
+        ascii85_encode(data)
+    }
+
+    fn decode(&self, data: &str) -> Result<Vec<u8>, DecodeError> {
+        ascii85_decode(data)
+    }
+}
+
+impl Encoding for Z85 {
+    fn encode(&self, data: &[u8]) -> String {
+        z85_encode(data)
+    }
+
+    fn decode(&self, data: &str) -> Result<Vec<u8>, DecodeError> {
+        z85_decode(data)
+    }
+}
+
+/// Encodes bytes to Ascii85, without the `<~ ~>` delimiters.
+/// Groups of four zero bytes are abbreviated to `z`.
+///
+/// ```
+/// assert_eq!(reddish::ascii85_encode(b"Man is distinguished"), "9jqo^BlbD-BleB1DJ+*+F(f,q");
+/// assert_eq!(reddish::ascii85_encode(b"sure."), "F*2M7/c");
+/// assert_eq!(reddish::ascii85_encode(&[0, 0, 0, 0]), "z");
+/// ```
+pub fn ascii85_encode(data: &[u8]) -> String {
+    encode_base85(data, |digit| (digit + b'!') as char, true)
+}
+
+/// Decodes Ascii85. Optional `<~ ~>` delimiters and whitespace are ignored.
+///
+/// ```
+/// assert_eq!(reddish::ascii85_decode("<~F*2M7/c~>"), Ok(b"sure.".to_vec()));
+/// assert_eq!(reddish::ascii85_decode("z"), Ok(vec![0, 0, 0, 0]));
+/// ```
+pub fn ascii85_decode(data: &str) -> Result<Vec<u8>, DecodeError> {
+    let start = if data.trim_start().starts_with("<~") {
+        data.find("<~").unwrap() + 2
+    } else {
+        0
+    };
+    let end = match data.trim_end().strip_suffix("~>") {
+        Some(stripped) if stripped.len() >= start => stripped.len(),
+        _ => data.len(),
+    };
+
+    decode_base85(&data[..end], start, true, |character| match character {
+        '!'..='u' => Some(character as u32 - '!' as u32),
+        _ => None,
+    })
+}
+
+/// Encodes bytes to Z85, the ZeroMQ variant of Base85 that is safe in source code and JSON.
+/// Inputs whose length is a multiple of 4 produce standard Z85; other lengths use
+/// Ascii85-style partial groups so any input can be encoded.
+///
+/// ```
+/// let data = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+/// assert_eq!(reddish::z85_encode(&data), "HelloWorld");
+/// ```
+pub fn z85_encode(data: &[u8]) -> String {
+    encode_base85(data, |digit| Z85_ALPHABET[digit as usize] as char, false)
+}
+
+/// Decodes Z85. Whitespace is ignored.
+///
+/// ```
+/// let result = reddish::z85_decode("HelloWorld").unwrap();
+/// assert_eq!(result, vec![0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b]);
+/// ```
+pub fn z85_decode(data: &str) -> Result<Vec<u8>, DecodeError> {
+    decode_base85(data, 0, false, |character| {
+        Z85_ALPHABET
+            .iter()
+            .position(|&c| c as char == character)
+            .map(|v| v as u32)
+    })
+}
+
+fn encode_base85<F>(data: &[u8], digit_to_char: F, zero_shortcut: bool) -> String
+where
+    F: Fn(u8) -> char,
+{
+    let mut result = String::with_capacity((data.len() + 3) / 4 * 5);
+
+    for group in data.chunks(4) {
+        let mut block = [0u8; 4];
+        block[..group.len()].copy_from_slice(group);
+        let mut value = u32::from_be_bytes(block);
+
+        if zero_shortcut && group.len() == 4 && value == 0 {
+            result.push('z');
+            continue;
+        }
+
+        let mut digits = [0u8; 5];
+        for digit in digits.iter_mut().rev() {
+            *digit = (value % 85) as u8;
+            value /= 85;
+        }
+
+        // A partial group of n bytes is written as n + 1 digits
+        for &digit in &digits[..group.len() + 1] {
+            result.push(digit_to_char(digit));
+        }
+    }
+
+    result
+}
+
+fn decode_base85<F>(
+    data: &str,
+    offset: usize,
+    zero_shortcut: bool,
+    char_to_digit: F,
+) -> Result<Vec<u8>, DecodeError>
+where
+    F: Fn(char) -> Option<u32>,
+{
+    let mut result = Vec::with_capacity(data.len() / 5 * 4);
+    let mut group = [0u32; 5];
+    let mut count = 0;
+    let mut length = 0;
+
+    for (index, character) in data[offset..].char_indices() {
+        let position = offset + index;
+
+        if character.is_whitespace() {
+            continue;
+        }
+
+        if zero_shortcut && character == 'z' {
+            if count != 0 {
+                return Err(DecodeError::InvalidCharacter { character, position });
+            }
+            result.extend_from_slice(&[0, 0, 0, 0]);
+            continue;
+        }
+
+        group[count] = char_to_digit(character)
+            .ok_or(DecodeError::InvalidCharacter { character, position })?;
+        count += 1;
+        length += 1;
+
+        if count == 5 {
+            result.extend_from_slice(&group_to_bytes(&group)?);
+            count = 0;
+        }
+    }
+
+    match count {
+        0 => {}
+        1 => return Err(DecodeError::InvalidLength { length }),
+        _ => {
+            // Pad the partial group with the highest digit and keep count - 1 bytes
+            for digit in group.iter_mut().skip(count) {
+                *digit = 84;
+            }
+            result.extend_from_slice(&group_to_bytes(&group)?[..count - 1]);
+        }
+    }
+
+    Ok(result)
+}
+
+fn group_to_bytes(group: &[u32; 5]) -> Result<[u8; 4], DecodeError> {
+    let value = group
+        .iter()
+        .try_fold(0u32, |acc, &digit| acc.checked_mul(85)?.checked_add(digit))
+        .ok_or(DecodeError::Overflow)?;
+
+    Ok(value.to_be_bytes())
+}
@@ -0,0 +1,129 @@
+#![allow(dead_code)]
+
+use xxhash_rust::{xxh3::Xxh3, xxh64::Xxh64};
+
+use super::adler32::Adler32State;
+use super::crc::{CRC32_CASTAGNOLI, CRC32_IEEE, CRC64_XZ};
+use super::fnv1a::{fnv1a_32_update, fnv1a_64_update, FNV32_OFFSET, FNV64_OFFSET};
+use super::murmur3::Murmur3State;
+
+/// Non-cryptographic checksums and hashes supported by [`Checksum`].
+///
+/// These are fast and suitable for cache keys, hash tables and detecting accidental
+/// corruption, but offer no protection against deliberate tampering.
+/// Use [`HashAlgorithm`](crate::HashAlgorithm) when that matters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChecksumAlgorithm {
+    Crc32,
+    Crc32c,
+    Crc64,
+    Adler32,
+    Fnv1a32,
+    Fnv1a64,
+    XxHash64,
+    Xxh3,
+    Murmur3_32,
+}
+
+/// A streaming non-cryptographic checksum. Feed data with [`update`](Checksum::update)
+/// and read the result with [`finalize`](Checksum::finalize); 32-bit algorithms return
+/// their value zero-extended to `u64`.
+///
+/// ```
+/// use reddish::{Checksum, ChecksumAlgorithm};
+///
+/// let mut checksum = Checksum::new(ChecksumAlgorithm::Crc32);
+/// checksum.update(b"12345");
+/// checksum.update(b"6789");
+/// assert_eq!(checksum.finalize(), reddish::crc32(b"123456789") as u64);
+/// ```
+///
+/// ```
+/// use reddish::{Checksum, ChecksumAlgorithm};
+///
+/// let mut checksum = Checksum::with_seed(ChecksumAlgorithm::XxHash64, 42);
+/// checksum.update(b"hello");
+/// assert_eq!(checksum.finalize(), reddish::xxhash64(b"hello", 42));
+/// ```
+#[derive(Clone)]
+pub struct Checksum {
+    algorithm: ChecksumAlgorithm,
+    state: ChecksumState,
+}
+
+#[derive(Clone)]
+enum ChecksumState {
+    Crc32(crc::Digest<'static, u32>),
+    Crc32c(crc::Digest<'static, u32>),
+    Crc64(crc::Digest<'static, u64>),
+    Adler32(Adler32State),
+    Fnv1a32(u32),
+    Fnv1a64(u64),
+    XxHash64(Box<Xxh64>),
+    Xxh3(Box<Xxh3>),
+    Murmur3_32(Murmur3State),
+}
+
+impl Checksum {
+    /// Creates a new checksum with a seed of 0.
+    pub fn new(algorithm: ChecksumAlgorithm) -> Self {
+        Checksum::with_seed(algorithm, 0)
+    }
+
+    /// Creates a new checksum with a seed. Only xxHash and MurmurHash3 are seeded
+    /// (MurmurHash3 uses the low 32 bits); the seed is ignored by other algorithms.
+    pub fn with_seed(algorithm: ChecksumAlgorithm, seed: u64) -> Self {
+        let state = match algorithm {
+            ChecksumAlgorithm::Crc32 => ChecksumState::Crc32(CRC32_IEEE.digest()),
+            ChecksumAlgorithm::Crc32c => ChecksumState::Crc32c(CRC32_CASTAGNOLI.digest()),
+            ChecksumAlgorithm::Crc64 => ChecksumState::Crc64(CRC64_XZ.digest()),
+            ChecksumAlgorithm::Adler32 => ChecksumState::Adler32(Adler32State::new()),
+            ChecksumAlgorithm::Fnv1a32 => ChecksumState::Fnv1a32(FNV32_OFFSET),
+            ChecksumAlgorithm::Fnv1a64 => ChecksumState::Fnv1a64(FNV64_OFFSET),
+            ChecksumAlgorithm::XxHash64 => ChecksumState::XxHash64(Box::new(Xxh64::new(seed))),
+            ChecksumAlgorithm::Xxh3 => ChecksumState::Xxh3(Box::new(Xxh3::with_seed(seed))),
+            ChecksumAlgorithm::Murmur3_32 => {
+                ChecksumState::Murmur3_32(Murmur3State::new(seed as u32))
+            }
+        };
+
+        Checksum { algorithm, state }
+    }
+
+    /// Returns the algorithm this checksum uses.
+    pub fn algorithm(&self) -> ChecksumAlgorithm {
+        self.algorithm
+    }
+
+    /// Feeds more data into the checksum.
+    pub fn update(&mut self, data: impl AsRef<[u8]>) {
+        let data = data.as_ref();
+
+        match &mut self.state {
+            ChecksumState::Crc32(digest) | ChecksumState::Crc32c(digest) => digest.update(data),
+            ChecksumState::Crc64(digest) => digest.update(data),
+            ChecksumState::Adler32(state) => state.update(data),
+            ChecksumState::Fnv1a32(hash) => *hash = fnv1a_32_update(*hash, data),
+            ChecksumState::Fnv1a64(hash) => *hash = fnv1a_64_update(*hash, data),
+            ChecksumState::XxHash64(hasher) => hasher.update(data),
+            ChecksumState::Xxh3(hasher) => hasher.update(data),
+            ChecksumState::Murmur3_32(state) => state.update(data),
+        }
+    }
+
+    /// Consumes the checksum and returns its value.
+    pub fn finalize(self) -> u64 {
+        match self.state {
+            ChecksumState::Crc32(digest) | ChecksumState::Crc32c(digest) => {
+                digest.finalize() as u64
+            }
+            ChecksumState::Crc64(digest) => digest.finalize(),
+            ChecksumState::Adler32(state) => state.finish() as u64,
+            ChecksumState::Fnv1a32(hash) => hash as u64,
+            ChecksumState::Fnv1a64(hash) => hash,
+            ChecksumState::XxHash64(hasher) => hasher.digest(),
+            ChecksumState::Xxh3(hasher) => hasher.digest(),
+            ChecksumState::Murmur3_32(state) => state.finish() as u64,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+#![allow(dead_code)]
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::Aes256Gcm;
+use chacha20poly1305::XChaCha20Poly1305;
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+use super::encryption_error::EncryptionError;
+
+/// Authenticated ciphers supported by [`encrypt_with`](crate::encrypt_with).
+///
+/// Both use a 256-bit key and a 16-byte authentication tag. XChaCha20-Poly1305 is the
+/// default because its 192-bit nonces can be chosen at random without any practical
+/// risk of reuse; AES-256-GCM is faster on CPUs with AES instructions but should not
+/// encrypt more than about 2^32 messages under one key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Cipher {
+    Aes256Gcm,
+    #[default]
+    XChaCha20Poly1305,
+}
+
+impl Cipher {
+    /// Returns the nonce length in bytes.
+    pub fn nonce_len(&self) -> usize {
+        match self {
+            Cipher::Aes256Gcm => 12,
+            Cipher::XChaCha20Poly1305 => 24,
+        }
+    }
+
+    pub(crate) fn id(&self) -> u8 {
+        match self {
+            Cipher::Aes256Gcm => 1,
+            Cipher::XChaCha20Poly1305 => 2,
+        }
+    }
+
+    pub(crate) fn from_id(id: u8) -> Option<Cipher> {
+        match id {
+            1 => Some(Cipher::Aes256Gcm),
+            2 => Some(Cipher::XChaCha20Poly1305),
+            _ => None,
+        }
+    }
+
+    /// Encrypts with a fresh random nonce and returns `nonce || ciphertext || tag`.
+    pub(crate) fn seal(
+        &self,
+        key: &[u8],
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        let mut nonce = vec![0u8; self.nonce_len()];
+        OsRng.fill_bytes(&mut nonce);
+
+        let payload = Payload { msg: plaintext, aad };
+        let ciphertext = match self {
+            Cipher::Aes256Gcm => Aes256Gcm::new_from_slice(key)
+                .map_err(|_| EncryptionError::InvalidKey)?
+                .encrypt(nonce.as_slice().into(), payload),
+            Cipher::XChaCha20Poly1305 => XChaCha20Poly1305::new_from_slice(key)
+                .map_err(|_| EncryptionError::InvalidKey)?
+                .encrypt(nonce.as_slice().into(), payload),
+        }
+        .map_err(|_| EncryptionError::EncryptionFailed)?;
+
+        nonce.extend_from_slice(&ciphertext);
+        Ok(nonce)
+    }
+
+    /// Reverses [`seal`](Cipher::seal).
+    pub(crate) fn open(
+        &self,
+        key: &[u8],
+        sealed: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        if sealed.len() < self.nonce_len() {
+            return Err(EncryptionError::InvalidToken);
+        }
+
+        let (nonce, ciphertext) = sealed.split_at(self.nonce_len());
+        let payload = Payload { msg: ciphertext, aad };
+        match self {
+            Cipher::Aes256Gcm => Aes256Gcm::new_from_slice(key)
+                .map_err(|_| EncryptionError::InvalidKey)?
+                .decrypt(nonce.into(), payload),
+            Cipher::XChaCha20Poly1305 => XChaCha20Poly1305::new_from_slice(key)
+                .map_err(|_| EncryptionError::InvalidKey)?
+                .decrypt(nonce.into(), payload),
+        }
+        .map_err(|_| EncryptionError::DecryptionFailed)
+    }
+}
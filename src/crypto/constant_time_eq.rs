@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+
+use subtle::ConstantTimeEq;
+
+/// Compares two byte slices in constant time, so the comparison does not leak
+/// how many leading bytes match. Slices of different lengths are never equal;
+/// only the length itself may be observable.
+///
+/// ```
+/// assert!(reddish::constant_time_eq(b"secret", b"secret"));
+/// assert!(!reddish::constant_time_eq(b"secret", b"secreT"));
+/// assert!(!reddish::constant_time_eq(b"secret", b"secret!"));
+/// ```
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.ct_eq(b).into()
+}
@@ -0,0 +1,38 @@
+#![allow(dead_code)]
+
+use crc::{Crc, CRC_32_ISCSI, CRC_32_ISO_HDLC, CRC_64_XZ};
+
+pub(crate) static CRC32_IEEE: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+pub(crate) static CRC32_CASTAGNOLI: Crc<u32> = Crc::<u32>::new(&CRC_32_ISCSI);
+pub(crate) static CRC64_XZ: Crc<u64> = Crc::<u64>::new(&CRC_64_XZ);
+
+/// Computes the CRC-32 (IEEE 802.3, as used by zip, gzip and PNG) of the data.
+/// This is an error-detecting checksum, not a cryptographic hash.
+///
+/// ```
+/// assert_eq!(reddish::crc32(b"123456789"), 0xcbf43926);
+/// assert_eq!(reddish::crc32(b""), 0);
+/// ```
+pub fn crc32(data: &[u8]) -> u32 {
+    CRC32_IEEE.checksum(data)
+}
+
+/// Computes the CRC-32C (Castagnoli, as used by iSCSI, ext4 and many storage formats).
+/// This is an error-detecting checksum, not a cryptographic hash.
+///
+/// ```
+/// assert_eq!(reddish::crc32c(b"123456789"), 0xe3069283);
+/// ```
+pub fn crc32c(data: &[u8]) -> u32 {
+    CRC32_CASTAGNOLI.checksum(data)
+}
+
+/// Computes the CRC-64/XZ (ECMA-182 polynomial, reflected, as used by xz) of the data.
+/// This is an error-detecting checksum, not a cryptographic hash.
+///
+/// ```
+/// assert_eq!(reddish::crc64(b"123456789"), 0x995dc9bbdf1939fa);
+/// ```
+pub fn crc64(data: &[u8]) -> u64 {
+    CRC64_XZ.checksum(data)
+}
@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+
+/// The error returned by the byte-oriented decoders when the input is malformed.
+/// Positions are byte offsets into the original input, including any skipped whitespace.
+///
+/// ```
+/// use reddish::DecodeError;
+///
+/// let err = reddish::hex_decode_bytes("ab cd xz").unwrap_err();
+/// assert_eq!(err, DecodeError::InvalidCharacter { character: 'x', position: 6 });
+/// assert_eq!(err.to_string(), "invalid character 'x' at position 6");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// A character outside the encoding's alphabet was found.
+    InvalidCharacter { character: char, position: usize },
+    /// The number of significant characters cannot form a complete encoding.
+    InvalidLength { length: usize },
+    /// Padding characters were misplaced or malformed.
+    InvalidPadding { position: usize },
+    /// The embedded checksum does not match the decoded data.
+    InvalidChecksum,
+    /// The decoded value does not fit in the target type.
+    Overflow,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidCharacter { character, position } => {
+                write!(f, "invalid character {:?} at position {}", character, position)
+            }
+            DecodeError::InvalidLength { length } => write!(f, "invalid input length {}", length),
+            DecodeError::InvalidPadding { position } => {
+                write!(f, "invalid padding at position {}", position)
+            }
+            DecodeError::InvalidChecksum => f.write_str("invalid checksum"),
+            DecodeError::Overflow => f.write_str("decoded value overflows"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
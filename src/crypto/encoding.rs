@@ -0,0 +1,64 @@
+#![allow(dead_code)]
+
+use super::{
+    base64_decode_with, base64_encode_with, hex_decode_bytes, hex_encode_bytes, Base64Variant,
+    DecodeError,
+};
+
+/// A binary-to-text encoding. Every encoding in the crate implements this trait,
+/// so callers can pick one at runtime and share the same [`DecodeError`] handling.
+///
+/// ```
+/// use reddish::{Base32Variant, Base58, Base64Variant, Encoding, Hex, Z85};
+///
+/// let encodings: Vec<Box<dyn Encoding>> = vec![
+///     Box::new(Hex),
+///     Box::new(Base64Variant::UrlSafeNoPad),
+///     Box::new(Base32Variant::Crockford),
+///     Box::new(Base58),
+///     Box::new(Z85),
+/// ];
+///
+/// for encoding in encodings {
+///     let encoded = encoding.encode(b"hello");
+///     assert_eq!(encoding.decode(&encoded).unwrap(), b"hello");
+/// }
+/// ```
+pub trait Encoding {
+    /// Encodes arbitrary bytes to text.
+    fn encode(&self, data: &[u8]) -> String;
+
+    /// Decodes text back into bytes.
+    fn decode(&self, data: &str) -> Result<Vec<u8>, DecodeError>;
+}
+
+/// Lowercase hexadecimal, as an [`Encoding`]. Decoding accepts either case.
+///
+/// ```
+/// use reddish::{Encoding, Hex};
+///
+/// assert_eq!(Hex.encode(&[0xca, 0xfe]), "cafe");
+/// assert_eq!(Hex.decode("CAFE"), Ok(vec![0xca, 0xfe]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hex;
+
+impl Encoding for Hex {
+    fn encode(&self, data: &[u8]) -> String {
+        hex_encode_bytes(data)
+    }
+
+    fn decode(&self, data: &str) -> Result<Vec<u8>, DecodeError> {
+        hex_decode_bytes(data)
+    }
+}
+
+impl Encoding for Base64Variant {
+    fn encode(&self, data: &[u8]) -> String {
+        base64_encode_with(data, *self)
+    }
+
+    fn decode(&self, data: &str) -> Result<Vec<u8>, DecodeError> {
+        base64_decode_with(data, *self)
+    }
+}
@@ -0,0 +1,90 @@
+#![allow(dead_code)]
+
+use super::base64_decode::base64_decode_with;
+use super::base64_encode::base64_encode_with;
+use super::base64_variant::Base64Variant;
+use super::cipher::Cipher;
+use super::encryption_error::EncryptionError;
+use super::secret_key::SecretKey;
+
+/// Encrypts and authenticates data with XChaCha20-Poly1305 and a random nonce.
+///
+/// The result is a URL-safe token holding a version byte, the nonce and the ciphertext,
+/// so it can be stored or sent as-is and passed straight to [`decrypt`](crate::decrypt).
+///
+/// ```
+/// use reddish::SecretKey;
+///
+/// let key = SecretKey::generate();
+/// let token = reddish::encrypt(&key, b"attack at dawn").unwrap();
+/// assert_eq!(reddish::decrypt(&key, &token).unwrap(), b"attack at dawn");
+///
+/// // Every call uses a new nonce
+/// assert_ne!(token, reddish::encrypt(&key, b"attack at dawn").unwrap());
+/// ```
+pub fn encrypt(key: &SecretKey, plaintext: &[u8]) -> Result<String, EncryptionError> {
+    encrypt_with(key, plaintext, b"", Cipher::default())
+}
+
+/// Encrypts data with the chosen cipher, binding it to `associated_data`.
+///
+/// Associated data is authenticated but not encrypted or stored in the token; the same
+/// bytes must be passed to [`decrypt_with`](crate::decrypt_with). Use it to tie a
+/// ciphertext to its context, such as a user ID or database row.
+///
+/// ```
+/// use reddish::{Cipher, SecretKey};
+///
+/// let key = SecretKey::generate();
+/// let card = b"4111 1111 1111 1111";
+/// let token = reddish::encrypt_with(&key, card, b"user:42", Cipher::Aes256Gcm).unwrap();
+/// assert!(reddish::decrypt_with(&key, &token, b"user:42").is_ok());
+/// assert!(reddish::decrypt_with(&key, &token, b"user:43").is_err());
+/// ```
+pub fn encrypt_with(
+    key: &SecretKey,
+    plaintext: &[u8],
+    associated_data: &[u8],
+    cipher: Cipher,
+) -> Result<String, EncryptionError> {
+    let mut token = vec![cipher.id()];
+    token.extend(cipher.seal(key.as_bytes(), plaintext, associated_data)?);
+    Ok(base64_encode_with(&token, Base64Variant::UrlSafeNoPad))
+}
+
+/// Decrypts a token produced by [`encrypt`](crate::encrypt) or
+/// [`encrypt_with`](crate::encrypt_with) without associated data. The cipher is read from
+/// the token.
+///
+/// ```
+/// use reddish::{EncryptionError, SecretKey};
+///
+/// let token = reddish::encrypt(&SecretKey::generate(), b"secret").unwrap();
+/// let other_key = SecretKey::generate();
+/// assert_eq!(reddish::decrypt(&other_key, &token), Err(EncryptionError::DecryptionFailed));
+/// assert_eq!(reddish::decrypt(&other_key, "garbage!"), Err(EncryptionError::InvalidToken));
+/// ```
+pub fn decrypt(key: &SecretKey, token: &str) -> Result<Vec<u8>, EncryptionError> {
+    decrypt_with(key, token, b"")
+}
+
+/// Decrypts a token, checking it against the associated data it was encrypted with.
+pub fn decrypt_with(
+    key: &SecretKey,
+    token: &str,
+    associated_data: &[u8],
+) -> Result<Vec<u8>, EncryptionError> {
+    let (cipher, sealed) = split_token(token)?;
+    cipher.open(key.as_bytes(), &sealed, associated_data)
+}
+
+pub(crate) fn split_token(token: &str) -> Result<(Cipher, Vec<u8>), EncryptionError> {
+    let mut bytes = base64_decode_with(token, Base64Variant::UrlSafeNoPad)
+        .map_err(|_| EncryptionError::InvalidToken)?;
+    if bytes.is_empty() {
+        return Err(EncryptionError::InvalidToken);
+    }
+
+    let cipher = Cipher::from_id(bytes.remove(0)).ok_or(EncryptionError::InvalidToken)?;
+    Ok((cipher, bytes))
+}
@@ -0,0 +1,58 @@
+#![allow(dead_code)]
+
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+use super::base64_decode::base64_decode_with;
+use super::base64_encode::base64_encode_with;
+use super::base64_variant::Base64Variant;
+use super::cipher::Cipher;
+use super::encryption_error::EncryptionError;
+use super::secret_key::SecretKey;
+
+const SALT_LEN: usize = 16;
+
+// Set on the version byte of tokens that carry an Argon2id salt
+const PASSPHRASE_FLAG: u8 = 0x80;
+
+/// Encrypts data under a passphrase. A random salt is generated, the key is derived with
+/// Argon2id (see [`SecretKey::from_passphrase`]) and the salt is stored in the token.
+///
+/// Key derivation is intentionally slow, so prefer [`encrypt`](crate::encrypt) with a
+/// stored [`SecretKey`] for high-volume use.
+///
+/// ```
+/// let passphrase = "correct horse battery staple";
+/// let token = reddish::encrypt_with_passphrase(passphrase, b"diary").unwrap();
+/// assert_eq!(reddish::decrypt_with_passphrase(passphrase, &token).unwrap(), b"diary");
+/// assert!(reddish::decrypt_with_passphrase("wrong", &token).is_err());
+/// ```
+pub fn encrypt_with_passphrase(
+    passphrase: &str,
+    plaintext: &[u8],
+) -> Result<String, EncryptionError> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+
+    let key = SecretKey::from_passphrase(passphrase, &salt)?;
+    let cipher = Cipher::default();
+
+    let mut token = vec![cipher.id() | PASSPHRASE_FLAG];
+    token.extend_from_slice(&salt);
+    token.extend(cipher.seal(key.as_bytes(), plaintext, b"")?);
+    Ok(base64_encode_with(&token, Base64Variant::UrlSafeNoPad))
+}
+
+/// Decrypts a token produced by [`encrypt_with_passphrase`](crate::encrypt_with_passphrase).
+pub fn decrypt_with_passphrase(passphrase: &str, token: &str) -> Result<Vec<u8>, EncryptionError> {
+    let bytes = base64_decode_with(token, Base64Variant::UrlSafeNoPad)
+        .map_err(|_| EncryptionError::InvalidToken)?;
+    if bytes.len() < 1 + SALT_LEN || bytes[0] & PASSPHRASE_FLAG == 0 {
+        return Err(EncryptionError::InvalidToken);
+    }
+
+    let cipher = Cipher::from_id(bytes[0] & !PASSPHRASE_FLAG).ok_or(EncryptionError::InvalidToken)?;
+    let (salt, sealed) = bytes[1..].split_at(SALT_LEN);
+    let key = SecretKey::from_passphrase(passphrase, salt)?;
+    cipher.open(key.as_bytes(), sealed, b"")
+}
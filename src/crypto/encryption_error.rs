@@ -0,0 +1,42 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by the encryption helpers such as [`encrypt`](crate::encrypt),
+/// [`decrypt`](crate::decrypt) and [`fernet_decrypt`](crate::fernet_decrypt).
+///
+/// Decryption failures deliberately do not say whether the key, the associated data or
+/// the ciphertext was wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncryptionError {
+    /// The key has the wrong length or encoding.
+    InvalidKey,
+    /// The token is not well formed (bad encoding, truncated, unknown version).
+    InvalidToken,
+    /// The token failed authentication: wrong key, wrong associated data or tampering.
+    DecryptionFailed,
+    /// The token is older than the allowed time-to-live.
+    Expired,
+    /// Deriving a key from a passphrase failed, e.g. because the salt is too short.
+    KeyDerivation(String),
+    /// The cipher refused to encrypt, e.g. because the plaintext is too long.
+    EncryptionFailed,
+}
+
+impl fmt::Display for EncryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EncryptionError::InvalidKey => write!(f, "invalid encryption key"),
+            EncryptionError::InvalidToken => write!(f, "invalid encrypted token"),
+            EncryptionError::DecryptionFailed => write!(f, "decryption failed"),
+            EncryptionError::Expired => write!(f, "token has expired"),
+            EncryptionError::KeyDerivation(reason) => {
+                write!(f, "key derivation failed: {}", reason)
+            }
+            EncryptionError::EncryptionFailed => write!(f, "encryption failed"),
+        }
+    }
+}
+
+impl Error for EncryptionError {}
@@ -0,0 +1,142 @@
+#![allow(dead_code)]
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cbc::cipher::block_padding::Pkcs7;
+use cbc::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+use super::base64_decode::base64_decode_with;
+use super::base64_encode::base64_encode_with;
+use super::base64_variant::Base64Variant;
+use super::constant_time_eq::constant_time_eq;
+use super::encryption_error::EncryptionError;
+use super::hash_algorithm::HashAlgorithm;
+use super::hmac::hmac;
+
+type Aes128CbcEnc = cbc::Encryptor<aes::Aes128>;
+type Aes128CbcDec = cbc::Decryptor<aes::Aes128>;
+
+const VERSION: u8 = 0x80;
+const KEY_LEN: usize = 32;
+const IV_LEN: usize = 16;
+const TAG_LEN: usize = 32;
+const HEADER_LEN: usize = 1 + 8 + IV_LEN;
+
+// Tokens stamped further than this in the future are rejected, as in the reference implementation
+const MAX_CLOCK_SKEW: u64 = 60;
+
+/// Generates a new random Fernet key: 32 bytes encoded as URL-safe Base64, the same
+/// format as Python's `cryptography.fernet.Fernet.generate_key()`.
+///
+/// ```
+/// let key = reddish::fernet_generate_key();
+/// assert_eq!(key.len(), 44);
+/// ```
+pub fn fernet_generate_key() -> String {
+    let mut key = [0u8; KEY_LEN];
+    OsRng.fill_bytes(&mut key);
+    base64_encode_with(&key, Base64Variant::UrlSafe)
+}
+
+/// Encrypts data into a [Fernet](https://github.com/fernet/spec) token
+/// (AES-128-CBC with HMAC-SHA256), interoperable with other Fernet implementations
+/// such as Python's `cryptography` package.
+///
+/// Prefer [`encrypt`](crate::encrypt) unless you need Fernet compatibility.
+///
+/// ```
+/// let key = reddish::fernet_generate_key();
+/// let token = reddish::fernet_encrypt(&key, b"hello").unwrap();
+/// assert!(token.starts_with("gAAAAA"));
+/// assert_eq!(reddish::fernet_decrypt(&key, &token, None).unwrap(), b"hello");
+/// ```
+pub fn fernet_encrypt(key: &str, plaintext: &[u8]) -> Result<String, EncryptionError> {
+    let key = decode_key(key)?;
+    let (signing_key, encryption_key) = key.split_at(KEY_LEN / 2);
+
+    let mut iv = [0u8; IV_LEN];
+    OsRng.fill_bytes(&mut iv);
+
+    let ciphertext = Aes128CbcEnc::new(encryption_key.into(), (&iv).into())
+        .encrypt_padded_vec_mut::<Pkcs7>(plaintext);
+
+    let mut token = Vec::with_capacity(HEADER_LEN + ciphertext.len() + TAG_LEN);
+    token.push(VERSION);
+    token.extend_from_slice(&now().to_be_bytes());
+    token.extend_from_slice(&iv);
+    token.extend_from_slice(&ciphertext);
+    let tag = hmac(HashAlgorithm::Sha256, signing_key, &token);
+    token.extend_from_slice(tag.as_bytes());
+
+    Ok(base64_encode_with(&token, Base64Variant::UrlSafe))
+}
+
+/// Decrypts a Fernet token. With `ttl` set, tokens older than that many seconds are
+/// rejected with [`EncryptionError::Expired`].
+///
+/// ```
+/// use reddish::EncryptionError;
+///
+/// // Test vector from the Fernet specification, created in 1985
+/// let key = "cw_0x689RpI-jtRR7oE8h_eQsKImvJapLeSbXpwF4e4=";
+/// let token = concat!(
+///     "gAAAAAAdwJ6wAAECAwQFBgcICQoLDA0ODy021cpGVWKZ_eEwCGM4BLLF_5CV9dOPmrhu",
+///     "VUPgJobwOz7JcbmrR64jVmpU4IwqDA==",
+/// );
+/// assert_eq!(reddish::fernet_decrypt(key, token, None).unwrap(), b"hello");
+/// assert_eq!(reddish::fernet_decrypt(key, token, Some(60)), Err(EncryptionError::Expired));
+/// ```
+pub fn fernet_decrypt(
+    key: &str,
+    token: &str,
+    ttl: Option<u64>,
+) -> Result<Vec<u8>, EncryptionError> {
+    let key = decode_key(key)?;
+    let (signing_key, encryption_key) = key.split_at(KEY_LEN / 2);
+
+    let token = base64_decode_with(token, Base64Variant::UrlSafe)
+        .map_err(|_| EncryptionError::InvalidToken)?;
+    if token.len() < HEADER_LEN + IV_LEN + TAG_LEN
+        || (token.len() - HEADER_LEN - TAG_LEN) % IV_LEN != 0
+    {
+        return Err(EncryptionError::InvalidToken);
+    }
+    if token[0] != VERSION {
+        return Err(EncryptionError::InvalidToken);
+    }
+
+    let (signed, tag) = token.split_at(token.len() - TAG_LEN);
+    if !constant_time_eq(hmac(HashAlgorithm::Sha256, signing_key, signed).as_bytes(), tag) {
+        return Err(EncryptionError::DecryptionFailed);
+    }
+
+    let mut timestamp = [0u8; 8];
+    timestamp.copy_from_slice(&signed[1..9]);
+    let timestamp = u64::from_be_bytes(timestamp);
+    let now = now();
+    if let Some(ttl) = ttl {
+        if timestamp.saturating_add(ttl) < now {
+            return Err(EncryptionError::Expired);
+        }
+    }
+    if timestamp > now + MAX_CLOCK_SKEW {
+        return Err(EncryptionError::InvalidToken);
+    }
+
+    let iv = &signed[9..HEADER_LEN];
+    Aes128CbcDec::new(encryption_key.into(), iv.into())
+        .decrypt_padded_vec_mut::<Pkcs7>(&signed[HEADER_LEN..])
+        .map_err(|_| EncryptionError::DecryptionFailed)
+}
+
+fn decode_key(key: &str) -> Result<[u8; KEY_LEN], EncryptionError> {
+    let key =
+        base64_decode_with(key, Base64Variant::UrlSafe).map_err(|_| EncryptionError::InvalidKey)?;
+    key.as_slice().try_into().map_err(|_| EncryptionError::InvalidKey)
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+
+pub(crate) const FNV32_OFFSET: u32 = 0x811c9dc5;
+pub(crate) const FNV32_PRIME: u32 = 0x01000193;
+pub(crate) const FNV64_OFFSET: u64 = 0xcbf29ce484222325;
+pub(crate) const FNV64_PRIME: u64 = 0x00000100000001b3;
+
+pub(crate) fn fnv1a_32_update(mut hash: u32, data: &[u8]) -> u32 {
+    for &byte in data {
+        hash ^= byte as u32;
+        hash = hash.wrapping_mul(FNV32_PRIME);
+    }
+    hash
+}
+
+pub(crate) fn fnv1a_64_update(mut hash: u64, data: &[u8]) -> u64 {
+    for &byte in data {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV64_PRIME);
+    }
+    hash
+}
+
+/// Computes the 32-bit FNV-1a hash of the data.
+/// Fast and well distributed for short keys, but not a cryptographic hash.
+///
+/// ```
+/// assert_eq!(reddish::fnv1a_32(b""), 0x811c9dc5);
+/// assert_eq!(reddish::fnv1a_32(b"a"), 0xe40c292c);
+/// ```
+pub fn fnv1a_32(data: &[u8]) -> u32 {
+    fnv1a_32_update(FNV32_OFFSET, data)
+}
+
+/// Computes the 64-bit FNV-1a hash of the data.
+/// Fast and well distributed for short keys, but not a cryptographic hash.
+///
+/// ```
+/// assert_eq!(reddish::fnv1a_64(b""), 0xcbf29ce484222325);
+/// assert_eq!(reddish::fnv1a_64(b"a"), 0xaf63dc4c8601ec8c);
+/// ```
+pub fn fnv1a_64(data: &[u8]) -> u64 {
+    fnv1a_64_update(FNV64_OFFSET, data)
+}
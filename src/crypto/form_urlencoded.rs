@@ -0,0 +1,62 @@
+#![allow(dead_code)]
+
+use std::borrow::Cow;
+
+use percent_encoding::percent_decode;
+
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Encodes a string as `application/x-www-form-urlencoded`, the format HTML forms submit.
+/// Spaces become `+`, and everything except letters, digits and `*-._` is percent-encoded.
+///
+/// ```
+/// assert_eq!(reddish::form_urlencode("hello world"), "hello+world");
+/// assert_eq!(reddish::form_urlencode("a+b=c&d"), "a%2Bb%3Dc%26d");
+/// assert_eq!(reddish::form_urlencode("café~"), "caf%C3%A9%7E");
+/// ```
+pub fn form_urlencode(data: &str) -> String {
+    let mut encoded = String::with_capacity(data.len());
+
+    for &byte in data.as_bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'*' | b'-' | b'.' | b'_' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            _ => {
+                encoded.push('%');
+                encoded.push(HEX_UPPER[(byte >> 4) as usize] as char);
+                encoded.push(HEX_UPPER[(byte & 0x0f) as usize] as char);
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Decodes an `application/x-www-form-urlencoded` string, turning `+` into a space.
+/// Returns None if the decoded bytes are not valid UTF-8.
+///
+/// ```
+/// assert_eq!(reddish::form_urldecode("hello+world"), Some("hello world".to_string()));
+/// assert_eq!(reddish::form_urldecode("a%2Bb"), Some("a+b".to_string()));
+/// assert_eq!(reddish::form_urldecode("%FF"), None);
+/// ```
+pub fn form_urldecode(data: &str) -> Option<String> {
+    let bytes = plus_to_space(data);
+    percent_decode(&bytes).decode_utf8().ok().map(|s| s.into_owned())
+}
+
+// Decodes like `form_urldecode`, replacing invalid UTF-8 instead of failing
+pub(crate) fn form_urldecode_lossy(data: &str) -> String {
+    let bytes = plus_to_space(data);
+    percent_decode(&bytes).decode_utf8_lossy().into_owned()
+}
+
+fn plus_to_space(data: &str) -> Cow<'_, [u8]> {
+    if data.contains('+') {
+        Cow::Owned(data.bytes().map(|b| if b == b'+' { b' ' } else { b }).collect())
+    } else {
+        Cow::Borrowed(data.as_bytes())
+    }
+}
@@ -0,0 +1,156 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Hash algorithms supported by [`Hasher`](crate::Hasher), [`hash_reader`](crate::hash_reader)
+/// and [`hash_file`](crate::hash_file).
+///
+/// Algorithms can be picked at runtime by name. Parsing is case-insensitive and ignores
+/// separators, so `"sha384"`, `"SHA-384"` and `"sha_384"` are all accepted.
+///
+/// ```
+/// use reddish::HashAlgorithm;
+///
+/// assert_eq!(HashAlgorithm::Sha256.output_len(), 32);
+/// assert_eq!(HashAlgorithm::Md5.name(), "md5");
+/// assert_eq!("SHA-512/256".parse(), Ok(HashAlgorithm::Sha512_256));
+/// assert_eq!("sha3-256".parse(), Ok(HashAlgorithm::Sha3_256));
+/// assert!("crc32".parse::<HashAlgorithm>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Md5,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha512_256,
+    Sha3_256,
+    Sha3_512,
+    Blake2b,
+    Blake2s,
+    Blake3,
+}
+
+impl HashAlgorithm {
+    /// Every supported algorithm, in declaration order.
+    pub const ALL: [HashAlgorithm; 12] = [
+        HashAlgorithm::Md5,
+        HashAlgorithm::Sha1,
+        HashAlgorithm::Sha224,
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Sha384,
+        HashAlgorithm::Sha512,
+        HashAlgorithm::Sha512_256,
+        HashAlgorithm::Sha3_256,
+        HashAlgorithm::Sha3_512,
+        HashAlgorithm::Blake2b,
+        HashAlgorithm::Blake2s,
+        HashAlgorithm::Blake3,
+    ];
+
+    /// Returns the lowercase name of the algorithm.
+    pub fn name(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Md5 => "md5",
+            HashAlgorithm::Sha1 => "sha1",
+            HashAlgorithm::Sha224 => "sha224",
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Sha384 => "sha384",
+            HashAlgorithm::Sha512 => "sha512",
+            HashAlgorithm::Sha512_256 => "sha512-256",
+            HashAlgorithm::Sha3_256 => "sha3-256",
+            HashAlgorithm::Sha3_512 => "sha3-512",
+            HashAlgorithm::Blake2b => "blake2b",
+            HashAlgorithm::Blake2s => "blake2s",
+            HashAlgorithm::Blake3 => "blake3",
+        }
+    }
+
+    /// Returns the length of the digest in bytes.
+    pub fn output_len(&self) -> usize {
+        match self {
+            HashAlgorithm::Md5 => 16,
+            HashAlgorithm::Sha1 => 20,
+            HashAlgorithm::Sha224 => 28,
+            HashAlgorithm::Sha256 => 32,
+            HashAlgorithm::Sha384 => 48,
+            HashAlgorithm::Sha512 => 64,
+            HashAlgorithm::Sha512_256 => 32,
+            HashAlgorithm::Sha3_256 => 32,
+            HashAlgorithm::Sha3_512 => 64,
+            HashAlgorithm::Blake2b => 64,
+            HashAlgorithm::Blake2s => 32,
+            HashAlgorithm::Blake3 => 32,
+        }
+    }
+
+    /// Returns the internal block size in bytes, as used by HMAC.
+    pub fn block_size(&self) -> usize {
+        match self {
+            HashAlgorithm::Md5 => 64,
+            HashAlgorithm::Sha1 => 64,
+            HashAlgorithm::Sha224 => 64,
+            HashAlgorithm::Sha256 => 64,
+            HashAlgorithm::Sha384 => 128,
+            HashAlgorithm::Sha512 => 128,
+            HashAlgorithm::Sha512_256 => 128,
+            HashAlgorithm::Sha3_256 => 136,
+            HashAlgorithm::Sha3_512 => 72,
+            HashAlgorithm::Blake2b => 128,
+            HashAlgorithm::Blake2s => 64,
+            HashAlgorithm::Blake3 => 64,
+        }
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The error returned when a string does not name a supported [`HashAlgorithm`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseHashAlgorithmError {
+    name: String,
+}
+
+impl fmt::Display for ParseHashAlgorithmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown hash algorithm: {}", self.name)
+    }
+}
+
+impl Error for ParseHashAlgorithmError {}
+
+impl FromStr for HashAlgorithm {
+    type Err = ParseHashAlgorithmError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized: String = s
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+
+        match normalized.as_str() {
+            "md5" => Ok(HashAlgorithm::Md5),
+            "sha1" => Ok(HashAlgorithm::Sha1),
+            "sha224" => Ok(HashAlgorithm::Sha224),
+            "sha256" => Ok(HashAlgorithm::Sha256),
+            "sha384" => Ok(HashAlgorithm::Sha384),
+            "sha512" => Ok(HashAlgorithm::Sha512),
+            "sha512256" => Ok(HashAlgorithm::Sha512_256),
+            "sha3256" => Ok(HashAlgorithm::Sha3_256),
+            "sha3512" => Ok(HashAlgorithm::Sha3_512),
+            "blake2b" | "blake2b512" => Ok(HashAlgorithm::Blake2b),
+            "blake2s" | "blake2s256" => Ok(HashAlgorithm::Blake2s),
+            "blake3" => Ok(HashAlgorithm::Blake3),
+            _ => Err(ParseHashAlgorithmError { name: s.to_string() }),
+        }
+    }
+}
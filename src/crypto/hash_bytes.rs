@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+
+use super::{HashAlgorithm, HashDigest, Hasher};
+
+/// Hashes a byte slice in one shot with the given algorithm.
+///
+/// ```
+/// use reddish::HashAlgorithm;
+///
+/// let digest = reddish::hash_bytes(HashAlgorithm::Sha1, b"abc");
+/// assert_eq!(digest.to_hex(), "a9993e364706816aba3e25717850c26c9cd0d89d");
+/// ```
+///
+/// ```
+/// use reddish::HashAlgorithm;
+///
+/// let algorithm: HashAlgorithm = "blake3".parse().unwrap();
+/// let digest = reddish::hash_bytes(algorithm, b"");
+/// assert_eq!(digest.to_hex(), "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262");
+/// ```
+pub fn hash_bytes(algorithm: HashAlgorithm, data: &[u8]) -> HashDigest {
+    let mut hasher = Hasher::new(algorithm);
+    hasher.update(data);
+    hasher.finalize()
+}
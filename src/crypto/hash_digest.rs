@@ -0,0 +1,61 @@
+#![allow(dead_code)]
+
+use std::fmt;
+
+use base64::{Engine as _, engine::general_purpose};
+
+/// The output of a hash computation, convertible to hex, Base64 or raw bytes.
+///
+/// ```
+/// use reddish::{Hasher, HashAlgorithm};
+///
+/// let mut hasher = Hasher::new(HashAlgorithm::Sha256);
+/// hasher.update(b"abc");
+/// let digest = hasher.finalize();
+///
+/// assert_eq!(digest.to_hex(), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+/// assert_eq!(digest.to_base64(), "ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0=");
+/// assert_eq!(digest.as_bytes().len(), 32);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HashDigest {
+    bytes: Vec<u8>,
+}
+
+impl HashDigest {
+    pub(crate) fn new(bytes: Vec<u8>) -> Self {
+        HashDigest { bytes }
+    }
+
+    /// Returns the digest as a lowercase hexadecimal string.
+    pub fn to_hex(&self) -> String {
+        hex::encode(&self.bytes)
+    }
+
+    /// Returns the digest as a standard, padded Base64 string.
+    pub fn to_base64(&self) -> String {
+        general_purpose::STANDARD.encode(&self.bytes)
+    }
+
+    /// Returns the raw digest bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consumes the digest and returns the raw bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl AsRef<[u8]> for HashDigest {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl fmt::Display for HashDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
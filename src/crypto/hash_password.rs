@@ -0,0 +1,107 @@
+#![allow(dead_code)]
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use pbkdf2::password_hash::{PasswordHasher, SaltString};
+use pbkdf2::Pbkdf2;
+use rand::rngs::OsRng;
+
+use super::password_hash_error::PasswordHashError;
+use super::password_hash_params::PasswordHashParams;
+
+const BCRYPT_MIN_COST: u32 = 4;
+const BCRYPT_MAX_COST: u32 = 31;
+pub(crate) const BCRYPT_MAX_PASSWORD_LEN: usize = 72;
+const PBKDF2_OUTPUT_LEN: usize = 32;
+
+/// Hashes a password with Argon2id and a random salt, returning a PHC string
+/// suitable for storing. Use [`verify_password`](crate::verify_password) to check it.
+///
+/// ```
+/// let hash = reddish::hash_password("hunter2").unwrap();
+/// assert!(hash.starts_with("$argon2id$v=19$m=19456,t=2,p=1$"));
+/// assert_eq!(reddish::verify_password("hunter2", &hash), Ok(true));
+/// ```
+pub fn hash_password(password: &str) -> Result<String, PasswordHashError> {
+    hash_password_with(password, &PasswordHashParams::default())
+}
+
+/// Hashes a password with the given algorithm and cost parameters and a random salt.
+///
+/// ```
+/// use reddish::PasswordHashParams;
+///
+/// let params = PasswordHashParams::Bcrypt { cost: 4 };
+/// let hash = reddish::hash_password_with("hunter2", &params).unwrap();
+/// assert!(hash.starts_with("$2b$04$"));
+///
+/// let params = PasswordHashParams::Pbkdf2Sha256 { rounds: 1000 };
+/// let hash = reddish::hash_password_with("hunter2", &params).unwrap();
+/// assert!(hash.starts_with("$pbkdf2-sha256$i=1000,l=32$"));
+/// ```
+pub fn hash_password_with(
+    password: &str,
+    params: &PasswordHashParams,
+) -> Result<String, PasswordHashError> {
+    match *params {
+        PasswordHashParams::Argon2id {
+            memory_kib,
+            iterations,
+            parallelism,
+        } => {
+            let argon2 = argon2_hasher(memory_kib, iterations, parallelism)?;
+            let salt = SaltString::generate(&mut OsRng);
+            argon2
+                .hash_password(password.as_bytes(), &salt)
+                .map(|hash| hash.to_string())
+                .map_err(|e| PasswordHashError::Hashing(e.to_string()))
+        }
+        PasswordHashParams::Bcrypt { cost } => {
+            if !(BCRYPT_MIN_COST..=BCRYPT_MAX_COST).contains(&cost) {
+                return Err(PasswordHashError::InvalidParams(format!(
+                    "bcrypt cost must be between {} and {}",
+                    BCRYPT_MIN_COST,
+                    BCRYPT_MAX_COST
+                )));
+            }
+            // bcrypt silently ignores everything past 72 bytes, so refuse rather than truncate
+            if password.len() > BCRYPT_MAX_PASSWORD_LEN {
+                return Err(PasswordHashError::PasswordTooLong);
+            }
+            bcrypt::hash_with_result(password, cost)
+                .map(|parts| parts.format_for_version(bcrypt::Version::TwoB))
+                .map_err(|e| PasswordHashError::Hashing(e.to_string()))
+        }
+        PasswordHashParams::Pbkdf2Sha256 { rounds } => {
+            if rounds == 0 {
+                return Err(PasswordHashError::InvalidParams(
+                    "PBKDF2 rounds must be at least 1".to_string(),
+                ));
+            }
+            let salt = SaltString::generate(&mut OsRng);
+            let pbkdf2_params = pbkdf2::Params {
+                rounds,
+                output_length: PBKDF2_OUTPUT_LEN,
+            };
+            Pbkdf2
+                .hash_password_customized(
+                    password.as_bytes(),
+                    Some(pbkdf2::Algorithm::Pbkdf2Sha256.ident()),
+                    None,
+                    pbkdf2_params,
+                    &salt,
+                )
+                .map(|hash| hash.to_string())
+                .map_err(|e| PasswordHashError::Hashing(e.to_string()))
+        }
+    }
+}
+
+pub(crate) fn argon2_hasher(
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+) -> Result<Argon2<'static>, PasswordHashError> {
+    let params = Params::new(memory_kib, iterations, parallelism, None)
+        .map_err(|e| PasswordHashError::InvalidParams(e.to_string()))?;
+    Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+}
@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use super::{HashAlgorithm, HashDigest, Hasher};
+
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Hashes everything read from `reader`, streaming it through a fixed-size buffer
+/// so the input never needs to fit in memory.
+///
+/// ```
+/// use reddish::HashAlgorithm;
+///
+/// let data = std::io::Cursor::new("hello world");
+/// let digest = reddish::hash_reader(HashAlgorithm::Sha256, data).unwrap();
+/// assert_eq!(digest.to_hex(), "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9");
+/// ```
+pub fn hash_reader<R: Read>(algorithm: HashAlgorithm, mut reader: R) -> io::Result<HashDigest> {
+    let mut hasher = Hasher::new(algorithm);
+    let mut buffer = vec![0u8; BUFFER_SIZE];
+
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(n) => hasher.update(&buffer[..n]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(hasher.finalize())
+}
+
+/// Hashes the contents of the file at `path` without loading it into memory.
+///
+/// ```no_run
+/// use reddish::HashAlgorithm;
+///
+/// let digest = reddish::hash_file(HashAlgorithm::Sha256, "release.tar.gz").unwrap();
+/// println!("{}", digest.to_hex());
+/// ```
+pub fn hash_file<P: AsRef<Path>>(algorithm: HashAlgorithm, path: P) -> io::Result<HashDigest> {
+    hash_reader(algorithm, File::open(path)?)
+}
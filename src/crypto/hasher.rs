@@ -0,0 +1,132 @@
+#![allow(dead_code)]
+
+use std::io;
+
+use blake2::{Blake2b512, Blake2s256};
+use sha1::Sha1;
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512, Sha512_256};
+use sha3::{Sha3_256, Sha3_512};
+
+use super::{HashAlgorithm, HashDigest};
+
+/// An incremental hasher that accepts data in pieces via [`update`](Hasher::update)
+/// and produces a [`HashDigest`] on [`finalize`](Hasher::finalize).
+/// It also implements [`std::io::Write`], so it can be used with [`std::io::copy`].
+///
+/// ```
+/// use reddish::{Hasher, HashAlgorithm};
+///
+/// let mut hasher = Hasher::new(HashAlgorithm::Md5);
+/// hasher.update("hello ");
+/// hasher.update("world");
+/// assert_eq!(hasher.finalize().to_hex(), reddish::md5_hash("hello world"));
+/// ```
+///
+/// ```
+/// use reddish::{Hasher, HashAlgorithm};
+///
+/// let mut hasher = Hasher::new(HashAlgorithm::Sha256);
+/// std::io::copy(&mut &b"hello world"[..], &mut hasher).unwrap();
+/// assert_eq!(hasher.finalize().to_hex(), reddish::sha256_hash("hello world"));
+/// ```
+#[derive(Clone)]
+pub struct Hasher {
+    algorithm: HashAlgorithm,
+    state: HasherState,
+}
+
+#[derive(Clone)]
+enum HasherState {
+    Md5(md5::Context),
+    Sha1(Sha1),
+    Sha224(Sha224),
+    Sha256(Sha256),
+    Sha384(Sha384),
+    Sha512(Sha512),
+    Sha512_256(Sha512_256),
+    Sha3_256(Sha3_256),
+    Sha3_512(Sha3_512),
+    Blake2b(Blake2b512),
+    Blake2s(Blake2s256),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl Hasher {
+    /// Creates a new hasher for the given algorithm.
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        let state = match algorithm {
+            HashAlgorithm::Md5 => HasherState::Md5(md5::Context::new()),
+            HashAlgorithm::Sha1 => HasherState::Sha1(Sha1::new()),
+            HashAlgorithm::Sha224 => HasherState::Sha224(Sha224::new()),
+            HashAlgorithm::Sha256 => HasherState::Sha256(Sha256::new()),
+            HashAlgorithm::Sha384 => HasherState::Sha384(Sha384::new()),
+            HashAlgorithm::Sha512 => HasherState::Sha512(Sha512::new()),
+            HashAlgorithm::Sha512_256 => HasherState::Sha512_256(Sha512_256::new()),
+            HashAlgorithm::Sha3_256 => HasherState::Sha3_256(Sha3_256::new()),
+            HashAlgorithm::Sha3_512 => HasherState::Sha3_512(Sha3_512::new()),
+            HashAlgorithm::Blake2b => HasherState::Blake2b(Blake2b512::new()),
+            HashAlgorithm::Blake2s => HasherState::Blake2s(Blake2s256::new()),
+            HashAlgorithm::Blake3 => HasherState::Blake3(Box::new(blake3::Hasher::new())),
+        };
+
+        Hasher { algorithm, state }
+    }
+
+    /// Returns the algorithm this hasher uses.
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// Feeds more data into the hasher.
+    pub fn update(&mut self, data: impl AsRef<[u8]>) {
+        let data = data.as_ref();
+
+        match &mut self.state {
+            HasherState::Md5(context) => context.consume(data),
+            HasherState::Sha1(hasher) => hasher.update(data),
+            HasherState::Sha224(hasher) => hasher.update(data),
+            HasherState::Sha256(hasher) => hasher.update(data),
+            HasherState::Sha384(hasher) => hasher.update(data),
+            HasherState::Sha512(hasher) => hasher.update(data),
+            HasherState::Sha512_256(hasher) => hasher.update(data),
+            HasherState::Sha3_256(hasher) => hasher.update(data),
+            HasherState::Sha3_512(hasher) => hasher.update(data),
+            HasherState::Blake2b(hasher) => hasher.update(data),
+            HasherState::Blake2s(hasher) => hasher.update(data),
+            HasherState::Blake3(hasher) => {
+                hasher.update(data);
+            }
+        }
+    }
+
+    /// Consumes the hasher and returns the digest of all data fed so far.
+    pub fn finalize(self) -> HashDigest {
+        let bytes = match self.state {
+            HasherState::Md5(context) => context.compute().0.to_vec(),
+            HasherState::Sha1(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha224(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha256(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha384(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha512(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha512_256(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha3_256(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha3_512(hasher) => hasher.finalize().to_vec(),
+            HasherState::Blake2b(hasher) => hasher.finalize().to_vec(),
+            HasherState::Blake2s(hasher) => hasher.finalize().to_vec(),
+            HasherState::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
+        };
+
+        HashDigest::new(bytes)
+    }
+}
+
+impl io::Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
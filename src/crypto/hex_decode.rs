@@ -1,5 +1,7 @@
 #![allow(dead_code)]
 
+use super::DecodeError;
+
 /// Decodes a hexadecimal string. Returns None if the input is invalid.
 ///
 /// ```
@@ -22,3 +24,43 @@ pub fn hex_decode(data: &str) -> Option<String> {
         Err(_) => None,
     }
 }
+
+/// Decodes upper- or lowercase hexadecimal into raw bytes, ignoring whitespace.
+///
+/// ```
+/// let result = reddish::hex_decode_bytes("DE AD be ef\n00");
+/// assert_eq!(result, Ok(vec![0xde, 0xad, 0xbe, 0xef, 0x00]));
+/// ```
+///
+/// ```
+/// use reddish::DecodeError;
+///
+/// assert_eq!(reddish::hex_decode_bytes("abc"), Err(DecodeError::InvalidLength { length: 3 }));
+/// ```
+pub fn hex_decode_bytes(data: &str) -> Result<Vec<u8>, DecodeError> {
+    let mut bytes = Vec::with_capacity(data.len() / 2);
+    let mut high = None;
+    let mut length = 0;
+
+    for (position, character) in data.char_indices() {
+        if character.is_whitespace() {
+            continue;
+        }
+
+        let nibble = character
+            .to_digit(16)
+            .ok_or(DecodeError::InvalidCharacter { character, position })? as u8;
+        length += 1;
+
+        match high.take() {
+            Some(high) => bytes.push(high << 4 | nibble),
+            None => high = Some(nibble),
+        }
+    }
+
+    if high.is_some() {
+        return Err(DecodeError::InvalidLength { length });
+    }
+
+    Ok(bytes)
+}
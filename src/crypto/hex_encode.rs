@@ -14,3 +14,23 @@
 pub fn hex_encode(data: &str) -> String {
     hex::encode(data.as_bytes())
 }
+
+/// Encodes arbitrary bytes to lowercase hexadecimal.
+///
+/// ```
+/// let result = reddish::hex_encode_bytes(&[0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(result, "deadbeef");
+/// ```
+pub fn hex_encode_bytes(data: &[u8]) -> String {
+    hex::encode(data)
+}
+
+/// Encodes arbitrary bytes to uppercase hexadecimal.
+///
+/// ```
+/// let result = reddish::hex_encode_upper(&[0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(result, "DEADBEEF");
+/// ```
+pub fn hex_encode_upper(data: &[u8]) -> String {
+    hex::encode_upper(data)
+}
@@ -0,0 +1,73 @@
+#![allow(dead_code)]
+
+use super::{constant_time_eq, hash_bytes, HashAlgorithm, HashDigest, Hasher};
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5c;
+
+/// Computes an HMAC (RFC 2104) of `data` with `key` using any [`HashAlgorithm`].
+///
+/// ```
+/// use reddish::HashAlgorithm;
+///
+/// let data = b"The quick brown fox jumps over the lazy dog";
+/// let mac = reddish::hmac(HashAlgorithm::Sha1, b"key", data);
+/// assert_eq!(mac.to_hex(), "de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9");
+/// ```
+pub fn hmac(algorithm: HashAlgorithm, key: &[u8], data: &[u8]) -> HashDigest {
+    let block_size = algorithm.block_size();
+
+    // Keys longer than a block are hashed first; shorter keys are zero-padded
+    let mut block_key = if key.len() > block_size {
+        hash_bytes(algorithm, key).into_bytes()
+    } else {
+        key.to_vec()
+    };
+    block_key.resize(block_size, 0);
+
+    let inner_key: Vec<u8> = block_key.iter().map(|b| b ^ IPAD).collect();
+    let outer_key: Vec<u8> = block_key.iter().map(|b| b ^ OPAD).collect();
+
+    let mut inner = Hasher::new(algorithm);
+    inner.update(&inner_key);
+    inner.update(data);
+
+    let mut outer = Hasher::new(algorithm);
+    outer.update(&outer_key);
+    outer.update(inner.finalize());
+    outer.finalize()
+}
+
+/// Computes the HMAC-SHA256 of `data` with `key`.
+///
+/// ```
+/// let mac = reddish::hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+/// assert_eq!(mac.to_hex(), "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+/// ```
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> HashDigest {
+    hmac(HashAlgorithm::Sha256, key, data)
+}
+
+/// Computes the HMAC-SHA512 of `data` with `key`.
+///
+/// ```
+/// let mac = reddish::hmac_sha512(b"Jefe", b"what do ya want for nothing?");
+/// assert_eq!(mac.as_bytes().len(), 64);
+/// assert!(mac.to_hex().starts_with("164b7a7bfcf819e2"));
+/// ```
+pub fn hmac_sha512(key: &[u8], data: &[u8]) -> HashDigest {
+    hmac(HashAlgorithm::Sha512, key, data)
+}
+
+/// Checks an expected raw HMAC tag against `data` in constant time.
+///
+/// ```
+/// use reddish::HashAlgorithm;
+///
+/// let tag = reddish::hmac_sha256(b"secret", b"payload");
+/// assert!(reddish::verify_hmac(HashAlgorithm::Sha256, b"secret", b"payload", tag.as_bytes()));
+/// assert!(!reddish::verify_hmac(HashAlgorithm::Sha256, b"secret", b"tampered", tag.as_bytes()));
+/// ```
+pub fn verify_hmac(algorithm: HashAlgorithm, key: &[u8], data: &[u8], expected: &[u8]) -> bool {
+    constant_time_eq(hmac(algorithm, key, data).as_bytes(), expected)
+}
@@ -0,0 +1,206 @@
+#![allow(dead_code)]
+
+use base64::{Engine as _, engine::general_purpose};
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use super::base64_encode::base64_encode_with;
+use super::base64_variant::Base64Variant;
+use super::constant_time_eq::constant_time_eq;
+use super::hmac::hmac;
+use super::jwt_algorithm::JwtAlgorithm;
+use super::jwt_error::JwtError;
+use super::jwt_validation::JwtValidation;
+
+/// Signs a set of claims as a compact JWT (RFC 7519) using HMAC.
+///
+/// The claims can be any value that serializes to a JSON object, such as a
+/// `serde_json::Value` or a `#[derive(Serialize)]` struct.
+///
+/// ```
+/// use reddish::{JwtAlgorithm, JwtValidation};
+/// use serde_json::json;
+///
+/// let claims = json!({ "sub": "42", "exp": 4102444800u64 });
+/// let token = reddish::jwt_encode(&claims, b"secret", JwtAlgorithm::Hs256).unwrap();
+/// assert!(token.starts_with("eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9."));
+///
+/// let validation = JwtValidation::default();
+/// let decoded: serde_json::Value = reddish::jwt_decode(&token, b"secret", &validation).unwrap();
+/// assert_eq!(decoded["sub"], "42");
+/// ```
+pub fn jwt_encode<T: Serialize>(
+    claims: &T,
+    key: &[u8],
+    algorithm: JwtAlgorithm,
+) -> Result<String, JwtError> {
+    if key.is_empty() {
+        return Err(JwtError::InvalidKey);
+    }
+
+    let payload = serde_json::to_value(claims).map_err(|e| JwtError::Serialization(e.to_string()))?;
+    if !payload.is_object() {
+        return Err(JwtError::Serialization("claims must be a JSON object".to_string()));
+    }
+
+    let header = json!({ "alg": algorithm.name(), "typ": "JWT" });
+    let signing_input = format!("{}.{}", encode_segment(&header), encode_segment(&payload));
+    let signature = hmac(algorithm.hash_algorithm(), key, signing_input.as_bytes());
+
+    Ok(format!(
+        "{}.{}",
+        signing_input,
+        base64_encode_with(signature.as_bytes(), Base64Variant::UrlSafeNoPad)
+    ))
+}
+
+/// Verifies a JWT's signature, validates its registered claims and returns the claims.
+///
+/// The signature is checked before any claim is trusted. Each failure has its own
+/// [`JwtError`] variant so callers can tell an expired token from a forged one.
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use reddish::{JwtAlgorithm, JwtError, JwtValidation};
+/// use serde_json::{json, Value};
+///
+/// let issued = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+/// let claims = json!({ "sub": "42", "iat": issued.timestamp(), "exp": issued.timestamp() + 60 });
+/// let token = reddish::jwt_encode(&claims, b"secret", JwtAlgorithm::Hs256).unwrap();
+///
+/// let later = JwtValidation::default().at(issued + Duration::minutes(5));
+/// assert_eq!(reddish::jwt_decode::<Value>(&token, b"secret", &later), Err(JwtError::Expired));
+///
+/// let wrong_key = JwtValidation::default().at(issued);
+/// let result = reddish::jwt_decode::<Value>(&token, b"guess", &wrong_key);
+/// assert_eq!(result, Err(JwtError::InvalidSignature));
+/// ```
+pub fn jwt_decode<T: DeserializeOwned>(
+    token: &str,
+    key: &[u8],
+    validation: &JwtValidation,
+) -> Result<T, JwtError> {
+    if key.is_empty() {
+        return Err(JwtError::InvalidKey);
+    }
+
+    let mut segments = token.split('.');
+    let parts = (segments.next(), segments.next(), segments.next(), segments.next());
+    let (header, payload, signature) = match parts {
+        (Some(header), Some(payload), Some(signature), None) => (header, payload, signature),
+        _ => return Err(JwtError::Malformed),
+    };
+
+    let algorithm = match decode_segment(header)?.get("alg").and_then(Value::as_str) {
+        Some(name) => JwtAlgorithm::from_name(name)
+            .ok_or_else(|| JwtError::UnsupportedAlgorithm(name.to_string()))?,
+        None => return Err(JwtError::Malformed),
+    };
+    if !validation.algorithms.contains(&algorithm) {
+        return Err(JwtError::AlgorithmNotAllowed(algorithm.name().to_string()));
+    }
+
+    let signature = decode_strict(signature)?;
+    let signing_input = &token[..header.len() + 1 + payload.len()];
+    let expected = hmac(algorithm.hash_algorithm(), key, signing_input.as_bytes());
+    if !constant_time_eq(expected.as_bytes(), &signature) {
+        return Err(JwtError::InvalidSignature);
+    }
+
+    let claims = decode_segment(payload)?;
+    validate_claims(&claims, validation)?;
+
+    serde_json::from_value(Value::Object(claims))
+        .map_err(|e| JwtError::Serialization(e.to_string()))
+}
+
+fn validate_claims(
+    claims: &Map<String, Value>,
+    validation: &JwtValidation,
+) -> Result<(), JwtError> {
+    for claim in &validation.required_claims {
+        if !claims.contains_key(claim) {
+            return Err(JwtError::MissingClaim(claim.clone()));
+        }
+    }
+
+    let now = validation.now.unwrap_or_else(Utc::now).timestamp() as f64;
+    let leeway = validation.leeway.num_seconds() as f64;
+
+    if validation.validate_exp {
+        if let Some(exp) = numeric_date(claims, "exp")? {
+            if now - leeway >= exp {
+                return Err(JwtError::Expired);
+            }
+        }
+    }
+    if validation.validate_nbf {
+        if let Some(nbf) = numeric_date(claims, "nbf")? {
+            if now + leeway < nbf {
+                return Err(JwtError::NotYetValid);
+            }
+        }
+    }
+    if validation.validate_iat {
+        if let Some(iat) = numeric_date(claims, "iat")? {
+            if now + leeway < iat {
+                return Err(JwtError::IssuedInFuture);
+            }
+        }
+    }
+
+    if !validation.audience.is_empty() {
+        let accepted = |aud: &Value| {
+            aud.as_str().map_or(false, |aud| validation.audience.iter().any(|a| a == aud))
+        };
+        let matches = match claims.get("aud") {
+            None => return Err(JwtError::MissingClaim("aud".to_string())),
+            Some(Value::Array(audiences)) => audiences.iter().any(accepted),
+            Some(aud @ Value::String(_)) => accepted(aud),
+            Some(_) => return Err(JwtError::InvalidClaim("aud".to_string())),
+        };
+        if !matches {
+            return Err(JwtError::InvalidAudience);
+        }
+    }
+
+    if let Some(issuer) = &validation.issuer {
+        match claims.get("iss") {
+            None => return Err(JwtError::MissingClaim("iss".to_string())),
+            Some(Value::String(iss)) if iss == issuer => {}
+            Some(Value::String(_)) => return Err(JwtError::InvalidIssuer),
+            Some(_) => return Err(JwtError::InvalidClaim("iss".to_string())),
+        }
+    }
+
+    Ok(())
+}
+
+fn numeric_date(claims: &Map<String, Value>, name: &str) -> Result<Option<f64>, JwtError> {
+    match claims.get(name) {
+        None => Ok(None),
+        Some(value) => {
+            value.as_f64().map(Some).ok_or_else(|| JwtError::InvalidClaim(name.to_string()))
+        }
+    }
+}
+
+fn encode_segment(value: &Value) -> String {
+    base64_encode_with(value.to_string().as_bytes(), Base64Variant::UrlSafeNoPad)
+}
+
+fn decode_segment(segment: &str) -> Result<Map<String, Value>, JwtError> {
+    let bytes = decode_strict(segment)?;
+    match serde_json::from_slice(&bytes) {
+        Ok(Value::Object(map)) => Ok(map),
+        _ => Err(JwtError::Malformed),
+    }
+}
+
+// Only the canonical unpadded URL-safe encoding is accepted, so each token has exactly one
+// valid spelling: no padding, whitespace or stray trailing bits
+fn decode_strict(segment: &str) -> Result<Vec<u8>, JwtError> {
+    general_purpose::URL_SAFE_NO_PAD.decode(segment).map_err(|_| JwtError::Malformed)
+}
@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use std::fmt;
+
+use super::hash_algorithm::HashAlgorithm;
+
+/// HMAC signing algorithms for [`jwt_encode`](crate::jwt_encode) and
+/// [`jwt_decode`](crate::jwt_decode), named as in the JWT `alg` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JwtAlgorithm {
+    Hs256,
+    Hs384,
+    Hs512,
+}
+
+impl JwtAlgorithm {
+    /// Returns the `alg` header value, e.g. `"HS256"`.
+    ///
+    /// ```
+    /// assert_eq!(reddish::JwtAlgorithm::Hs384.name(), "HS384");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            JwtAlgorithm::Hs256 => "HS256",
+            JwtAlgorithm::Hs384 => "HS384",
+            JwtAlgorithm::Hs512 => "HS512",
+        }
+    }
+
+    pub(crate) fn from_name(name: &str) -> Option<JwtAlgorithm> {
+        match name {
+            "HS256" => Some(JwtAlgorithm::Hs256),
+            "HS384" => Some(JwtAlgorithm::Hs384),
+            "HS512" => Some(JwtAlgorithm::Hs512),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn hash_algorithm(&self) -> HashAlgorithm {
+        match self {
+            JwtAlgorithm::Hs256 => HashAlgorithm::Sha256,
+            JwtAlgorithm::Hs384 => HashAlgorithm::Sha384,
+            JwtAlgorithm::Hs512 => HashAlgorithm::Sha512,
+        }
+    }
+}
+
+impl fmt::Display for JwtAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,57 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by [`jwt_encode`](crate::jwt_encode) and [`jwt_decode`](crate::jwt_decode).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JwtError {
+    /// The signing key is empty.
+    InvalidKey,
+    /// The token is not three Base64URL segments of JSON.
+    Malformed,
+    /// The header names an algorithm this crate does not implement, including `none`.
+    UnsupportedAlgorithm(String),
+    /// The header algorithm is not in [`JwtValidation::algorithms`](crate::JwtValidation).
+    AlgorithmNotAllowed(String),
+    /// The signature does not match the key.
+    InvalidSignature,
+    /// The `exp` claim is in the past.
+    Expired,
+    /// The `nbf` claim is in the future.
+    NotYetValid,
+    /// The `iat` claim is in the future.
+    IssuedInFuture,
+    /// The `aud` claim does not match any accepted audience.
+    InvalidAudience,
+    /// The `iss` claim does not match the expected issuer.
+    InvalidIssuer,
+    /// A required claim is absent.
+    MissingClaim(String),
+    /// A registered claim has the wrong type, e.g. a string `exp`.
+    InvalidClaim(String),
+    /// The claims could not be converted to or from JSON.
+    Serialization(String),
+}
+
+impl fmt::Display for JwtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JwtError::InvalidKey => write!(f, "invalid JWT signing key"),
+            JwtError::Malformed => write!(f, "malformed JWT"),
+            JwtError::UnsupportedAlgorithm(alg) => write!(f, "unsupported JWT algorithm: {}", alg),
+            JwtError::AlgorithmNotAllowed(alg) => write!(f, "JWT algorithm not allowed: {}", alg),
+            JwtError::InvalidSignature => write!(f, "invalid JWT signature"),
+            JwtError::Expired => write!(f, "JWT has expired"),
+            JwtError::NotYetValid => write!(f, "JWT is not valid yet"),
+            JwtError::IssuedInFuture => write!(f, "JWT was issued in the future"),
+            JwtError::InvalidAudience => write!(f, "invalid JWT audience"),
+            JwtError::InvalidIssuer => write!(f, "invalid JWT issuer"),
+            JwtError::MissingClaim(claim) => write!(f, "missing JWT claim: {}", claim),
+            JwtError::InvalidClaim(claim) => write!(f, "invalid JWT claim: {}", claim),
+            JwtError::Serialization(reason) => write!(f, "JWT serialization failed: {}", reason),
+        }
+    }
+}
+
+impl Error for JwtError {}
@@ -0,0 +1,94 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::jwt_algorithm::JwtAlgorithm;
+
+/// Rules applied by [`jwt_decode`](crate::jwt_decode).
+///
+/// By default only `HS256` is accepted, `exp` is required, and `exp`, `nbf` and `iat`
+/// are checked against the current time with no leeway. Audience and issuer are only
+/// checked when set.
+///
+/// ```
+/// use chrono::Duration;
+/// use reddish::{JwtAlgorithm, JwtValidation};
+///
+/// let validation = JwtValidation::new(JwtAlgorithm::Hs512)
+///     .with_leeway(Duration::seconds(30))
+///     .with_audience("api")
+///     .with_issuer("auth.example.com");
+/// assert_eq!(validation.algorithms, vec![JwtAlgorithm::Hs512]);
+/// assert_eq!(validation.required_claims, vec!["exp".to_string()]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JwtValidation {
+    /// Algorithms the header may name; anything else is rejected before checking the signature.
+    pub algorithms: Vec<JwtAlgorithm>,
+    /// Clock skew tolerated when checking `exp`, `nbf` and `iat`.
+    pub leeway: Duration,
+    pub validate_exp: bool,
+    pub validate_nbf: bool,
+    pub validate_iat: bool,
+    /// Accepted audiences; the token's `aud` must contain at least one. Empty disables the check.
+    pub audience: Vec<String>,
+    /// Expected `iss` claim.
+    pub issuer: Option<String>,
+    /// Claims that must be present.
+    pub required_claims: Vec<String>,
+    /// The time to validate against; `None` means now.
+    pub now: Option<DateTime<Utc>>,
+}
+
+impl JwtValidation {
+    /// Creates the default rules for a single algorithm.
+    pub fn new(algorithm: JwtAlgorithm) -> Self {
+        JwtValidation {
+            algorithms: vec![algorithm],
+            leeway: Duration::zero(),
+            validate_exp: true,
+            validate_nbf: true,
+            validate_iat: true,
+            audience: Vec::new(),
+            issuer: None,
+            required_claims: vec!["exp".to_string()],
+            now: None,
+        }
+    }
+
+    /// Sets the tolerated clock skew.
+    pub fn with_leeway(mut self, leeway: Duration) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    /// Adds an accepted audience.
+    pub fn with_audience(mut self, audience: &str) -> Self {
+        self.audience.push(audience.to_string());
+        self
+    }
+
+    /// Sets the expected issuer.
+    pub fn with_issuer(mut self, issuer: &str) -> Self {
+        self.issuer = Some(issuer.to_string());
+        self
+    }
+
+    /// Replaces the list of required claims.
+    pub fn with_required_claims(mut self, claims: &[&str]) -> Self {
+        self.required_claims = claims.iter().map(|claim| claim.to_string()).collect();
+        self
+    }
+
+    /// Validates against a fixed time instead of the current time.
+    pub fn at(mut self, now: DateTime<Utc>) -> Self {
+        self.now = Some(now);
+        self
+    }
+}
+
+impl Default for JwtValidation {
+    fn default() -> Self {
+        JwtValidation::new(JwtAlgorithm::Hs256)
+    }
+}
@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 
 /// Computes the MD5 hash of the input data and returns it as a hexadecimal string.
+/// Unsalted and fast, so never use it to store passwords; see
+/// [`hash_password`](crate::hash_password).
 ///
 /// ```
 /// let result = reddish::md5_hash("hello world");
@@ -12,5 +14,16 @@
 /// assert_eq!(result, "d41d8cd98f00b204e9800998ecf8427e");
 /// ```
 pub fn md5_hash(data: &str) -> String {
-    format!("{:x}", md5::compute(data.as_bytes()))
+    md5_hash_bytes(data.as_bytes())
+}
+
+/// Computes the MD5 hash of a byte slice and returns it as a hexadecimal string.
+///
+/// ```
+/// let result = reddish::md5_hash_bytes(&[0xff, 0x00, 0x10]);
+/// assert_eq!(result.len(), 32);
+/// assert_eq!(reddish::md5_hash_bytes(b"hello world"), reddish::md5_hash("hello world"));
+/// ```
+pub fn md5_hash_bytes(data: &[u8]) -> String {
+    format!("{:x}", md5::compute(data))
 }
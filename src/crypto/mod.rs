@@ -4,6 +4,99 @@ pub use md5_hash::*;
 mod sha256_hash;
 pub use sha256_hash::*;
 
+mod hash_algorithm;
+pub use hash_algorithm::*;
+
+mod hash_digest;
+pub use hash_digest::*;
+
+mod hasher;
+pub use hasher::*;
+
+mod hash_bytes;
+pub use hash_bytes::*;
+
+mod hash_reader;
+pub use hash_reader::*;
+
+mod crc;
+pub use self::crc::*;
+
+mod adler32;
+pub use adler32::*;
+
+mod fnv1a;
+pub use fnv1a::*;
+
+mod xxhash;
+pub use xxhash::*;
+
+mod murmur3;
+pub use murmur3::*;
+
+mod checksum;
+pub use checksum::*;
+
+mod password_hash_error;
+pub use password_hash_error::*;
+
+mod password_hash_params;
+pub use password_hash_params::*;
+
+mod hash_password;
+pub use hash_password::*;
+
+mod verify_password;
+pub use verify_password::*;
+
+mod encryption_error;
+pub use encryption_error::*;
+
+mod secret_key;
+pub use secret_key::*;
+
+mod cipher;
+pub use cipher::*;
+
+mod encrypt;
+pub use encrypt::*;
+
+mod encrypt_with_passphrase;
+pub use encrypt_with_passphrase::*;
+
+mod fernet;
+pub use fernet::*;
+
+mod jwt_algorithm;
+pub use jwt_algorithm::*;
+
+mod jwt_error;
+pub use jwt_error::*;
+
+mod jwt_validation;
+pub use jwt_validation::*;
+
+mod jwt;
+pub use jwt::*;
+
+mod sri;
+pub use sri::*;
+
+mod constant_time_eq;
+pub use constant_time_eq::*;
+
+mod hmac;
+pub use hmac::*;
+
+mod webhook_signature;
+pub use webhook_signature::*;
+
+mod decode_error;
+pub use decode_error::*;
+
+mod base64_variant;
+pub use base64_variant::*;
+
 mod base64_encode;
 pub use base64_encode::*;
 
@@ -16,8 +109,43 @@ pub use url_encode::*;
 mod url_decode;
 pub use url_decode::*;
 
+mod url_component;
+pub use url_component::*;
+
+mod form_urlencoded;
+pub use form_urlencoded::*;
+
+mod query_string;
+pub use query_string::*;
+
+mod url_parse_error;
+pub use url_parse_error::*;
+
+mod url;
+pub use self::url::*;
+
 mod hex_encode;
 pub use hex_encode::*;
 
 mod hex_decode;
 pub use hex_decode::*;
+
+mod encoding;
+pub use encoding::*;
+
+mod radix;
+
+mod base32;
+pub use base32::*;
+
+mod base58;
+pub use base58::*;
+
+mod base85;
+pub use base85::*;
+
+mod base36;
+pub use base36::*;
+
+mod base62;
+pub use base62::*;
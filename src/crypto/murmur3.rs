@@ -0,0 +1,160 @@
+#![allow(dead_code)]
+
+const C1_32: u32 = 0xcc9e2d51;
+const C2_32: u32 = 0x1b873593;
+const C1_64: u64 = 0x87c37b91114253d5;
+const C2_64: u64 = 0x4cf5ad432745937f;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Murmur3State {
+    hash: u32,
+    tail: [u8; 4],
+    tail_len: usize,
+    total_len: u64,
+}
+
+impl Murmur3State {
+    pub(crate) fn new(seed: u32) -> Self {
+        Murmur3State {
+            hash: seed,
+            tail: [0; 4],
+            tail_len: 0,
+            total_len: 0,
+        }
+    }
+
+    pub(crate) fn update(&mut self, mut data: &[u8]) {
+        self.total_len += data.len() as u64;
+
+        // Complete a block left over from the previous update first
+        if self.tail_len > 0 {
+            let needed = (4 - self.tail_len).min(data.len());
+            self.tail[self.tail_len..self.tail_len + needed].copy_from_slice(&data[..needed]);
+            self.tail_len += needed;
+            data = &data[needed..];
+
+            if self.tail_len < 4 {
+                return;
+            }
+
+            self.mix_block(u32::from_le_bytes(self.tail));
+            self.tail_len = 0;
+        }
+
+        let mut blocks = data.chunks_exact(4);
+        for block in &mut blocks {
+            self.mix_block(u32::from_le_bytes([block[0], block[1], block[2], block[3]]));
+        }
+
+        let rest = blocks.remainder();
+        self.tail[..rest.len()].copy_from_slice(rest);
+        self.tail_len = rest.len();
+    }
+
+    pub(crate) fn finish(&self) -> u32 {
+        let mut hash = self.hash;
+
+        if self.tail_len > 0 {
+            let mut k = 0u32;
+            for (i, &byte) in self.tail[..self.tail_len].iter().enumerate() {
+                k |= (byte as u32) << (8 * i);
+            }
+            hash ^= mix_k32(k);
+        }
+
+        // The reference implementation folds in the length as a 32-bit value
+        fmix32(hash ^ self.total_len as u32)
+    }
+
+    fn mix_block(&mut self, k: u32) {
+        self.hash ^= mix_k32(k);
+        self.hash = self.hash.rotate_left(13).wrapping_mul(5).wrapping_add(0xe6546b64);
+    }
+}
+
+fn mix_k32(k: u32) -> u32 {
+    k.wrapping_mul(C1_32).rotate_left(15).wrapping_mul(C2_32)
+}
+
+fn fmix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^ (h >> 16)
+}
+
+fn fmix64(mut k: u64) -> u64 {
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xff51afd7ed558ccd);
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xc4ceb9fe1a85ec53);
+    k ^ (k >> 33)
+}
+
+/// Computes the 32-bit MurmurHash3 (x86_32 variant) of the data with the given seed.
+/// Well distributed and fast, but not a cryptographic hash.
+///
+/// ```
+/// assert_eq!(reddish::murmur3_32(b"", 0), 0);
+/// assert_eq!(reddish::murmur3_32(b"", 1), 0x514e28b7);
+/// assert_eq!(reddish::murmur3_32(b"hello", 0), 0x248bfa47);
+/// ```
+pub fn murmur3_32(data: &[u8], seed: u32) -> u32 {
+    let mut state = Murmur3State::new(seed);
+    state.update(data);
+    state.finish()
+}
+
+/// Computes the 128-bit MurmurHash3 (x64_128 variant) of the data with the given seed.
+/// The two 64-bit halves `h1` and `h2` are returned as `h2 << 64 | h1`, matching the
+/// little-endian byte order of the reference output. Not a cryptographic hash.
+///
+/// ```
+/// let hash = reddish::murmur3_x64_128(b"hello", 0);
+/// assert_eq!(hash as u64, 0xcbd8a7b341bd9b02);
+/// assert_eq!((hash >> 64) as u64, 0x5b1e906a48ae1d19);
+/// ```
+pub fn murmur3_x64_128(data: &[u8], seed: u32) -> u128 {
+    let mut h1 = seed as u64;
+    let mut h2 = seed as u64;
+
+    let mut blocks = data.chunks_exact(16);
+    for block in &mut blocks {
+        let k1 = u64::from_le_bytes(block[..8].try_into().unwrap());
+        let k2 = u64::from_le_bytes(block[8..].try_into().unwrap());
+
+        h1 ^= k1.wrapping_mul(C1_64).rotate_left(31).wrapping_mul(C2_64);
+        h1 = h1.rotate_left(27).wrapping_add(h2).wrapping_mul(5).wrapping_add(0x52dce729);
+
+        h2 ^= k2.wrapping_mul(C2_64).rotate_left(33).wrapping_mul(C1_64);
+        h2 = h2.rotate_left(31).wrapping_add(h1).wrapping_mul(5).wrapping_add(0x38495ab5);
+    }
+
+    let tail = blocks.remainder();
+    if tail.len() > 8 {
+        let mut k2 = 0u64;
+        for (i, &byte) in tail[8..].iter().enumerate() {
+            k2 |= (byte as u64) << (8 * i);
+        }
+        h2 ^= k2.wrapping_mul(C2_64).rotate_left(33).wrapping_mul(C1_64);
+    }
+    if !tail.is_empty() {
+        let mut k1 = 0u64;
+        for (i, &byte) in tail.iter().take(8).enumerate() {
+            k1 |= (byte as u64) << (8 * i);
+        }
+        h1 ^= k1.wrapping_mul(C1_64).rotate_left(31).wrapping_mul(C2_64);
+    }
+
+    h1 ^= data.len() as u64;
+    h2 ^= data.len() as u64;
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+    h1 = fmix64(h1);
+    h2 = fmix64(h2);
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+
+    ((h2 as u128) << 64) | h1 as u128
+}
@@ -0,0 +1,40 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by [`hash_password`](crate::hash_password) and
+/// [`verify_password`](crate::verify_password).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordHashError {
+    /// The cost parameters are out of range for the algorithm.
+    InvalidParams(String),
+    /// The stored hash is not a well-formed PHC or bcrypt string.
+    InvalidHash,
+    /// The stored hash uses an algorithm this crate does not verify.
+    UnsupportedAlgorithm(String),
+    /// The password is longer than bcrypt's 72-byte limit.
+    PasswordTooLong,
+    /// Hashing failed for another reason, such as the OS random source.
+    Hashing(String),
+}
+
+impl fmt::Display for PasswordHashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PasswordHashError::InvalidParams(reason) => {
+                write!(f, "invalid password hash parameters: {}", reason)
+            }
+            PasswordHashError::InvalidHash => f.write_str("invalid password hash string"),
+            PasswordHashError::UnsupportedAlgorithm(name) => {
+                write!(f, "unsupported password hash algorithm: {}", name)
+            }
+            PasswordHashError::PasswordTooLong => {
+                f.write_str("password exceeds the 72-byte bcrypt limit")
+            }
+            PasswordHashError::Hashing(reason) => write!(f, "password hashing failed: {}", reason),
+        }
+    }
+}
+
+impl Error for PasswordHashError {}
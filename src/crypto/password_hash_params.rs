@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+
+/// Algorithm and cost parameters for [`hash_password_with`](crate::hash_password_with).
+///
+/// The default is Argon2id with the OWASP-recommended parameters
+/// (19 MiB of memory, 2 iterations, 1 lane).
+///
+/// ```
+/// use reddish::PasswordHashParams;
+///
+/// assert_eq!(PasswordHashParams::default(), PasswordHashParams::argon2id());
+/// assert_eq!(PasswordHashParams::bcrypt(), PasswordHashParams::Bcrypt { cost: 12 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PasswordHashParams {
+    /// Argon2id (RFC 9106), stored as `$argon2id$v=19$m=..,t=..,p=..$salt$hash`.
+    Argon2id {
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    },
+    /// bcrypt, stored in the usual `$2b$<cost>$...` format. Passwords are limited to 72 bytes.
+    Bcrypt { cost: u32 },
+    /// PBKDF2-HMAC-SHA256, stored as `$pbkdf2-sha256$i=..,l=32$salt$hash`.
+    Pbkdf2Sha256 { rounds: u32 },
+}
+
+impl PasswordHashParams {
+    /// Argon2id with 19 MiB of memory, 2 iterations and 1 lane.
+    pub fn argon2id() -> Self {
+        PasswordHashParams::Argon2id {
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+
+    /// bcrypt with a cost of 12.
+    pub fn bcrypt() -> Self {
+        PasswordHashParams::Bcrypt { cost: 12 }
+    }
+
+    /// PBKDF2-HMAC-SHA256 with 600,000 rounds.
+    pub fn pbkdf2_sha256() -> Self {
+        PasswordHashParams::Pbkdf2Sha256 { rounds: 600_000 }
+    }
+}
+
+impl Default for PasswordHashParams {
+    fn default() -> Self {
+        PasswordHashParams::argon2id()
+    }
+}
@@ -0,0 +1,140 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use super::form_urlencoded::{form_urldecode_lossy, form_urlencode};
+
+/// How [`build_query_map`](crate::build_query_map) writes keys with several values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueryArrayFormat {
+    /// `a=1&a=2`
+    Repeat,
+    /// `a[]=1&a[]=2` for keys with more than one value, as used by PHP and Rails
+    Brackets,
+}
+
+/// Parses a query string into decoded key/value pairs, keeping their order and any
+/// repeated keys. A leading `?` is ignored, keys without `=` get an empty value, and
+/// both `+` and `%20` decode to a space. Invalid UTF-8 is replaced rather than rejected.
+///
+/// ```
+/// let pairs = reddish::parse_query("?q=rust+lang&tag=a&tag=b&flag");
+/// assert_eq!(pairs, vec![
+///     ("q".to_string(), "rust lang".to_string()),
+///     ("tag".to_string(), "a".to_string()),
+///     ("tag".to_string(), "b".to_string()),
+///     ("flag".to_string(), "".to_string()),
+/// ]);
+/// ```
+pub fn parse_query(query: &str) -> Vec<(String, String)> {
+    let query = query.strip_prefix('?').unwrap_or(query);
+
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (form_urldecode_lossy(key), form_urldecode_lossy(value))
+        })
+        .collect()
+}
+
+/// Parses a query string into a map from each key to all of its values. Bracketed
+/// array keys are merged with plain ones, so `a[]=1&a[]=2` and `a=1&a=2` both give
+/// `a => ["1", "2"]`. Other brackets, like `a[b]`, are left as part of the key.
+///
+/// ```
+/// let map = reddish::parse_query_map("ids[]=1&ids[]=2&sort=name");
+/// assert_eq!(map["ids"], vec!["1", "2"]);
+/// assert_eq!(map["sort"], vec!["name"]);
+/// ```
+pub fn parse_query_map(query: &str) -> HashMap<String, Vec<String>> {
+    let mut map: HashMap<String, Vec<String>> = HashMap::new();
+
+    for (mut key, value) in parse_query(query) {
+        if key.ends_with("[]") {
+            key.truncate(key.len() - 2);
+        }
+        map.entry(key).or_default().push(value);
+    }
+
+    map
+}
+
+/// Builds a form-encoded query string (without a leading `?`) from key/value pairs,
+/// in iteration order. Accepts a `Vec` of pairs or a map; use a `BTreeMap` or a `Vec`
+/// when the output order matters.
+///
+/// ```
+/// let query = reddish::build_query(vec![("q", "rust lang"), ("page", "2")]);
+/// assert_eq!(query, "q=rust+lang&page=2");
+///
+/// let query = reddish::build_query(&[("a&b".to_string(), "c=d".to_string())]);
+/// assert_eq!(query, "a%26b=c%3Dd");
+/// ```
+pub fn build_query<I, K, V>(pairs: I) -> String
+where
+    I: IntoIterator,
+    I::Item: QueryPair<K, V>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    pairs
+        .into_iter()
+        .map(|pair| {
+            let (key, value) = pair.into_pair();
+            format!("{}={}", form_urlencode(key.as_ref()), form_urlencode(value.as_ref()))
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Builds a query string from a map of keys to value lists, the inverse of
+/// [`parse_query_map`](crate::parse_query_map). Keys are sorted so the output is stable.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use reddish::QueryArrayFormat;
+///
+/// let mut map = HashMap::new();
+/// map.insert("ids".to_string(), vec!["1".to_string(), "2".to_string()]);
+/// map.insert("sort".to_string(), vec!["name".to_string()]);
+///
+/// assert_eq!(reddish::build_query_map(&map, QueryArrayFormat::Repeat), "ids=1&ids=2&sort=name");
+/// let brackets = reddish::build_query_map(&map, QueryArrayFormat::Brackets);
+/// assert_eq!(brackets, "ids[]=1&ids[]=2&sort=name");
+/// ```
+pub fn build_query_map(map: &HashMap<String, Vec<String>>, format: QueryArrayFormat) -> String {
+    let mut keys: Vec<&String> = map.keys().collect();
+    keys.sort();
+
+    let mut parts = Vec::new();
+    for key in keys {
+        let values = &map[key];
+        let suffix =
+            if format == QueryArrayFormat::Brackets && values.len() > 1 { "[]" } else { "" };
+        for value in values {
+            parts.push(format!("{}{}={}", form_urlencode(key), suffix, form_urlencode(value)));
+        }
+    }
+
+    parts.join("&")
+}
+
+/// A key/value pair accepted by [`build_query`](crate::build_query): an owned tuple or a
+/// reference to one, as yielded by iterating a `Vec`, slice or map.
+pub trait QueryPair<K, V> {
+    fn into_pair(self) -> (K, V);
+}
+
+impl<K, V> QueryPair<K, V> for (K, V) {
+    fn into_pair(self) -> (K, V) {
+        self
+    }
+}
+
+impl<'a, K, V> QueryPair<&'a K, &'a V> for &'a (K, V) {
+    fn into_pair(self) -> (&'a K, &'a V) {
+        (&self.0, &self.1)
+    }
+}
@@ -0,0 +1,116 @@
+#![allow(dead_code)]
+
+use super::DecodeError;
+
+/// Encodes bytes as a big-endian number in the base given by the alphabet's length.
+/// Each leading zero byte is kept as a leading zero digit, as in Base58.
+pub(crate) fn radix_encode(data: &[u8], alphabet: &[u8]) -> String {
+    let base = alphabet.len() as u32;
+    let zeros = data.iter().take_while(|&&b| b == 0).count();
+
+    // Digits in little-endian order
+    let mut digits: Vec<u8> = Vec::with_capacity(data.len() * 2);
+
+    for &byte in &data[zeros..] {
+        let mut carry = byte as u32;
+
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % base) as u8;
+            carry /= base;
+        }
+
+        while carry > 0 {
+            digits.push((carry % base) as u8);
+            carry /= base;
+        }
+    }
+
+    let mut result = String::with_capacity(zeros + digits.len());
+    result.extend(std::iter::repeat(alphabet[0] as char).take(zeros));
+    result.extend(digits.iter().rev().map(|&d| alphabet[d as usize] as char));
+    result
+}
+
+/// Inverse of [`radix_encode`]. Whitespace is ignored.
+pub(crate) fn radix_decode(data: &str, alphabet: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let base = alphabet.len() as u32;
+    let mut values = Vec::with_capacity(data.len());
+
+    for (position, character) in data.char_indices() {
+        if character.is_whitespace() {
+            continue;
+        }
+
+        let value = alphabet
+            .iter()
+            .position(|&c| c as char == character)
+            .ok_or(DecodeError::InvalidCharacter { character, position })?;
+        values.push(value as u32);
+    }
+
+    let zeros = values.iter().take_while(|&&v| v == 0).count();
+
+    // Bytes in little-endian order
+    let mut bytes: Vec<u8> = Vec::with_capacity(values.len());
+
+    for &value in &values[zeros..] {
+        let mut carry = value;
+
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * base;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut result = vec![0u8; zeros];
+    result.extend(bytes.iter().rev());
+    Ok(result)
+}
+
+/// Encodes an integer using the alphabet's length as the base.
+pub(crate) fn radix_encode_u64(mut value: u64, alphabet: &[u8]) -> String {
+    let base = alphabet.len() as u64;
+    let mut digits = Vec::new();
+
+    loop {
+        digits.push(alphabet[(value % base) as usize]);
+        value /= base;
+
+        if value == 0 {
+            break;
+        }
+    }
+
+    digits.iter().rev().map(|&d| d as char).collect()
+}
+
+/// Inverse of [`radix_encode_u64`].
+pub(crate) fn radix_decode_u64(data: &str, alphabet: &[u8]) -> Result<u64, DecodeError> {
+    if data.is_empty() {
+        return Err(DecodeError::InvalidLength { length: 0 });
+    }
+
+    let base = alphabet.len() as u64;
+    let mut value: u64 = 0;
+
+    for (position, character) in data.char_indices() {
+        let digit = alphabet
+            .iter()
+            .position(|&c| c as char == character)
+            .ok_or(DecodeError::InvalidCharacter { character, position })?;
+
+        value = value
+            .checked_mul(base)
+            .and_then(|v| v.checked_add(digit as u64))
+            .ok_or(DecodeError::Overflow)?;
+    }
+
+    Ok(value)
+}
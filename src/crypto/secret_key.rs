@@ -0,0 +1,132 @@
+#![allow(dead_code)]
+
+use std::fmt;
+
+use hkdf::Hkdf;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::Sha256;
+
+use super::constant_time_eq::constant_time_eq;
+use super::encryption_error::EncryptionError;
+use super::hash_password::argon2_hasher;
+use super::password_hash_params::PasswordHashParams;
+
+/// Length in bytes of a [`SecretKey`].
+pub const SECRET_KEY_LEN: usize = 32;
+
+/// A 256-bit key for [`encrypt`](crate::encrypt) and [`decrypt`](crate::decrypt).
+///
+/// Keys are generated from the OS random source, loaded from existing bytes, or derived
+/// from a passphrase or other key material. `Debug` never prints the key, and keys are
+/// compared in constant time.
+///
+/// ```
+/// use reddish::SecretKey;
+///
+/// let key = SecretKey::generate();
+/// assert_eq!(key.as_bytes().len(), 32);
+/// assert_eq!(format!("{:?}", key), "SecretKey(..)");
+///
+/// assert!(SecretKey::from_bytes(&[0u8; 16]).is_err());
+/// ```
+#[derive(Clone)]
+pub struct SecretKey([u8; SECRET_KEY_LEN]);
+
+impl SecretKey {
+    /// Generates a new random key.
+    pub fn generate() -> Self {
+        let mut key = [0u8; SECRET_KEY_LEN];
+        OsRng.fill_bytes(&mut key);
+        SecretKey(key)
+    }
+
+    /// Loads a key from exactly 32 bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EncryptionError> {
+        let key: [u8; SECRET_KEY_LEN] = bytes.try_into().map_err(|_| EncryptionError::InvalidKey)?;
+        Ok(SecretKey(key))
+    }
+
+    /// Derives a key from a human-chosen passphrase with Argon2id using the default
+    /// [`PasswordHashParams`]. The salt must be at least 8 bytes and stored alongside the
+    /// ciphertext; [`encrypt_with_passphrase`](crate::encrypt_with_passphrase) does this for you.
+    ///
+    /// ```
+    /// use reddish::SecretKey;
+    ///
+    /// let a = SecretKey::from_passphrase("correct horse", b"per-user-salt").unwrap();
+    /// let b = SecretKey::from_passphrase("correct horse", b"per-user-salt").unwrap();
+    /// assert_eq!(a, b);
+    /// assert!(SecretKey::from_passphrase("correct horse", b"short").is_err());
+    /// ```
+    pub fn from_passphrase(passphrase: &str, salt: &[u8]) -> Result<Self, EncryptionError> {
+        Self::from_passphrase_with(passphrase, salt, &PasswordHashParams::argon2id())
+    }
+
+    /// Derives a key from a passphrase with custom Argon2id costs.
+    /// Only [`PasswordHashParams::Argon2id`] is accepted.
+    pub fn from_passphrase_with(
+        passphrase: &str,
+        salt: &[u8],
+        params: &PasswordHashParams,
+    ) -> Result<Self, EncryptionError> {
+        let argon2 = match *params {
+            PasswordHashParams::Argon2id {
+                memory_kib,
+                iterations,
+                parallelism,
+            } => argon2_hasher(memory_kib, iterations, parallelism)
+                .map_err(|e| EncryptionError::KeyDerivation(e.to_string()))?,
+            _ => {
+                return Err(EncryptionError::KeyDerivation(
+                    "passphrase keys require Argon2id parameters".to_string(),
+                ))
+            }
+        };
+
+        let mut key = [0u8; SECRET_KEY_LEN];
+        argon2
+            .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+            .map_err(|e| EncryptionError::KeyDerivation(e.to_string()))?;
+        Ok(SecretKey(key))
+    }
+
+    /// Derives a key from existing high-entropy key material with HKDF-SHA256 (RFC 5869).
+    /// Use a distinct `info` per purpose so one master key can yield independent subkeys.
+    /// Not suitable for passphrases; use [`from_passphrase`](SecretKey::from_passphrase).
+    ///
+    /// ```
+    /// use reddish::SecretKey;
+    ///
+    /// let master = [7u8; 32];
+    /// let cookies = SecretKey::from_hkdf(&master, b"salt", b"cookies");
+    /// let uploads = SecretKey::from_hkdf(&master, b"salt", b"uploads");
+    /// assert_ne!(cookies, uploads);
+    /// ```
+    pub fn from_hkdf(input_key_material: &[u8], salt: &[u8], info: &[u8]) -> Self {
+        let mut key = [0u8; SECRET_KEY_LEN];
+        Hkdf::<Sha256>::new(Some(salt), input_key_material)
+            .expand(info, &mut key)
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+        SecretKey(key)
+    }
+
+    /// Returns the raw key bytes, e.g. to store the key in a secrets manager.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SecretKey {}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SecretKey(..)")
+    }
+}
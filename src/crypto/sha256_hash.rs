@@ -3,6 +3,8 @@
 use sha2::{Sha256, Digest};
 
 /// Computes the SHA256 hash of the input data and returns it as a hexadecimal string.
+/// Unsalted and fast, so never use it to store passwords; see
+/// [`hash_password`](crate::hash_password).
 ///
 /// ```
 /// let result = reddish::sha256_hash("hello world");
@@ -14,7 +16,18 @@ use sha2::{Sha256, Digest};
 /// assert_eq!(result, "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
 /// ```
 pub fn sha256_hash(data: &str) -> String {
+    sha256_hash_bytes(data.as_bytes())
+}
+
+/// Computes the SHA256 hash of a byte slice and returns it as a hexadecimal string.
+///
+/// ```
+/// let result = reddish::sha256_hash_bytes(&[0xff, 0x00, 0x10]);
+/// assert_eq!(result.len(), 64);
+/// assert_eq!(reddish::sha256_hash_bytes(b"abc"), reddish::sha256_hash("abc"));
+/// ```
+pub fn sha256_hash_bytes(data: &[u8]) -> String {
     let mut hasher = Sha256::new();
-    hasher.update(data.as_bytes());
+    hasher.update(data);
     format!("{:x}", hasher.finalize())
 }
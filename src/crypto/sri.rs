@@ -0,0 +1,73 @@
+#![allow(dead_code)]
+
+use base64::{Engine as _, engine::general_purpose};
+
+use super::{hash_bytes, HashAlgorithm};
+
+/// Computes a Subresource Integrity string (`<algorithm>-<base64 digest>`) for the data.
+/// The SRI specification only defines `sha256`, `sha384` and `sha512`, so `None` is
+/// returned for any other algorithm.
+///
+/// ```
+/// use reddish::HashAlgorithm;
+///
+/// let result = reddish::sri_hash(HashAlgorithm::Sha384, b"alert('Hello, world.');");
+/// let expected = "sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO";
+/// assert_eq!(result.unwrap(), expected);
+/// assert_eq!(reddish::sri_hash(HashAlgorithm::Md5, b"data"), None);
+/// ```
+pub fn sri_hash(algorithm: HashAlgorithm, data: &[u8]) -> Option<String> {
+    sri_strength(algorithm)?;
+    Some(format!("{}-{}", algorithm.name(), hash_bytes(algorithm, data).to_base64()))
+}
+
+/// Checks data against a Subresource Integrity string such as `sha384-...`.
+///
+/// As in browsers, the string may hold several space-separated tokens, and only tokens
+/// of the strongest listed algorithm are checked; the data passes if any of them
+/// matches. Tokens for algorithms other than `sha256`, `sha384` and `sha512` are
+/// ignored, and `?` options after the digest are skipped. Unlike browsers, a string
+/// without any usable token fails.
+///
+/// ```
+/// let integrity = "sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO";
+/// assert!(reddish::verify_sri(b"alert('Hello, world.');", integrity));
+/// assert!(!reddish::verify_sri(b"alert('Goodbye.');", integrity));
+/// ```
+pub fn verify_sri(data: &[u8], integrity: &str) -> bool {
+    let tokens: Vec<(HashAlgorithm, &str)> = integrity
+        .split_whitespace()
+        .filter_map(|token| {
+            let (name, rest) = token.split_once('-')?;
+            let algorithm = [HashAlgorithm::Sha256, HashAlgorithm::Sha384, HashAlgorithm::Sha512]
+                .iter()
+                .copied()
+                .find(|algorithm| algorithm.name() == name)?;
+            Some((algorithm, rest.split('?').next().unwrap_or(rest)))
+        })
+        .collect();
+
+    let strengths = tokens.iter().filter_map(|(algorithm, _)| sri_strength(*algorithm));
+    let strongest = match strengths.max() {
+        Some(strength) => strength,
+        None => return false,
+    };
+
+    tokens
+        .iter()
+        .filter(|(algorithm, _)| sri_strength(*algorithm) == Some(strongest))
+        .any(|(algorithm, encoded)| match general_purpose::STANDARD.decode(encoded) {
+            Ok(expected) => hash_bytes(*algorithm, data).as_bytes() == expected.as_slice(),
+            Err(_) => false,
+        })
+}
+
+// The algorithms allowed in SRI, ranked from weakest to strongest
+fn sri_strength(algorithm: HashAlgorithm) -> Option<u8> {
+    match algorithm {
+        HashAlgorithm::Sha256 => Some(1),
+        HashAlgorithm::Sha384 => Some(2),
+        HashAlgorithm::Sha512 => Some(3),
+        _ => None,
+    }
+}
@@ -0,0 +1,555 @@
+#![allow(dead_code)]
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::form_urlencoded::{form_urldecode_lossy, form_urlencode};
+use super::query_string::parse_query;
+use super::url_parse_error::UrlParseError;
+
+/// An absolute URL split into its RFC 3986 components.
+///
+/// Components are stored as they appear in the URL, still percent-encoded; the scheme
+/// is lowercased since it is case-insensitive. Use [`normalize`](Url::normalize) to
+/// compare URLs and [`resolve`](Url::resolve) to follow relative links.
+///
+/// ```
+/// use reddish::Url;
+///
+/// let url = Url::parse("https://user:pw@Example.com:8080/a/b?q=1#top").unwrap();
+/// assert_eq!(url.scheme(), "https");
+/// assert_eq!(url.username(), "user");
+/// assert_eq!(url.password(), Some("pw"));
+/// assert_eq!(url.host(), Some("Example.com"));
+/// assert_eq!(url.port(), Some(8080));
+/// assert_eq!(url.path(), "/a/b");
+/// assert_eq!(url.query(), Some("q=1"));
+/// assert_eq!(url.fragment(), Some("top"));
+/// assert_eq!(url.to_string(), "https://user:pw@Example.com:8080/a/b?q=1#top");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Url {
+    scheme: String,
+    authority: Option<Authority>,
+    path: String,
+    query: Option<String>,
+    fragment: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Authority {
+    userinfo: Option<String>,
+    host: String,
+    port: Option<u16>,
+}
+
+// A URI reference split per RFC 3986 Appendix B, before any validation of the parts
+struct Reference<'a> {
+    scheme: Option<&'a str>,
+    authority: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+impl Url {
+    /// Parses an absolute URL. Relative references such as `/path` or `../x` are rejected
+    /// with [`UrlParseError::MissingScheme`]; resolve them against a base with
+    /// [`resolve`](Url::resolve) instead.
+    ///
+    /// ```
+    /// use reddish::{Url, UrlParseError};
+    ///
+    /// assert!(Url::parse("mailto:someone@example.com").is_ok());
+    /// assert_eq!(Url::parse("/relative/path"), Err(UrlParseError::MissingScheme));
+    /// assert_eq!(Url::parse("http://example.com:99999"), Err(UrlParseError::InvalidPort));
+    /// ```
+    pub fn parse(input: &str) -> Result<Url, UrlParseError> {
+        let reference = split_reference(input)?;
+        let scheme = reference.scheme.ok_or(UrlParseError::MissingScheme)?;
+
+        Ok(Url {
+            scheme: scheme.to_ascii_lowercase(),
+            authority: reference.authority.map(parse_authority).transpose()?,
+            path: reference.path.to_string(),
+            query: reference.query.map(str::to_string),
+            fragment: reference.fragment.map(str::to_string),
+        })
+    }
+
+    /// Returns the lowercased scheme, e.g. `"https"`.
+    pub fn scheme(&self) -> &str {
+        &self.scheme
+    }
+
+    /// Returns the user name, or an empty string when there is none.
+    pub fn username(&self) -> &str {
+        match self.userinfo() {
+            Some(userinfo) => userinfo.split(':').next().unwrap_or(""),
+            None => "",
+        }
+    }
+
+    /// Returns the password, if the URL has one.
+    pub fn password(&self) -> Option<&str> {
+        self.userinfo().and_then(|userinfo| userinfo.split_once(':')).map(|(_, password)| password)
+    }
+
+    /// Returns the host, including brackets for IPv6 addresses. `None` for URLs without an
+    /// authority, such as `mailto:` links.
+    pub fn host(&self) -> Option<&str> {
+        self.authority.as_ref().map(|authority| authority.host.as_str())
+    }
+
+    /// Returns the explicit port, if any.
+    pub fn port(&self) -> Option<u16> {
+        self.authority.as_ref().and_then(|authority| authority.port)
+    }
+
+    /// Returns the explicit port or the scheme's default (80 for `http`, 443 for `https`, ...).
+    ///
+    /// ```
+    /// let url = reddish::Url::parse("https://example.com").unwrap();
+    /// assert_eq!(url.port(), None);
+    /// assert_eq!(url.port_or_default(), Some(443));
+    /// ```
+    pub fn port_or_default(&self) -> Option<u16> {
+        self.port().or_else(|| default_port(&self.scheme))
+    }
+
+    /// Returns the path, which may be empty.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the non-empty path segments, still percent-encoded.
+    ///
+    /// ```
+    /// let url = reddish::Url::parse("https://example.com/api/v1/users/").unwrap();
+    /// assert_eq!(url.path_segments(), vec!["api", "v1", "users"]);
+    /// ```
+    pub fn path_segments(&self) -> Vec<&str> {
+        self.path.split('/').filter(|segment| !segment.is_empty()).collect()
+    }
+
+    /// Returns the query without the leading `?`.
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+
+    /// Returns the fragment without the leading `#`.
+    pub fn fragment(&self) -> Option<&str> {
+        self.fragment.as_deref()
+    }
+
+    /// Replaces the path. A leading `/` is added when the URL has a host, since the path
+    /// could not otherwise be told apart from the authority.
+    pub fn set_path(&mut self, path: &str) {
+        self.path = if self.authority.is_some() && !path.is_empty() && !path.starts_with('/') {
+            format!("/{}", path)
+        } else {
+            path.to_string()
+        };
+    }
+
+    /// Replaces the query; pass an already encoded string, e.g. from
+    /// [`build_query`](crate::build_query).
+    pub fn set_query(&mut self, query: Option<&str>) {
+        self.query = query.map(str::to_string);
+    }
+
+    /// Replaces the fragment.
+    pub fn set_fragment(&mut self, fragment: Option<&str>) {
+        self.fragment = fragment.map(str::to_string);
+    }
+
+    /// Returns the decoded query parameters in order.
+    pub fn query_pairs(&self) -> Vec<(String, String)> {
+        self.query.as_deref().map(parse_query).unwrap_or_default()
+    }
+
+    /// Returns the first decoded value of a query parameter.
+    ///
+    /// ```
+    /// let url = reddish::Url::parse("https://example.com/search?q=rust+lang&page=2").unwrap();
+    /// assert_eq!(url.query_param("q"), Some("rust lang".to_string()));
+    /// assert_eq!(url.query_param("missing"), None);
+    /// ```
+    pub fn query_param(&self, key: &str) -> Option<String> {
+        self.query_pairs().into_iter().find(|(k, _)| k == key).map(|(_, value)| value)
+    }
+
+    /// Appends a query parameter, form-encoding the key and value. Existing parameters
+    /// with the same key are kept; see [`set_query_param`](Url::set_query_param).
+    ///
+    /// ```
+    /// let mut url = reddish::Url::parse("https://example.com/search?q=rust").unwrap();
+    /// url.add_query_param("tag", "a&b");
+    /// assert_eq!(url.to_string(), "https://example.com/search?q=rust&tag=a%26b");
+    /// ```
+    pub fn add_query_param(&mut self, key: &str, value: &str) {
+        let pair = format!("{}={}", form_urlencode(key), form_urlencode(value));
+        self.query = Some(match self.query.take() {
+            Some(query) if !query.is_empty() => format!("{}&{}", query, pair),
+            _ => pair,
+        });
+    }
+
+    /// Removes every query parameter with this key, including bracketed `key[]` entries.
+    /// Other parameters are left byte-for-byte unchanged, and an emptied query is dropped.
+    ///
+    /// ```
+    /// let link = "https://example.com/?utm_source=x&id=7&utm_source=y";
+    /// let mut url = reddish::Url::parse(link).unwrap();
+    /// url.remove_query_param("utm_source");
+    /// assert_eq!(url.to_string(), "https://example.com/?id=7");
+    ///
+    /// url.remove_query_param("id");
+    /// assert_eq!(url.to_string(), "https://example.com/");
+    /// ```
+    pub fn remove_query_param(&mut self, key: &str) {
+        let query = match self.query.take() {
+            Some(query) => query,
+            None => return,
+        };
+
+        let kept: Vec<&str> = query
+            .split('&')
+            .filter(|pair| {
+                let raw_key = pair.split('=').next().unwrap_or("");
+                let decoded = form_urldecode_lossy(raw_key);
+                !pair.is_empty() && decoded != key && decoded.strip_suffix("[]") != Some(key)
+            })
+            .collect();
+
+        if !kept.is_empty() {
+            self.query = Some(kept.join("&"));
+        }
+    }
+
+    /// Replaces all values of a query parameter with a single value.
+    pub fn set_query_param(&mut self, key: &str, value: &str) {
+        self.remove_query_param(key);
+        self.add_query_param(key, value);
+    }
+
+    /// Appends path segments to the path, with exactly one `/` between them. The query
+    /// and fragment are kept. Unlike [`resolve`](Url::resolve), the last segment of the
+    /// base is never replaced. The appended text should already be percent-encoded.
+    ///
+    /// ```
+    /// let base = reddish::Url::parse("https://api.example.com/v1/").unwrap();
+    /// assert_eq!(base.join("/users/42").to_string(), "https://api.example.com/v1/users/42");
+    ///
+    /// let base = reddish::Url::parse("https://api.example.com/v1?key=abc").unwrap();
+    /// assert_eq!(base.join("users").to_string(), "https://api.example.com/v1/users?key=abc");
+    /// ```
+    pub fn join(&self, path: &str) -> Url {
+        let mut url = self.clone();
+        let tail = path.trim_start_matches('/');
+        if !tail.is_empty() {
+            url.path = format!("{}/{}", self.path.trim_end_matches('/'), tail);
+        }
+        url
+    }
+
+    /// Resolves a relative reference against this URL as a browser would follow a link,
+    /// using the algorithm in RFC 3986 section 5.2.
+    ///
+    /// ```
+    /// let base = reddish::Url::parse("http://a/b/c/d;p?q").unwrap();
+    /// assert_eq!(base.resolve("g").unwrap().to_string(), "http://a/b/c/g");
+    /// assert_eq!(base.resolve("../g").unwrap().to_string(), "http://a/b/g");
+    /// assert_eq!(base.resolve("//g").unwrap().to_string(), "http://g");
+    /// assert_eq!(base.resolve("?y").unwrap().to_string(), "http://a/b/c/d;p?y");
+    /// assert_eq!(base.resolve("#s").unwrap().to_string(), "http://a/b/c/d;p?q#s");
+    /// ```
+    pub fn resolve(&self, reference: &str) -> Result<Url, UrlParseError> {
+        let r = split_reference(reference)?;
+        let fragment = r.fragment.map(str::to_string);
+
+        if let Some(scheme) = r.scheme {
+            return Ok(Url {
+                scheme: scheme.to_ascii_lowercase(),
+                authority: r.authority.map(parse_authority).transpose()?,
+                path: remove_dot_segments(r.path),
+                query: r.query.map(str::to_string),
+                fragment,
+            });
+        }
+
+        if let Some(authority) = r.authority {
+            return Ok(Url {
+                scheme: self.scheme.clone(),
+                authority: Some(parse_authority(authority)?),
+                path: remove_dot_segments(r.path),
+                query: r.query.map(str::to_string),
+                fragment,
+            });
+        }
+
+        let (path, query) = if r.path.is_empty() {
+            (self.path.clone(), r.query.map(str::to_string).or_else(|| self.query.clone()))
+        } else if r.path.starts_with('/') {
+            (remove_dot_segments(r.path), r.query.map(str::to_string))
+        } else {
+            (remove_dot_segments(&self.merge(r.path)), r.query.map(str::to_string))
+        };
+
+        Ok(Url {
+            scheme: self.scheme.clone(),
+            authority: self.authority.clone(),
+            path,
+            query,
+            fragment,
+        })
+    }
+
+    /// Returns the syntax-based normal form from RFC 3986 section 6.2.2: lowercase host,
+    /// uppercase percent-escapes, decoded unreserved characters, no `.` or `..` segments
+    /// and no default port. URLs that differ only in these ways normalize to equal values.
+    ///
+    /// ```
+    /// let url = reddish::Url::parse("HTTP://Example.COM:80/a/./b/../c/%7euser?x=%2f").unwrap();
+    /// assert_eq!(url.normalize().to_string(), "http://example.com/a/c/~user?x=%2F");
+    ///
+    /// let url = reddish::Url::parse("https://example.com").unwrap();
+    /// assert_eq!(url.normalize().to_string(), "https://example.com/");
+    /// ```
+    pub fn normalize(&self) -> Url {
+        let authority = self.authority.as_ref().map(|authority| Authority {
+            userinfo: authority.userinfo.as_deref().map(normalize_percent_encoding),
+            // Lowercase after decoding so `%41` ends up as `a`, then re-encode so the
+            // escapes that remain are uppercase again.
+            host: normalize_percent_encoding(
+                &normalize_percent_encoding(&authority.host).to_ascii_lowercase(),
+            ),
+            port: authority.port.filter(|&port| Some(port) != default_port(&self.scheme)),
+        });
+
+        let mut path = remove_dot_segments(&normalize_percent_encoding(&self.path));
+        if authority.is_some() && path.is_empty() {
+            path.push('/');
+        }
+
+        Url {
+            scheme: self.scheme.clone(),
+            authority,
+            path,
+            query: self.query.as_deref().map(normalize_percent_encoding),
+            fragment: self.fragment.as_deref().map(normalize_percent_encoding),
+        }
+    }
+
+    fn userinfo(&self) -> Option<&str> {
+        self.authority.as_ref().and_then(|authority| authority.userinfo.as_deref())
+    }
+
+    // RFC 3986 section 5.2.3
+    fn merge(&self, path: &str) -> String {
+        if self.authority.is_some() && self.path.is_empty() {
+            return format!("/{}", path);
+        }
+
+        match self.path.rfind('/') {
+            Some(index) => format!("{}{}", &self.path[..=index], path),
+            None => path.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Url {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:", self.scheme)?;
+        if let Some(authority) = &self.authority {
+            write!(f, "//")?;
+            if let Some(userinfo) = &authority.userinfo {
+                write!(f, "{}@", userinfo)?;
+            }
+            write!(f, "{}", authority.host)?;
+            if let Some(port) = authority.port {
+                write!(f, ":{}", port)?;
+            }
+        }
+        write!(f, "{}", self.path)?;
+        if let Some(query) = &self.query {
+            write!(f, "?{}", query)?;
+        }
+        if let Some(fragment) = &self.fragment {
+            write!(f, "#{}", fragment)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Url {
+    type Err = UrlParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Url::parse(s)
+    }
+}
+
+fn split_reference(input: &str) -> Result<Reference<'_>, UrlParseError> {
+    let invalid = input.char_indices().find(|(_, c)| c.is_whitespace() || c.is_control());
+    if let Some((position, character)) = invalid {
+        return Err(UrlParseError::InvalidCharacter { character, position });
+    }
+
+    let (rest, fragment) = match input.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (input, None),
+    };
+    let (mut rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+
+    let mut scheme = None;
+    if let Some(colon) = rest.find(':') {
+        if colon > 0 && !rest[..colon].contains('/') {
+            let candidate = &rest[..colon];
+            let allowed = |c: char| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.');
+            let valid = candidate.starts_with(|c: char| c.is_ascii_alphabetic())
+                && candidate.chars().all(allowed);
+            if !valid {
+                return Err(UrlParseError::InvalidScheme);
+            }
+            scheme = Some(candidate);
+            rest = &rest[colon + 1..];
+        }
+    }
+
+    let mut authority = None;
+    if let Some(after) = rest.strip_prefix("//") {
+        let end = after.find('/').unwrap_or(after.len());
+        authority = Some(&after[..end]);
+        rest = &after[end..];
+    }
+
+    Ok(Reference {
+        scheme,
+        authority,
+        path: rest,
+        query,
+        fragment,
+    })
+}
+
+fn parse_authority(authority: &str) -> Result<Authority, UrlParseError> {
+    let (userinfo, host_port) = match authority.rsplit_once('@') {
+        Some((userinfo, host_port)) => (Some(userinfo.to_string()), host_port),
+        None => (None, authority),
+    };
+
+    let (host, port) = if host_port.starts_with('[') {
+        let end = host_port.find(']').ok_or(UrlParseError::InvalidHost)?;
+        let (host, rest) = host_port.split_at(end + 1);
+        match rest {
+            "" => (host, None),
+            _ => (host, Some(rest.strip_prefix(':').ok_or(UrlParseError::InvalidHost)?)),
+        }
+    } else {
+        match host_port.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_port, None),
+        }
+    };
+
+    if !host.starts_with('[') && host.contains(['[', ']']) {
+        return Err(UrlParseError::InvalidHost);
+    }
+
+    let port = match port {
+        None | Some("") => None,
+        Some(port) if port.bytes().all(|b| b.is_ascii_digit()) => {
+            Some(port.parse().map_err(|_| UrlParseError::InvalidPort)?)
+        }
+        Some(_) => return Err(UrlParseError::InvalidPort),
+    };
+
+    Ok(Authority {
+        userinfo,
+        host: host.to_string(),
+        port,
+    })
+}
+
+fn default_port(scheme: &str) -> Option<u16> {
+    match scheme {
+        "http" | "ws" => Some(80),
+        "https" | "wss" => Some(443),
+        "ftp" => Some(21),
+        _ => None,
+    }
+}
+
+// RFC 3986 section 5.2.4
+fn remove_dot_segments(path: &str) -> String {
+    let mut input = path.to_string();
+    let mut output = String::with_capacity(path.len());
+
+    while !input.is_empty() {
+        if input.starts_with("../") {
+            input.drain(..3);
+        } else if input.starts_with("./") {
+            input.drain(..2);
+        } else if input.starts_with("/./") {
+            input.replace_range(..3, "/");
+        } else if input == "/." {
+            input = "/".to_string();
+        } else if input.starts_with("/../") {
+            input.replace_range(..4, "/");
+            pop_segment(&mut output);
+        } else if input == "/.." {
+            input = "/".to_string();
+            pop_segment(&mut output);
+        } else if input == "." || input == ".." {
+            input.clear();
+        } else {
+            let start = usize::from(input.starts_with('/'));
+            let end = input[start..].find('/').map_or(input.len(), |i| i + start);
+            output.push_str(&input[..end]);
+            input.drain(..end);
+        }
+    }
+
+    output
+}
+
+fn pop_segment(output: &mut String) {
+    match output.rfind('/') {
+        Some(index) => output.truncate(index),
+        None => output.clear(),
+    }
+}
+
+// Uppercases percent-escapes and decodes the ones that stand for unreserved characters
+fn normalize_percent_encoding(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut output = String::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && i + 2 < bytes.len()
+            && bytes[i + 1].is_ascii_hexdigit()
+            && bytes[i + 2].is_ascii_hexdigit()
+        {
+            let decoded = u8::from_str_radix(&text[i + 1..i + 3], 16).unwrap_or(0);
+            if decoded.is_ascii_alphanumeric() || matches!(decoded, b'-' | b'.' | b'_' | b'~') {
+                output.push(decoded as char);
+            } else {
+                output.push('%');
+                output.push_str(&text[i + 1..i + 3].to_ascii_uppercase());
+            }
+            i += 3;
+        } else {
+            let c = text[i..].chars().next().expect("index is on a char boundary");
+            output.push(c);
+            i += c.len_utf8();
+        }
+    }
+
+    output
+}
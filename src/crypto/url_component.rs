@@ -0,0 +1,76 @@
+#![allow(dead_code)]
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+// Everything except RFC 3986 unreserved characters
+pub(crate) const UNRESERVED: &AsciiSet =
+    &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
+// Unreserved plus sub-delims, but escaping ':' and '@' so user and password stay separable
+pub(crate) const USERINFO: &AsciiSet = &UNRESERVED
+    .remove(b'!')
+    .remove(b'$')
+    .remove(b'&')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')')
+    .remove(b'*')
+    .remove(b'+')
+    .remove(b',')
+    .remove(b';')
+    .remove(b'=');
+
+// pchar: unreserved, sub-delims, ':' and '@'
+pub(crate) const PATH_SEGMENT: &AsciiSet = &USERINFO.remove(b':').remove(b'@');
+
+// pchar plus '/' and '?'
+pub(crate) const FRAGMENT: &AsciiSet = &PATH_SEGMENT.remove(b'/').remove(b'?');
+
+// Like the fragment, but '&', '=' and '+' are escaped so a key or value cannot split the query
+pub(crate) const QUERY_COMPONENT: &AsciiSet = &FRAGMENT.add(b'&').add(b'=').add(b'+');
+
+/// Percent-encodes a single path segment. Slashes are escaped so the result is always
+/// one segment; characters allowed in a segment, like `:`, `@` and `+`, are kept.
+///
+/// ```
+/// assert_eq!(reddish::url_encode_path_segment("a/b c"), "a%2Fb%20c");
+/// assert_eq!(reddish::url_encode_path_segment("user@host:1+1"), "user@host:1+1");
+/// assert_eq!(reddish::url_encode_path_segment("50%?#"), "50%25%3F%23");
+/// ```
+pub fn url_encode_path_segment(data: &str) -> String {
+    utf8_percent_encode(data, PATH_SEGMENT).to_string()
+}
+
+/// Percent-encodes a query key or value. `&`, `=`, `+` and `#` are escaped, while `/`,
+/// `?`, `:` and `@` are kept. Spaces become `%20`; use
+/// [`form_urlencode`](crate::form_urlencode) for `+`-for-space form encoding.
+///
+/// ```
+/// assert_eq!(reddish::url_encode_query("a&b=c+d"), "a%26b%3Dc%2Bd");
+/// assert_eq!(reddish::url_encode_query("/path?x"), "/path?x");
+/// assert_eq!(reddish::url_encode_query("hello world"), "hello%20world");
+/// ```
+pub fn url_encode_query(data: &str) -> String {
+    utf8_percent_encode(data, QUERY_COMPONENT).to_string()
+}
+
+/// Percent-encodes a fragment (the part after `#`).
+///
+/// ```
+/// assert_eq!(reddish::url_encode_fragment("section 2/intro?x"), "section%202/intro?x");
+/// assert_eq!(reddish::url_encode_fragment("#hash"), "%23hash");
+/// ```
+pub fn url_encode_fragment(data: &str) -> String {
+    utf8_percent_encode(data, FRAGMENT).to_string()
+}
+
+/// Percent-encodes a user name or password for the userinfo part of a URL.
+/// `:` and `@` are escaped so they cannot be confused with the separators.
+///
+/// ```
+/// assert_eq!(reddish::url_encode_userinfo("p@ss:word"), "p%40ss%3Aword");
+/// assert_eq!(reddish::url_encode_userinfo("j.doe+tag"), "j.doe+tag");
+/// ```
+pub fn url_encode_userinfo(data: &str) -> String {
+    utf8_percent_encode(data, USERINFO).to_string()
+}
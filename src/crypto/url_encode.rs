@@ -1,8 +1,13 @@
 #![allow(dead_code)]
 
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use percent_encoding::utf8_percent_encode;
 
-/// URL encodes the input string.
+use super::url_component::UNRESERVED;
+
+/// URL encodes the input string, escaping everything except the RFC 3986 unreserved
+/// characters (letters, digits, `-`, `.`, `_` and `~`). Safe for any URL component;
+/// see [`url_encode_path_segment`](crate::url_encode_path_segment) and friends for
+/// lighter, component-specific escaping.
 ///
 /// ```
 /// let result = reddish::url_encode("hello world");
@@ -11,7 +16,12 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 ///
 /// ```
 /// let result = reddish::url_encode("hello@example.com");
-/// assert_eq!(result, "hello%40example%2Ecom");
+/// assert_eq!(result, "hello%40example.com");
+/// ```
+///
+/// ```
+/// let result = reddish::url_encode("a-b_c.d~e");
+/// assert_eq!(result, "a-b_c.d~e");
 /// ```
 ///
 /// ```
@@ -19,5 +29,5 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 /// assert_eq!(result, "caf%C3%A9");
 /// ```
 pub fn url_encode(data: &str) -> String {
-    utf8_percent_encode(data, NON_ALPHANUMERIC).to_string()
+    utf8_percent_encode(data, UNRESERVED).to_string()
 }
@@ -0,0 +1,35 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by [`Url::parse`](crate::Url::parse) and [`Url::resolve`](crate::Url::resolve).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlParseError {
+    /// The input has no `scheme:` prefix, so it is a relative reference, not a URL.
+    MissingScheme,
+    /// The scheme contains characters other than letters, digits, `+`, `-` and `.`.
+    InvalidScheme,
+    /// The host is malformed, e.g. an unclosed IPv6 bracket.
+    InvalidHost,
+    /// The port is not a number between 0 and 65535.
+    InvalidPort,
+    /// The input contains whitespace or a control character at the given byte offset.
+    InvalidCharacter { character: char, position: usize },
+}
+
+impl fmt::Display for UrlParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UrlParseError::MissingScheme => write!(f, "URL has no scheme"),
+            UrlParseError::InvalidScheme => write!(f, "invalid URL scheme"),
+            UrlParseError::InvalidHost => write!(f, "invalid URL host"),
+            UrlParseError::InvalidPort => write!(f, "invalid URL port"),
+            UrlParseError::InvalidCharacter { character, position } => {
+                write!(f, "invalid character {:?} at position {}", character, position)
+            }
+        }
+    }
+}
+
+impl Error for UrlParseError {}
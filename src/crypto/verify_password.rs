@@ -0,0 +1,96 @@
+#![allow(dead_code)]
+
+use argon2::Argon2;
+use pbkdf2::password_hash::{PasswordHash, PasswordVerifier};
+use pbkdf2::Pbkdf2;
+
+use super::hash_password::BCRYPT_MAX_PASSWORD_LEN;
+use super::password_hash_error::PasswordHashError;
+use super::password_hash_params::PasswordHashParams;
+
+/// Checks a password against a hash produced by [`hash_password`](crate::hash_password)
+/// or [`hash_password_with`](crate::hash_password_with). The algorithm and parameters are
+/// read from the hash itself, so hashes made with older settings keep verifying.
+///
+/// Returns `Ok(false)` on a wrong password and an error if the hash is malformed.
+/// Passwords longer than 72 bytes are refused with
+/// [`PasswordTooLong`](crate::PasswordHashError::PasswordTooLong) against bcrypt hashes,
+/// as bcrypt would otherwise accept any password sharing the first 72 bytes.
+///
+/// ```
+/// let hash = reddish::hash_password("hunter2").unwrap();
+/// assert_eq!(reddish::verify_password("hunter2", &hash), Ok(true));
+/// assert_eq!(reddish::verify_password("hunter3", &hash), Ok(false));
+/// assert!(reddish::verify_password("hunter2", "not a hash").is_err());
+/// ```
+pub fn verify_password(password: &str, hash: &str) -> Result<bool, PasswordHashError> {
+    if is_bcrypt_hash(hash) {
+        if password.len() > BCRYPT_MAX_PASSWORD_LEN {
+            return Err(PasswordHashError::PasswordTooLong);
+        }
+        return bcrypt::verify(password, hash).map_err(|_| PasswordHashError::InvalidHash);
+    }
+
+    let parsed = PasswordHash::new(hash).map_err(|_| PasswordHashError::InvalidHash)?;
+    let result = match parsed.algorithm.as_str() {
+        "argon2id" | "argon2i" | "argon2d" => {
+            Argon2::default().verify_password(password.as_bytes(), &parsed)
+        }
+        "pbkdf2-sha256" | "pbkdf2-sha512" => Pbkdf2.verify_password(password.as_bytes(), &parsed),
+        other => return Err(PasswordHashError::UnsupportedAlgorithm(other.to_string())),
+    };
+
+    match result {
+        Ok(()) => Ok(true),
+        Err(pbkdf2::password_hash::Error::Password) => Ok(false),
+        Err(_) => Err(PasswordHashError::InvalidHash),
+    }
+}
+
+/// Returns `true` if a stored hash was not produced with exactly these parameters,
+/// meaning it should be replaced with a fresh hash the next time the user logs in.
+/// Unparseable hashes also need rehashing.
+///
+/// ```
+/// use reddish::PasswordHashParams;
+///
+/// let old = PasswordHashParams::Bcrypt { cost: 4 };
+/// let hash = reddish::hash_password_with("hunter2", &old).unwrap();
+/// assert!(!reddish::needs_rehash(&hash, &old));
+/// assert!(reddish::needs_rehash(&hash, &PasswordHashParams::Bcrypt { cost: 5 }));
+/// assert!(reddish::needs_rehash(&hash, &PasswordHashParams::argon2id()));
+/// ```
+pub fn needs_rehash(hash: &str, params: &PasswordHashParams) -> bool {
+    stored_params(hash) != Some(*params)
+}
+
+fn stored_params(hash: &str) -> Option<PasswordHashParams> {
+    if is_bcrypt_hash(hash) {
+        let parts: bcrypt::HashParts = hash.parse().ok()?;
+        return Some(PasswordHashParams::Bcrypt { cost: parts.get_cost() });
+    }
+
+    let parsed = PasswordHash::new(hash).ok()?;
+    match parsed.algorithm.as_str() {
+        "argon2id" => {
+            if parsed.version != Some(argon2::Version::V0x13.into()) {
+                return None;
+            }
+            let stored = argon2::Params::try_from(&parsed).ok()?;
+            Some(PasswordHashParams::Argon2id {
+                memory_kib: stored.m_cost(),
+                iterations: stored.t_cost(),
+                parallelism: stored.p_cost(),
+            })
+        }
+        "pbkdf2-sha256" => {
+            let stored = pbkdf2::Params::try_from(&parsed).ok()?;
+            Some(PasswordHashParams::Pbkdf2Sha256 { rounds: stored.rounds })
+        }
+        _ => None,
+    }
+}
+
+fn is_bcrypt_hash(hash: &str) -> bool {
+    ["$2a$", "$2b$", "$2x$", "$2y$"].iter().any(|prefix| hash.starts_with(prefix))
+}
@@ -0,0 +1,80 @@
+#![allow(dead_code)]
+
+use base64::{Engine as _, engine::general_purpose};
+
+use super::{hmac, verify_hmac, HashAlgorithm};
+
+/// Signs a webhook payload, returning the `<algorithm>=<hex>` header format
+/// used by GitHub and many other providers (e.g. `sha256=...`).
+///
+/// ```
+/// use reddish::HashAlgorithm;
+///
+/// let secret = b"It's a Secret to Everybody";
+/// let signature = reddish::sign_webhook(HashAlgorithm::Sha256, secret, b"Hello, World!");
+/// let hex = "757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+/// assert_eq!(signature, format!("sha256={}", hex));
+/// ```
+pub fn sign_webhook(algorithm: HashAlgorithm, secret: &[u8], payload: &[u8]) -> String {
+    format!("{}={}", algorithm.name(), hmac(algorithm, secret, payload).to_hex())
+}
+
+/// Verifies a webhook signature in constant time.
+///
+/// The signature may be hex or Base64 (standard or URL-safe) encoded and may carry an
+/// `<algorithm>=` prefix such as `sha256=`. A prefix naming a different algorithm
+/// than `algorithm` is rejected.
+///
+/// ```
+/// use reddish::HashAlgorithm;
+///
+/// let secret = b"It's a Secret to Everybody";
+/// let payload = b"Hello, World!";
+///
+/// let hex = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+/// assert!(reddish::verify_webhook_signature(HashAlgorithm::Sha256, secret, payload, hex));
+///
+/// let base64 = "dXEH6g6yUJ/CESIczphLijdXC211hsIsRvQ3nIsEPhc=";
+/// assert!(reddish::verify_webhook_signature(HashAlgorithm::Sha256, secret, payload, base64));
+///
+/// assert!(!reddish::verify_webhook_signature(HashAlgorithm::Sha256, b"wrong", payload, hex));
+/// ```
+pub fn verify_webhook_signature(
+    algorithm: HashAlgorithm,
+    secret: &[u8],
+    payload: &[u8],
+    signature: &str,
+) -> bool {
+    let signature = signature.trim();
+    let encoded = match signature.split_once('=') {
+        Some((prefix, rest)) if !prefix.is_empty() && !rest.is_empty() => {
+            match prefix.parse::<HashAlgorithm>() {
+                Ok(named) if named == algorithm => rest,
+                Ok(_) => return false,
+                // Not an algorithm prefix; the `=` is Base64 padding
+                Err(_) => signature,
+            }
+        }
+        _ => signature,
+    };
+
+    match decode_signature(encoded, algorithm.output_len()) {
+        Some(expected) => verify_hmac(algorithm, secret, payload, &expected),
+        None => false,
+    }
+}
+
+fn decode_signature(encoded: &str, output_len: usize) -> Option<Vec<u8>> {
+    if encoded.len() == output_len * 2 {
+        if let Ok(bytes) = hex::decode(encoded) {
+            return Some(bytes);
+        }
+    }
+
+    general_purpose::STANDARD
+        .decode(encoded)
+        .or_else(|_| general_purpose::URL_SAFE.decode(encoded))
+        .or_else(|_| general_purpose::STANDARD_NO_PAD.decode(encoded))
+        .or_else(|_| general_purpose::URL_SAFE_NO_PAD.decode(encoded))
+        .ok()
+}
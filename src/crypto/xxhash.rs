@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+
+use xxhash_rust::{xxh3, xxh64};
+
+/// Computes the 64-bit xxHash (XXH64) of the data with the given seed.
+/// Extremely fast and well distributed, but not a cryptographic hash.
+///
+/// ```
+/// assert_eq!(reddish::xxhash64(b"", 0), 0xef46db3751d8e999);
+/// assert_ne!(reddish::xxhash64(b"abc", 0), reddish::xxhash64(b"abc", 1));
+/// ```
+pub fn xxhash64(data: &[u8], seed: u64) -> u64 {
+    xxh64::xxh64(data, seed)
+}
+
+/// Computes the 64-bit XXH3 hash of the data, the fastest member of the xxHash family.
+/// Not a cryptographic hash.
+///
+/// ```
+/// assert_eq!(reddish::xxh3_64(b""), 0x2d06800538d394c2);
+/// ```
+pub fn xxh3_64(data: &[u8]) -> u64 {
+    xxh3::xxh3_64(data)
+}
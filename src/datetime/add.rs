@@ -0,0 +1,40 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, Duration, TimeZone};
+
+use super::add_days::checked_add_days;
+use super::add_months::add_months;
+use super::time_unit::TimeUnit;
+
+/// Adds an amount of any [`TimeUnit`](crate::TimeUnit) to a date. Subtract with a negative amount.
+/// Returns `None` if the result is out of range.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use reddish::TimeUnit;
+///
+/// let date = Utc.with_ymd_and_hms(2024, 8, 31, 12, 0, 0).unwrap();
+/// let later = Utc.with_ymd_and_hms(2024, 8, 31, 13, 30, 0).unwrap();
+/// assert_eq!(reddish::add(&date, 90, TimeUnit::Minute).unwrap(), later);
+/// let end_of_november = Utc.with_ymd_and_hms(2024, 11, 30, 12, 0, 0).unwrap();
+/// assert_eq!(reddish::add(&date, 1, TimeUnit::Quarter).unwrap(), end_of_november);
+/// let last_year = Utc.with_ymd_and_hms(2023, 8, 31, 12, 0, 0).unwrap();
+/// assert_eq!(reddish::add(&date, -1, TimeUnit::Year).unwrap(), last_year);
+/// assert_eq!(reddish::add(&date, i64::MAX, TimeUnit::Second), None);
+/// ```
+pub fn add<Tz: TimeZone>(
+    datetime: &DateTime<Tz>,
+    amount: i64,
+    unit: TimeUnit,
+) -> Option<DateTime<Tz>> {
+    match unit {
+        TimeUnit::Second => datetime.clone().checked_add_signed(Duration::try_seconds(amount)?),
+        TimeUnit::Minute => datetime.clone().checked_add_signed(Duration::try_minutes(amount)?),
+        TimeUnit::Hour => datetime.clone().checked_add_signed(Duration::try_hours(amount)?),
+        TimeUnit::Day => checked_add_days(datetime, amount),
+        TimeUnit::Week => checked_add_days(datetime, amount.checked_mul(7)?),
+        TimeUnit::Month => add_months(datetime, amount),
+        TimeUnit::Quarter => add_months(datetime, amount.checked_mul(3)?),
+        TimeUnit::Year => add_months(datetime, amount.checked_mul(12)?),
+    }
+}
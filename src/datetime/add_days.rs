@@ -1,10 +1,20 @@
 #![allow(dead_code)]
 
-use chrono::{DateTime, Utc, Duration};
+use chrono::{DateTime, Duration, TimeZone};
+
+use super::local_time::resolve_local;
 
 /// Adds a specified number of days to a date.
 /// Can add negative days to subtract.
 ///
+/// Days are calendar days in the datetime's own time zone, so the wall-clock time is kept
+/// across daylight saving changes even though such a day lasts 23 or 25 hours.
+///
+/// # Panics
+///
+/// Panics if the result is outside the range chrono can represent, roughly ±262,000 years.
+/// Use [`add`](crate::add) with [`TimeUnit::Day`](crate::TimeUnit::Day) to get `None` instead.
+///
 /// ```
 /// use chrono::{Utc, TimeZone, Datelike};
 /// let date = Utc.with_ymd_and_hms(2023, 12, 1, 12, 0, 0).unwrap();
@@ -20,6 +30,26 @@ use chrono::{DateTime, Utc, Duration};
 /// assert_eq!(result.day(), 2);
 /// assert_eq!(result.month(), 12);
 /// ```
-pub fn add_days(datetime: &DateTime<Utc>, days: i64) -> DateTime<Utc> {
-    *datetime + Duration::days(days)
+///
+/// ```
+/// use chrono::{TimeZone, Timelike};
+/// use chrono_tz::America::New_York;
+///
+/// // Clocks in New York sprang forward on 10 March 2024
+/// let date = New_York.with_ymd_and_hms(2024, 3, 9, 9, 0, 0).unwrap();
+/// let result = reddish::add_days(&date, 1);
+/// assert_eq!(result.hour(), 9);
+/// assert_eq!((result - date).num_hours(), 23);
+/// ```
+pub fn add_days<Tz: TimeZone>(datetime: &DateTime<Tz>, days: i64) -> DateTime<Tz> {
+    checked_add_days(datetime, days).expect("date out of range")
+}
+
+// Like `add_days`, returning `None` instead of panicking when the result is out of range
+pub(crate) fn checked_add_days<Tz: TimeZone>(
+    datetime: &DateTime<Tz>,
+    days: i64,
+) -> Option<DateTime<Tz>> {
+    let naive = datetime.naive_local().checked_add_signed(Duration::try_days(days)?)?;
+    Some(resolve_local(&datetime.timezone(), naive))
 }
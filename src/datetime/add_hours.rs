@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, Duration, TimeZone};
+
+/// Adds elapsed hours to a date. Unlike days, hours are exact, so the wall-clock time can
+/// shift by the daylight saving offset. Returns `None` if the result is out of range.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+///
+/// let date = Utc.with_ymd_and_hms(2024, 12, 31, 22, 0, 0).unwrap();
+/// let next_year = Utc.with_ymd_and_hms(2025, 1, 1, 1, 0, 0).unwrap();
+/// assert_eq!(reddish::add_hours(&date, 3).unwrap(), next_year);
+/// ```
+pub fn add_hours<Tz: TimeZone>(datetime: &DateTime<Tz>, hours: i64) -> Option<DateTime<Tz>> {
+    datetime.clone().checked_add_signed(Duration::try_hours(hours)?)
+}
@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone};
+
+use super::days_in_month::month_length;
+use super::local_time::resolve_local;
+
+/// Adds calendar months to a date, keeping the wall-clock time. Subtract with a negative
+/// number. When the target month is shorter, the day is clamped to its last day.
+/// Returns `None` if the result is out of range.
+///
+/// ```
+/// use chrono::{Datelike, TimeZone, Utc};
+///
+/// let date = Utc.with_ymd_and_hms(2024, 1, 31, 9, 0, 0).unwrap();
+/// let at = |month, day| Utc.with_ymd_and_hms(2024, month, day, 9, 0, 0).unwrap();
+/// assert_eq!(reddish::add_months(&date, 1).unwrap(), at(2, 29));
+/// assert_eq!(reddish::add_months(&date, 3).unwrap(), at(4, 30));
+/// assert_eq!(reddish::add_months(&date, -2).unwrap().month(), 11);
+/// assert_eq!(reddish::add_months(&date, i64::MAX), None);
+/// ```
+pub fn add_months<Tz: TimeZone>(datetime: &DateTime<Tz>, months: i64) -> Option<DateTime<Tz>> {
+    let date = shift_months(datetime.date_naive(), months)?;
+    Some(resolve_local(&datetime.timezone(), date.and_time(datetime.time())))
+}
+
+/// Adds calendar years to a date, keeping the wall-clock time. 29 February becomes
+/// 28 February in years that are not leap years. Returns `None` if the result is out of range.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+///
+/// let leap_day = Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap();
+/// let next_year = Utc.with_ymd_and_hms(2025, 2, 28, 0, 0, 0).unwrap();
+/// assert_eq!(reddish::add_years(&leap_day, 1).unwrap(), next_year);
+/// assert_eq!(reddish::add_years(&leap_day, 4).unwrap(), leap_day + chrono::Duration::days(1461));
+/// ```
+pub fn add_years<Tz: TimeZone>(datetime: &DateTime<Tz>, years: i64) -> Option<DateTime<Tz>> {
+    add_months(datetime, years.checked_mul(12)?)
+}
+
+// Moves a date by whole months, clamping the day to the length of the target month
+pub(crate) fn shift_months(date: NaiveDate, months: i64) -> Option<NaiveDate> {
+    let index = (date.year() as i64 * 12 + date.month0() as i64).checked_add(months)?;
+    let year = i32::try_from(index.div_euclid(12)).ok()?;
+    let month = index.rem_euclid(12) as u32 + 1;
+    let day = date.day().min(month_length(year, month));
+    NaiveDate::from_ymd_opt(year, month, day)
+}
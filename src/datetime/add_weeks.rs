@@ -0,0 +1,19 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, TimeZone};
+
+use super::add_days::checked_add_days;
+
+/// Adds weeks to a date, keeping the wall-clock time like [`add_days`](crate::add_days).
+/// Returns `None` if the result is out of range.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+///
+/// let date = Utc.with_ymd_and_hms(2024, 12, 20, 10, 0, 0).unwrap();
+/// let next_year = Utc.with_ymd_and_hms(2025, 1, 3, 10, 0, 0).unwrap();
+/// assert_eq!(reddish::add_weeks(&date, 2).unwrap(), next_year);
+/// ```
+pub fn add_weeks<Tz: TimeZone>(datetime: &DateTime<Tz>, weeks: i64) -> Option<DateTime<Tz>> {
+    checked_add_days(datetime, weeks.checked_mul(7)?)
+}
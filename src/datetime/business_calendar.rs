@@ -0,0 +1,449 @@
+#![allow(dead_code)]
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Weekday};
+
+use super::holiday::{Holiday, Observance};
+use super::holiday_parse_error::HolidayParseError;
+use super::holiday_parser::parse_rules;
+use super::local_time::resolve_local;
+
+const US_HOLIDAYS: &str = include_str!("holidays/us.txt");
+const UK_HOLIDAYS: &str = include_str!("holidays/uk.txt");
+const BR_HOLIDAYS: &str = include_str!("holidays/br.txt");
+
+/// A working-day calendar with configurable weekend days and holidays, for deadlines and
+/// SLAs counted in business days.
+///
+/// Datetimes are read in their own time zone, and results keep the original time of day.
+///
+/// ```
+/// use chrono::{TimeZone, Utc, Weekday};
+/// use reddish::BusinessCalendar;
+///
+/// let calendar = BusinessCalendar::united_states();
+/// // Thursday 3 July 2025; Independence Day falls on the Friday
+/// let ordered = Utc.with_ymd_and_hms(2025, 7, 3, 9, 0, 0).unwrap();
+/// let due = calendar.add_business_days(&ordered, 2).unwrap();
+/// assert_eq!(due, Utc.with_ymd_and_hms(2025, 7, 8, 9, 0, 0).unwrap());
+/// assert_eq!(calendar.business_days_between(&ordered, &due), 2);
+///
+/// // A Friday–Saturday weekend without holidays
+/// let gulf = BusinessCalendar::new().with_weekend(&[Weekday::Fri, Weekday::Sat]);
+/// let thursday = Utc.with_ymd_and_hms(2025, 7, 3, 9, 0, 0).unwrap();
+/// let sunday = Utc.with_ymd_and_hms(2025, 7, 6, 9, 0, 0).unwrap();
+/// assert_eq!(gulf.next_business_day(&thursday), Some(sunday));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusinessCalendar {
+    // Indexed by `Weekday::num_days_from_monday`
+    weekend: [bool; 7],
+    holidays: Vec<Holiday>,
+}
+
+impl BusinessCalendar {
+    /// Creates a calendar with a Saturday–Sunday weekend and no holidays.
+    pub fn new() -> Self {
+        BusinessCalendar {
+            weekend: [false, false, false, false, false, true, true],
+            holidays: Vec::new(),
+        }
+    }
+
+    /// Builds a calendar from text with one holiday per line, written as `rule: name`.
+    /// The weekend is Saturday–Sunday unless a `weekend` line lists other days, and
+    /// anything after `#` is a comment.
+    ///
+    /// A rule is one of:
+    /// - `MM-DD` for a fixed day every year, e.g. `12-25`
+    /// - `YYYY-MM-DD` for a single date
+    /// - `<first|second|third|fourth|fifth|last> <weekday> of <month>`, e.g. `last mon of may`
+    /// - `easter`, `easter+N` or `easter-N` for days relative to Easter Sunday, with `N` up to 365
+    ///
+    /// It may be followed by `observed nearest` (Saturday to Friday, Sunday to Monday),
+    /// `observed next` (the next free working day), `from YYYY`, `until YYYY` and
+    /// `except YYYY,YYYY` to skip the rule in years the holiday was moved.
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use reddish::BusinessCalendar;
+    ///
+    /// let rules = "
+    ///     weekend sat sun
+    ///     12-25 observed nearest: Christmas Day
+    ///     fourth thu of nov: Thanksgiving
+    ///     easter-2: Good Friday  # closed for the long weekend
+    /// ";
+    /// let calendar = BusinessCalendar::from_rules(rules).unwrap();
+    /// let thanksgiving = Utc.with_ymd_and_hms(2025, 11, 27, 0, 0, 0).unwrap();
+    /// assert!(calendar.is_holiday(&thanksgiving));
+    ///
+    /// assert!(BusinessCalendar::from_rules("13-01: Nothing").is_err());
+    /// ```
+    pub fn from_rules(text: &str) -> Result<Self, HolidayParseError> {
+        let parsed = parse_rules(text)?;
+        let mut calendar = BusinessCalendar::new();
+        if let Some(weekend) = parsed.weekend {
+            calendar = calendar.with_weekend(&weekend);
+        }
+        calendar.holidays = parsed.holidays;
+        Ok(calendar)
+    }
+
+    /// United States federal holidays, with weekend holidays observed on the nearest weekday.
+    pub fn united_states() -> Self {
+        BusinessCalendar::from_rules(US_HOLIDAYS).expect("built-in US holidays are valid")
+    }
+
+    /// Bank holidays in England and Wales, with substitute days for weekend holidays.
+    ///
+    /// One-off and moved bank holidays proclaimed from 1995 to 2023 are included, such as
+    /// the Platinum Jubilee in 2022. Proclamations made after this release are not, so add
+    /// them with [`with_holiday`](Self::with_holiday).
+    pub fn united_kingdom() -> Self {
+        BusinessCalendar::from_rules(UK_HOLIDAYS).expect("built-in UK holidays are valid")
+    }
+
+    /// Brazilian national holidays, plus Carnival and Corpus Christi as kept by banks.
+    pub fn brazil() -> Self {
+        BusinessCalendar::from_rules(BR_HOLIDAYS).expect("built-in BR holidays are valid")
+    }
+
+    /// Returns the built-in calendar for a country code (`US`, `UK`/`GB` or `BR`), ignoring case.
+    ///
+    /// ```
+    /// assert!(reddish::BusinessCalendar::for_country("gb").is_some());
+    /// assert!(reddish::BusinessCalendar::for_country("XX").is_none());
+    /// ```
+    pub fn for_country(code: &str) -> Option<Self> {
+        match code.to_ascii_uppercase().as_str() {
+            "US" | "USA" => Some(BusinessCalendar::united_states()),
+            "UK" | "GB" | "GBR" => Some(BusinessCalendar::united_kingdom()),
+            "BR" | "BRA" => Some(BusinessCalendar::brazil()),
+            _ => None,
+        }
+    }
+
+    /// Replaces the weekend days.
+    ///
+    /// # Panics
+    ///
+    /// Panics if every day of the week is a weekend day, since no business day would be left.
+    pub fn with_weekend(mut self, days: &[Weekday]) -> Self {
+        self.weekend = [false; 7];
+        for day in days {
+            self.weekend[day.num_days_from_monday() as usize] = true;
+        }
+        assert!(
+            self.weekend.contains(&false),
+            "a business calendar needs at least one working weekday"
+        );
+        self
+    }
+
+    /// Adds a holiday to the calendar.
+    pub fn with_holiday(mut self, holiday: Holiday) -> Self {
+        self.holidays.push(holiday);
+        self
+    }
+
+    /// Returns the weekend days, starting from Monday.
+    pub fn weekend(&self) -> Vec<Weekday> {
+        let mut day = Weekday::Mon;
+        let mut days = Vec::new();
+        for &is_weekend in &self.weekend {
+            if is_weekend {
+                days.push(day);
+            }
+            day = day.succ();
+        }
+        days
+    }
+
+    /// Returns the holiday rules of the calendar.
+    pub fn holidays(&self) -> &[Holiday] {
+        &self.holidays
+    }
+
+    /// Returns the days taken off for holidays in `year`, sorted by date. A holiday that
+    /// falls on a weekend appears on its observed date, which may be in the next or
+    /// previous year.
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// let calendar = reddish::BusinessCalendar::united_kingdom();
+    /// let days: Vec<NaiveDate> =
+    ///     calendar.holidays_in(2022).into_iter().map(|(date, _)| date).collect();
+    /// // Christmas Day fell on a Sunday, so Tuesday the 27th was a substitute day
+    /// assert!(days.contains(&NaiveDate::from_ymd_opt(2022, 12, 26).unwrap()));
+    /// assert!(days.contains(&NaiveDate::from_ymd_opt(2022, 12, 27).unwrap()));
+    /// ```
+    pub fn holidays_in(&self, year: i32) -> Vec<(NaiveDate, &Holiday)> {
+        let mut days: Vec<(NaiveDate, &Holiday)> = (year - 1..=year + 1)
+            .flat_map(|rule_year| self.observed_in(rule_year))
+            .filter(|(date, _)| date.year() == year)
+            .collect();
+        days.sort_by_key(|(date, _)| *date);
+        days
+    }
+
+    /// Checks whether a datetime falls on a weekend day of this calendar.
+    pub fn is_weekend_day<Tz: TimeZone>(&self, datetime: &DateTime<Tz>) -> bool {
+        self.is_weekend_date(datetime.date_naive())
+    }
+
+    /// Checks whether a datetime falls on an observed holiday.
+    pub fn is_holiday<Tz: TimeZone>(&self, datetime: &DateTime<Tz>) -> bool {
+        let date = datetime.date_naive();
+        self.holidays_in(date.year()).iter().any(|(observed, _)| *observed == date)
+    }
+
+    /// Checks whether a datetime falls on a day that is neither a weekend day nor a holiday.
+    pub fn is_business_day<Tz: TimeZone>(&self, datetime: &DateTime<Tz>) -> bool {
+        YearCache::new(self).is_business_date(datetime.date_naive())
+    }
+
+    /// Moves forward (or backward, for a negative count) by the given number of business
+    /// days, keeping the time of day. Weekend days and holidays are skipped; adding zero
+    /// returns the datetime unchanged even if it is not a business day. Returns `None` if
+    /// the result is out of range.
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// let calendar = reddish::BusinessCalendar::new();
+    /// let friday = Utc.with_ymd_and_hms(2024, 5, 10, 17, 0, 0).unwrap();
+    /// let monday = Utc.with_ymd_and_hms(2024, 5, 13, 17, 0, 0).unwrap();
+    /// assert_eq!(calendar.add_business_days(&friday, 1), Some(monday));
+    /// let week_before = Utc.with_ymd_and_hms(2024, 5, 3, 17, 0, 0).unwrap();
+    /// assert_eq!(calendar.add_business_days(&friday, -5), Some(week_before));
+    /// assert_eq!(calendar.add_business_days(&friday, i64::MAX), None);
+    /// ```
+    pub fn add_business_days<Tz: TimeZone>(
+        &self,
+        datetime: &DateTime<Tz>,
+        days: i64,
+    ) -> Option<DateTime<Tz>> {
+        let forward = days >= 0;
+        let working_days = self.working_days_per_week();
+        let mut remaining = days.unsigned_abs();
+        let mut date = datetime.date_naive();
+
+        // Jump whole weeks while that cannot overshoot, then walk the last few days
+        while remaining > working_days {
+            let weeks = (remaining - 1) / working_days;
+            let span = Duration::try_days(i64::try_from(weeks).ok()?.checked_mul(7)?)?;
+            let counted = if forward {
+                let target = date.checked_add_signed(span)?;
+                let counted = self.business_dates_in(date, target);
+                date = target;
+                counted
+            } else {
+                // Counts [target, date) rather than (target, date]
+                let target = date.checked_sub_signed(span)?;
+                let mut cache = YearCache::new(self);
+                let counted = self.business_dates_in(target, date)
+                    + cache.is_business_date(target) as u64
+                    - cache.is_business_date(date) as u64;
+                date = target;
+                counted
+            };
+            remaining -= counted;
+        }
+
+        let mut cache = YearCache::new(self);
+        while remaining > 0 {
+            date = if forward { date.succ_opt()? } else { date.pred_opt()? };
+            if cache.is_business_date(date) {
+                remaining -= 1;
+            }
+        }
+        Some(with_date(datetime, date))
+    }
+
+    /// Counts the business days after `from` up to and including `to`, so that adding the
+    /// result to `from` reaches `to` when both are business days. The count is negative
+    /// when `to` comes first, and only the local dates matter.
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// let calendar = reddish::BusinessCalendar::new();
+    /// let monday = Utc.with_ymd_and_hms(2024, 5, 6, 9, 0, 0).unwrap();
+    /// let next_monday = Utc.with_ymd_and_hms(2024, 5, 13, 9, 0, 0).unwrap();
+    /// assert_eq!(calendar.business_days_between(&monday, &next_monday), 5);
+    /// assert_eq!(calendar.business_days_between(&next_monday, &monday), -5);
+    /// ```
+    pub fn business_days_between<Tz1: TimeZone, Tz2: TimeZone>(
+        &self,
+        from: &DateTime<Tz1>,
+        to: &DateTime<Tz2>,
+    ) -> i64 {
+        let (start, end) = (from.date_naive(), to.date_naive());
+        if start <= end {
+            self.business_dates_in(start, end) as i64
+        } else {
+            // Going backwards counts the days from `to` up to, but excluding, `from`
+            let mut cache = YearCache::new(self);
+            let counted = self.business_dates_in(end, start) + cache.is_business_date(end) as u64
+                - cache.is_business_date(start) as u64;
+            -(counted as i64)
+        }
+    }
+
+    /// Returns the first business day after the datetime's date, at the same time of day,
+    /// or `None` if it is out of range.
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// let calendar = reddish::BusinessCalendar::brazil();
+    /// // Carnival Monday and Tuesday in 2025 were 3 and 4 March
+    /// let friday = Utc.with_ymd_and_hms(2025, 2, 28, 12, 0, 0).unwrap();
+    /// let ash_wednesday = Utc.with_ymd_and_hms(2025, 3, 5, 12, 0, 0).unwrap();
+    /// assert_eq!(calendar.next_business_day(&friday), Some(ash_wednesday));
+    /// ```
+    pub fn next_business_day<Tz: TimeZone>(&self, datetime: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        self.add_business_days(datetime, 1)
+    }
+
+    fn working_days_per_week(&self) -> u64 {
+        self.weekend.iter().filter(|&&is_weekend| !is_weekend).count() as u64
+    }
+
+    // Counts the business days after `after` up to and including `through`, working out
+    // whole weeks arithmetically
+    fn business_dates_in(&self, after: NaiveDate, through: NaiveDate) -> u64 {
+        let weeks = (through - after).num_days() / 7;
+        let week_end = after + Duration::days(weeks * 7);
+        let mut count =
+            weeks as u64 * self.working_days_per_week() - self.working_holidays_in(after, week_end);
+
+        let mut cache = YearCache::new(self);
+        let mut date = week_end;
+        while date < through {
+            date += Duration::days(1);
+            if cache.is_business_date(date) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    // Counts the distinct observed holidays after `after` up to and including `through`
+    // that fall on working weekdays
+    fn working_holidays_in(&self, after: NaiveDate, through: NaiveDate) -> u64 {
+        if self.holidays.is_empty() || after >= through {
+            return 0;
+        }
+        (after.year()..=through.year())
+            .map(|year| {
+                let mut dates: Vec<NaiveDate> = self
+                    .holidays_in(year)
+                    .into_iter()
+                    .map(|(date, _)| date)
+                    .filter(|date| {
+                        *date > after && *date <= through && !self.is_weekend_date(*date)
+                    })
+                    .collect();
+                dates.dedup();
+                dates.len() as u64
+            })
+            .sum()
+    }
+
+    fn is_weekend_date(&self, date: NaiveDate) -> bool {
+        self.weekend[date.weekday().num_days_from_monday() as usize]
+    }
+
+    // Observed dates of every holiday whose rule falls in `rule_year`
+    fn observed_in(&self, rule_year: i32) -> Vec<(NaiveDate, &Holiday)> {
+        let actual: Vec<(NaiveDate, &Holiday)> = self
+            .holidays
+            .iter()
+            .filter_map(|holiday| holiday.date_in(rule_year).map(|date| (date, holiday)))
+            .collect();
+
+        let stays = |(date, holiday): &(NaiveDate, &Holiday)| {
+            holiday.observance == Observance::None || !self.is_weekend_date(*date)
+        };
+        let mut observed: Vec<(NaiveDate, &Holiday)> =
+            actual.iter().copied().filter(stays).collect();
+        let mut taken: HashSet<NaiveDate> = observed.iter().map(|(date, _)| *date).collect();
+
+        let mut moved: Vec<(NaiveDate, &Holiday)> =
+            actual.into_iter().filter(|day| !stays(day)).collect();
+        moved.sort_by_key(|(date, _)| *date);
+        for (date, holiday) in moved {
+            let date = match holiday.observance {
+                Observance::Nearest => self.nearest_working_date(date),
+                _ => {
+                    let mut next = date + Duration::days(1);
+                    while self.is_weekend_date(next) || taken.contains(&next) {
+                        next += Duration::days(1);
+                    }
+                    next
+                }
+            };
+            taken.insert(date);
+            observed.push((date, holiday));
+        }
+
+        observed
+    }
+
+    // The closest day that is not a weekend day, preferring the later one on a tie
+    fn nearest_working_date(&self, date: NaiveDate) -> NaiveDate {
+        let mut distance = 1;
+        loop {
+            let after = date + Duration::days(distance);
+            if !self.is_weekend_date(after) {
+                return after;
+            }
+            let before = date - Duration::days(distance);
+            if !self.is_weekend_date(before) {
+                return before;
+            }
+            distance += 1;
+        }
+    }
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        BusinessCalendar::new()
+    }
+}
+
+// Remembers the holidays of the last year looked up, so walking day by day only works
+// them out once per year
+struct YearCache<'a> {
+    calendar: &'a BusinessCalendar,
+    year: Option<i32>,
+    holidays: HashSet<NaiveDate>,
+}
+
+impl<'a> YearCache<'a> {
+    fn new(calendar: &'a BusinessCalendar) -> Self {
+        YearCache {
+            calendar,
+            year: None,
+            holidays: HashSet::new(),
+        }
+    }
+
+    fn is_business_date(&mut self, date: NaiveDate) -> bool {
+        if self.calendar.is_weekend_date(date) {
+            return false;
+        }
+        if self.year != Some(date.year()) {
+            self.year = Some(date.year());
+            self.holidays =
+                self.calendar.holidays_in(date.year()).into_iter().map(|(date, _)| date).collect();
+        }
+        !self.holidays.contains(&date)
+    }
+}
+
+// Moves a datetime to another local date, keeping its wall-clock time
+fn with_date<Tz: TimeZone>(datetime: &DateTime<Tz>, date: NaiveDate) -> DateTime<Tz> {
+    resolve_local(&datetime.timezone(), date.and_time(datetime.naive_local().time()))
+}
@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, Datelike, TimeZone};
+
+/// Returns the day of the year, from 1 to 366, in the datetime's own time zone.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+///
+/// assert_eq!(reddish::day_of_year(&Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()), 1);
+/// assert_eq!(reddish::day_of_year(&Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap()), 366);
+/// ```
+pub fn day_of_year<Tz: TimeZone>(datetime: &DateTime<Tz>) -> u32 {
+    datetime.ordinal()
+}
+
+/// Returns the ISO 8601 week-numbering year and week number (1 to 53).
+///
+/// The ISO year differs from the calendar year for a few days around New Year, because
+/// week 1 is the week containing the first Thursday of January.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+///
+/// assert_eq!(reddish::iso_week(&Utc.with_ymd_and_hms(2024, 5, 15, 0, 0, 0).unwrap()), (2024, 20));
+/// // 30 December 2024 already belongs to week 1 of 2025
+/// assert_eq!(reddish::iso_week(&Utc.with_ymd_and_hms(2024, 12, 30, 0, 0, 0).unwrap()), (2025, 1));
+/// ```
+pub fn iso_week<Tz: TimeZone>(datetime: &DateTime<Tz>) -> (i32, u32) {
+    let week = datetime.iso_week();
+    (week.year(), week.week())
+}
@@ -1,9 +1,11 @@
 #![allow(dead_code)]
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeZone};
 
 /// Calculates the number of days between two dates.
 /// Returns a positive number if the second date is after the first, negative otherwise.
+/// Only whole 24-hour periods are counted, and the dates may be in different time zones.
+/// Use [`diff_in`](crate::diff_in) to count calendar days, months or years.
 ///
 /// ```
 /// use chrono::{Utc, TimeZone};
@@ -18,7 +20,10 @@ use chrono::{DateTime, Utc};
 /// let date2 = Utc.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap();
 /// assert_eq!(reddish::days_between(&date1, &date2), -4);
 /// ```
-pub fn days_between(date1: &DateTime<Utc>, date2: &DateTime<Utc>) -> i64 {
-    let duration = date2.signed_duration_since(*date1);
+pub fn days_between<Tz1: TimeZone, Tz2: TimeZone>(
+    date1: &DateTime<Tz1>,
+    date2: &DateTime<Tz2>,
+) -> i64 {
+    let duration = date2.clone().signed_duration_since(date1);
     duration.num_days()
 }
@@ -0,0 +1,37 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, Datelike, TimeZone};
+
+/// Checks whether a year is a leap year in the Gregorian calendar.
+///
+/// ```
+/// assert!(reddish::is_leap_year(2024));
+/// assert!(reddish::is_leap_year(2000));
+/// assert!(!reddish::is_leap_year(1900));
+/// assert!(!reddish::is_leap_year(2023));
+/// ```
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the number of days in the month containing `datetime`, in its own time zone.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+///
+/// assert_eq!(reddish::days_in_month(&Utc.with_ymd_and_hms(2024, 2, 10, 0, 0, 0).unwrap()), 29);
+/// assert_eq!(reddish::days_in_month(&Utc.with_ymd_and_hms(2023, 2, 10, 0, 0, 0).unwrap()), 28);
+/// assert_eq!(reddish::days_in_month(&Utc.with_ymd_and_hms(2023, 4, 10, 0, 0, 0).unwrap()), 30);
+/// ```
+pub fn days_in_month<Tz: TimeZone>(datetime: &DateTime<Tz>) -> u32 {
+    month_length(datetime.year(), datetime.month())
+}
+
+pub(crate) fn month_length(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
@@ -0,0 +1,77 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, Datelike, TimeZone};
+
+use super::add::add;
+use super::time_unit::TimeUnit;
+
+/// Counts the whole units between two dates: the largest number that can be
+/// [`add`](crate::add)ed to `from` without passing `to`. The result is negative when `to`
+/// is before `from`.
+///
+/// Days and longer units are counted on the calendar of `from`'s time zone, so
+/// 31 January to 28 February is one month, and 15 March to 14 April is not.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use reddish::TimeUnit;
+///
+/// let jan_31 = Utc.with_ymd_and_hms(2023, 1, 31, 0, 0, 0).unwrap();
+/// let feb_28 = Utc.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap();
+/// assert_eq!(reddish::diff_in(&jan_31, &feb_28, TimeUnit::Month), 1);
+///
+/// let birth = Utc.with_ymd_and_hms(1990, 6, 15, 0, 0, 0).unwrap();
+/// let today = Utc.with_ymd_and_hms(2024, 6, 14, 0, 0, 0).unwrap();
+/// assert_eq!(reddish::diff_in(&birth, &today, TimeUnit::Year), 33);
+/// assert_eq!(reddish::diff_in(&today, &birth, TimeUnit::Year), -33);
+/// ```
+pub fn diff_in<Tz1: TimeZone, Tz2: TimeZone>(
+    from: &DateTime<Tz1>,
+    to: &DateTime<Tz2>,
+    unit: TimeUnit,
+) -> i64 {
+    let to = to.with_timezone(&from.timezone());
+    let elapsed = to.clone().signed_duration_since(from);
+
+    match unit {
+        TimeUnit::Second => elapsed.num_seconds(),
+        TimeUnit::Minute => elapsed.num_minutes(),
+        TimeUnit::Hour => elapsed.num_hours(),
+        TimeUnit::Day => {
+            let estimate = (to.date_naive() - from.date_naive()).num_days();
+            settle(from, &to, estimate, TimeUnit::Day)
+        }
+        TimeUnit::Week => diff_in(from, &to, TimeUnit::Day) / 7,
+        TimeUnit::Month | TimeUnit::Quarter | TimeUnit::Year => {
+            let years = to.year() as i64 - from.year() as i64;
+            let estimate = years * 12 + to.month() as i64 - from.month() as i64;
+            let months = settle(from, &to, estimate, TimeUnit::Month);
+            match unit {
+                TimeUnit::Quarter => months / 3,
+                TimeUnit::Year => months / 12,
+                _ => months,
+            }
+        }
+    }
+}
+
+// Steps a calendar estimate back by one when adding it to `from` would overshoot `to`
+fn settle<Tz: TimeZone>(
+    from: &DateTime<Tz>,
+    to: &DateTime<Tz>,
+    estimate: i64,
+    unit: TimeUnit,
+) -> i64 {
+    let landed = match add(from, estimate, unit) {
+        Some(landed) => landed,
+        // Out of range means well past `to`
+        None => return estimate - estimate.signum(),
+    };
+    if estimate > 0 && landed > *to {
+        estimate - 1
+    } else if estimate < 0 && landed < *to {
+        estimate + 1
+    } else {
+        estimate
+    }
+}
@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use chrono::NaiveDate;
+
+/// Returns the date of Western (Gregorian) Easter Sunday in the given year.
+///
+/// ```
+/// use chrono::NaiveDate;
+/// assert_eq!(reddish::easter_sunday(2024), NaiveDate::from_ymd_opt(2024, 3, 31));
+/// assert_eq!(reddish::easter_sunday(2025), NaiveDate::from_ymd_opt(2025, 4, 20));
+/// ```
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    // The anonymous Gregorian algorithm (Meeus/Jones/Butcher)
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
@@ -1,8 +1,16 @@
 #![allow(dead_code)]
 
-use chrono::{DateTime, Utc, Datelike, TimeZone};
+use chrono::{DateTime, TimeZone};
 
-/// Returns the last day of the month for a given date.
+use super::period::Period;
+use super::start_of::end_of;
+
+/// Returns the last instant of the month for a given date: one nanosecond before the next
+/// month starts in the date's own time zone. See [`end_of`](crate::end_of).
+///
+/// # Panics
+///
+/// Panics if `datetime` is in the last month chrono supports.
 ///
 /// ```
 /// use chrono::{Utc, TimeZone, Datelike, Timelike};
@@ -27,16 +35,6 @@ use chrono::{DateTime, Utc, Datelike, TimeZone};
 /// assert_eq!(result.day(), 29); // February 2024 has 29 days (leap year)
 /// assert_eq!(result.month(), 2);
 /// ```
-pub fn end_of_month(datetime: &DateTime<Utc>) -> DateTime<Utc> {
-    let year = datetime.year();
-    let month = datetime.month();
-
-    // Get the first day of next month, then subtract one day
-    let next_month = if month == 12 {
-        Utc.with_ymd_and_hms(year + 1, 1, 1, 0, 0, 0).unwrap()
-    } else {
-        Utc.with_ymd_and_hms(year, month + 1, 1, 0, 0, 0).unwrap()
-    };
-
-    next_month - chrono::Duration::seconds(1)
+pub fn end_of_month<Tz: TimeZone>(datetime: &DateTime<Tz>) -> DateTime<Tz> {
+    end_of(datetime, Period::Month).expect("date out of range")
 }
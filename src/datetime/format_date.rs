@@ -1,9 +1,12 @@
 #![allow(dead_code)]
 
-use chrono::{DateTime, Utc};
+use std::fmt::Display;
+
+use chrono::{DateTime, SecondsFormat, TimeZone};
 
 /// Formats a datetime using a specified format string.
-/// Uses strftime format specifiers.
+/// Uses strftime format specifiers, rendering the wall-clock time of the datetime's own
+/// time zone.
 ///
 /// ```
 /// use chrono::{Utc, TimeZone};
@@ -18,7 +21,10 @@ use chrono::{DateTime, Utc};
 /// let result = reddish::format_date(&date, "%B %d, %Y");
 /// assert_eq!(result, "December 25, 2023");
 /// ```
-pub fn format_date(datetime: &DateTime<Utc>, format: &str) -> String {
+pub fn format_date<Tz: TimeZone>(datetime: &DateTime<Tz>, format: &str) -> String
+where
+    Tz::Offset: Display,
+{
     datetime.format(format).to_string()
 }
 
@@ -31,11 +37,14 @@ pub fn format_date(datetime: &DateTime<Utc>, format: &str) -> String {
 /// assert!(result.contains("December 25, 2023"));
 /// assert!(result.contains("3:30 PM"));
 /// ```
-pub fn format_date_human(datetime: &DateTime<Utc>) -> String {
+pub fn format_date_human<Tz: TimeZone>(datetime: &DateTime<Tz>) -> String
+where
+    Tz::Offset: Display,
+{
     datetime.format("%B %d, %Y at %l:%M %p").to_string()
 }
 
-/// Formats a datetime in ISO 8601 format.
+/// Formats a datetime in ISO 8601 format, with `Z` for UTC and a numeric offset otherwise.
 ///
 /// ```
 /// use chrono::{Utc, TimeZone};
@@ -43,6 +52,16 @@ pub fn format_date_human(datetime: &DateTime<Utc>) -> String {
 /// let result = reddish::format_date_iso(&date);
 /// assert_eq!(result, "2023-12-25T15:30:45Z");
 /// ```
-pub fn format_date_iso(datetime: &DateTime<Utc>) -> String {
-    datetime.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+///
+/// ```
+/// use chrono::TimeZone;
+/// use chrono_tz::America::Sao_Paulo;
+/// let date = Sao_Paulo.with_ymd_and_hms(2023, 12, 25, 15, 30, 45).unwrap();
+/// assert_eq!(reddish::format_date_iso(&date), "2023-12-25T15:30:45-03:00");
+/// ```
+pub fn format_date_iso<Tz: TimeZone>(datetime: &DateTime<Tz>) -> String
+where
+    Tz::Offset: Display,
+{
+    datetime.to_rfc3339_opts(SecondsFormat::Secs, true)
 }
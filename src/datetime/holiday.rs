@@ -0,0 +1,145 @@
+#![allow(dead_code)]
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use super::days_in_month::month_length;
+use super::easter::easter_sunday;
+
+/// How a [`Holiday`] picks its date each year.
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use reddish::HolidayRule;
+///
+/// // Thanksgiving in the United States
+/// let thanksgiving = HolidayRule::NthWeekday { month: 11, weekday: Weekday::Thu, n: 4 };
+/// assert_eq!(thanksgiving.date_in(2024), NaiveDate::from_ymd_opt(2024, 11, 28));
+///
+/// let good_friday = HolidayRule::Easter(-2);
+/// assert_eq!(good_friday.date_in(2024), NaiveDate::from_ymd_opt(2024, 3, 29));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HolidayRule {
+    /// The same month and day every year.
+    Fixed { month: u32, day: u32 },
+    /// The `n`th given weekday of a month, counting from 1. A negative `n` counts from the
+    /// end, so `-1` is the last one.
+    NthWeekday { month: u32, weekday: Weekday, n: i8 },
+    /// A number of days before (negative) or after Easter Sunday.
+    Easter(i64),
+    /// A single date, such as a one-off national day of mourning.
+    Date(NaiveDate),
+}
+
+impl HolidayRule {
+    /// Returns the date the rule falls on in `year`, or `None` if it does not occur that year.
+    pub fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            HolidayRule::Fixed { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+            HolidayRule::NthWeekday { month, weekday, n } if n > 0 => {
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, n as u8)
+            }
+            HolidayRule::NthWeekday { month, weekday, n } if n < 0 => {
+                let last = NaiveDate::from_ymd_opt(year, month, month_length(year, month))?;
+                let last_day = last.weekday().num_days_from_monday();
+                let back = (last_day + 7 - weekday.num_days_from_monday()) % 7;
+                let date = last - Duration::days(back as i64 + 7 * (-(n as i64) - 1));
+                if date.month() == month {
+                    Some(date)
+                } else {
+                    None
+                }
+            }
+            HolidayRule::NthWeekday { .. } => None,
+            HolidayRule::Easter(offset) => {
+                easter_sunday(year)?.checked_add_signed(Duration::try_days(offset)?)
+            }
+            HolidayRule::Date(date) if date.year() == year => Some(date),
+            HolidayRule::Date(_) => None,
+        }
+    }
+}
+
+/// What happens when a holiday falls on a weekend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Observance {
+    /// The holiday is not moved.
+    #[default]
+    None,
+    /// A holiday on Saturday is observed on Friday and one on Sunday on Monday, as for
+    /// United States federal holidays.
+    Nearest,
+    /// The holiday is observed on the next working day that is not already a holiday, as
+    /// for substitute bank holidays in the United Kingdom.
+    NextWorkingDay,
+}
+
+/// A named holiday for a [`BusinessCalendar`](crate::BusinessCalendar).
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use reddish::{Holiday, HolidayRule, Observance};
+///
+/// let juneteenth = Holiday::new("Juneteenth", HolidayRule::Fixed { month: 6, day: 19 })
+///     .with_observance(Observance::Nearest)
+///     .with_years(Some(2021), None);
+/// assert_eq!(juneteenth.date_in(2020), None);
+/// assert_eq!(juneteenth.date_in(2022), NaiveDate::from_ymd_opt(2022, 6, 19));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Holiday {
+    pub name: String,
+    pub rule: HolidayRule,
+    pub observance: Observance,
+    /// The first year the holiday is kept, if it has not always been.
+    pub from_year: Option<i32>,
+    /// The last year the holiday is kept, if it has been abolished.
+    pub until_year: Option<i32>,
+    /// Years the rule is skipped, for instance because the holiday was moved by proclamation.
+    pub excluded_years: Vec<i32>,
+}
+
+impl Holiday {
+    /// Creates a holiday that is kept every year and never moved.
+    pub fn new(name: &str, rule: HolidayRule) -> Self {
+        Holiday {
+            name: name.to_string(),
+            rule,
+            observance: Observance::None,
+            from_year: None,
+            until_year: None,
+            excluded_years: Vec::new(),
+        }
+    }
+
+    /// Sets what happens when the holiday falls on a weekend.
+    pub fn with_observance(mut self, observance: Observance) -> Self {
+        self.observance = observance;
+        self
+    }
+
+    /// Limits the holiday to the years from `from_year` to `until_year`, both inclusive.
+    pub fn with_years(mut self, from_year: Option<i32>, until_year: Option<i32>) -> Self {
+        self.from_year = from_year;
+        self.until_year = until_year;
+        self
+    }
+
+    /// Skips the holiday in the given years. Add a [`HolidayRule::Date`] holiday for the day
+    /// it moved to, if any.
+    pub fn with_excluded_years(mut self, years: &[i32]) -> Self {
+        self.excluded_years = years.to_vec();
+        self
+    }
+
+    /// Returns the actual (not observed) date of the holiday in `year`, if it is kept that year.
+    pub fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        if self.from_year.map_or(false, |from| year < from)
+            || self.until_year.map_or(false, |until| year > until)
+            || self.excluded_years.contains(&year)
+        {
+            return None;
+        }
+        self.rule.date_in(year)
+    }
+}
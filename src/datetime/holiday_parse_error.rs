@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by [`BusinessCalendar::from_rules`](crate::BusinessCalendar::from_rules).
+/// Line numbers start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HolidayParseError {
+    /// A holiday line has no `: name` part, or the name is empty.
+    MissingName { line: usize },
+    /// The date rule of a holiday could not be understood.
+    InvalidRule { line: usize, rule: String },
+    /// An `observed`, `from`, `until` or `except` option is unknown or has a bad value.
+    InvalidOption { line: usize, option: String },
+    /// A `weekend` line names something that is not a weekday.
+    InvalidWeekday { line: usize, day: String },
+    /// A `weekend` line covers every day of the week, leaving no business days.
+    NoWorkingDays { line: usize },
+}
+
+impl fmt::Display for HolidayParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HolidayParseError::MissingName { line } => {
+                write!(f, "line {}: holiday has no name", line)
+            }
+            HolidayParseError::InvalidRule { line, rule } => {
+                write!(f, "line {}: invalid holiday rule '{}'", line, rule)
+            }
+            HolidayParseError::InvalidOption { line, option } => {
+                write!(f, "line {}: invalid option '{}'", line, option)
+            }
+            HolidayParseError::InvalidWeekday { line, day } => {
+                write!(f, "line {}: '{}' is not a weekday", line, day)
+            }
+            HolidayParseError::NoWorkingDays { line } => {
+                write!(f, "line {}: the weekend leaves no working days", line)
+            }
+        }
+    }
+}
+
+impl Error for HolidayParseError {}
@@ -0,0 +1,152 @@
+#![allow(dead_code)]
+
+use chrono::{Month, NaiveDate, Weekday};
+
+use super::holiday::{Holiday, HolidayRule, Observance};
+use super::holiday_parse_error::HolidayParseError;
+
+// Easter falls between March 22 and April 25, so larger offsets leave its year.
+const MAX_EASTER_OFFSET: i64 = 365;
+
+// The weekend and holidays read from the text format described on
+// `BusinessCalendar::from_rules`. The weekend is `None` when the text does not set one.
+pub(crate) struct ParsedRules {
+    pub(crate) weekend: Option<Vec<Weekday>>,
+    pub(crate) holidays: Vec<Holiday>,
+}
+
+pub(crate) fn parse_rules(text: &str) -> Result<ParsedRules, HolidayParseError> {
+    let mut parsed = ParsedRules {
+        weekend: None,
+        holidays: Vec::new(),
+    };
+
+    for (index, raw) in text.lines().enumerate() {
+        let line = index + 1;
+        let content = raw.split('#').next().unwrap_or("").trim();
+        if content.is_empty() {
+            continue;
+        }
+
+        let mut words = content.split_whitespace();
+        if words.next().map(|word| word.eq_ignore_ascii_case("weekend")) == Some(true)
+            && !content.contains(':')
+        {
+            let days = words
+                .map(|day| {
+                    day.trim_end_matches(',').parse::<Weekday>().map_err(|_| {
+                        HolidayParseError::InvalidWeekday { line, day: day.to_string() }
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut covered = [false; 7];
+            for day in &days {
+                covered[day.num_days_from_monday() as usize] = true;
+            }
+            if !covered.contains(&false) {
+                return Err(HolidayParseError::NoWorkingDays { line });
+            }
+            parsed.weekend = Some(days);
+            continue;
+        }
+
+        let (spec, name) = match content.find(':') {
+            Some(colon) => (content[..colon].trim(), content[colon + 1..].trim()),
+            None => return Err(HolidayParseError::MissingName { line }),
+        };
+        if name.is_empty() {
+            return Err(HolidayParseError::MissingName { line });
+        }
+        parsed.holidays.push(parse_holiday(spec, name, line)?);
+    }
+
+    Ok(parsed)
+}
+
+fn parse_holiday(spec: &str, name: &str, line: usize) -> Result<Holiday, HolidayParseError> {
+    let words: Vec<&str> = spec.split_whitespace().collect();
+    let options_at = words
+        .iter()
+        .position(|word| {
+            matches!(word.to_ascii_lowercase().as_str(), "observed" | "from" | "until" | "except")
+        })
+        .unwrap_or(words.len());
+
+    let rule_text = words[..options_at].join(" ");
+    let rule =
+        parse_rule(&rule_text).ok_or(HolidayParseError::InvalidRule { line, rule: rule_text })?;
+    let mut holiday = Holiday::new(name, rule);
+
+    for option in words[options_at..].chunks(2) {
+        let invalid = || HolidayParseError::InvalidOption {
+            line,
+            option: option.join(" "),
+        };
+        let value = option.get(1).ok_or_else(invalid)?.to_ascii_lowercase();
+        match option[0].to_ascii_lowercase().as_str() {
+            "observed" => {
+                holiday.observance = match value.as_str() {
+                    "none" => Observance::None,
+                    "nearest" => Observance::Nearest,
+                    "next" => Observance::NextWorkingDay,
+                    _ => return Err(invalid()),
+                }
+            }
+            "from" => holiday.from_year = Some(value.parse().map_err(|_| invalid())?),
+            "until" => holiday.until_year = Some(value.parse().map_err(|_| invalid())?),
+            "except" => {
+                let years = value.split(',').map(|year| year.trim().parse().map_err(|_| invalid()));
+                holiday.excluded_years = years.collect::<Result<_, _>>()?;
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    Ok(holiday)
+}
+
+fn parse_rule(text: &str) -> Option<HolidayRule> {
+    let lower = text.to_ascii_lowercase();
+
+    if let Some(offset) = lower.strip_prefix("easter") {
+        let offset = offset.trim();
+        return if offset.is_empty() {
+            Some(HolidayRule::Easter(0))
+        } else {
+            let offset: i64 = offset.trim_start_matches('+').trim().parse().ok()?;
+            (offset.abs() <= MAX_EASTER_OFFSET).then_some(HolidayRule::Easter(offset))
+        };
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(&lower, "%Y-%m-%d") {
+        return Some(HolidayRule::Date(date));
+    }
+
+    let words: Vec<&str> = lower.split_whitespace().collect();
+    match words.as_slice() {
+        [month_day] => {
+            let (month, day) = month_day.split_once('-')?;
+            let (month, day) = (month.parse().ok()?, day.parse().ok()?);
+            // Validate against a leap year so that 02-29 is accepted
+            NaiveDate::from_ymd_opt(2000, month, day)?;
+            Some(HolidayRule::Fixed { month, day })
+        }
+        [ordinal, weekday, "of", month] => {
+            let n = match *ordinal {
+                "first" | "1st" => 1,
+                "second" | "2nd" => 2,
+                "third" | "3rd" => 3,
+                "fourth" | "4th" => 4,
+                "fifth" | "5th" => 5,
+                "last" => -1,
+                _ => return None,
+            };
+            Some(HolidayRule::NthWeekday {
+                month: month.parse::<Month>().ok()?.number_from_month(),
+                weekday: weekday.parse().ok()?,
+                n,
+            })
+        }
+        _ => None,
+    }
+}
@@ -1,8 +1,9 @@
 #![allow(dead_code)]
 
-use chrono::{DateTime, Utc, Weekday, Datelike};
+use chrono::{DateTime, Datelike, TimeZone, Weekday};
 
-/// Checks if a given date falls on a weekend (Saturday or Sunday).
+/// Checks if a given date falls on a weekend (Saturday or Sunday) in its own time zone.
+/// See [`BusinessCalendar`](crate::BusinessCalendar) for other weekends and holidays.
 ///
 /// ```
 /// use chrono::{Utc, TimeZone};
@@ -17,6 +18,14 @@ use chrono::{DateTime, Utc, Weekday, Datelike};
 /// let monday = Utc.with_ymd_and_hms(2023, 12, 4, 12, 0, 0).unwrap();
 /// assert_eq!(reddish::is_weekend(&monday), false);
 /// ```
-pub fn is_weekend(datetime: &DateTime<Utc>) -> bool {
+///
+/// ```
+/// use chrono::TimeZone;
+/// use chrono_tz::America::Sao_Paulo;
+/// // Saturday evening in São Paulo, already Sunday in UTC
+/// let saturday = Sao_Paulo.with_ymd_and_hms(2023, 12, 2, 22, 0, 0).unwrap();
+/// assert!(reddish::is_weekend(&saturday));
+/// ```
+pub fn is_weekend<Tz: TimeZone>(datetime: &DateTime<Tz>) -> bool {
     matches!(datetime.weekday(), Weekday::Sat | Weekday::Sun)
 }
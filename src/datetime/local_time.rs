@@ -0,0 +1,19 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, Duration, LocalResult, NaiveDateTime, Offset, TimeZone};
+
+// Turns a wall-clock time in `tz` into an instant. An ambiguous time (when clocks fall
+// back) resolves to the earlier instant; a time skipped when clocks spring forward moves
+// forward by the length of the gap, e.g. 02:30 becomes 03:30.
+pub(crate) fn resolve_local<Tz: TimeZone>(tz: &Tz, naive: NaiveDateTime) -> DateTime<Tz> {
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(datetime) => datetime,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => {
+            // Read the wall clock with the offset in force before the transition
+            let before = tz.offset_from_utc_datetime(&(naive - Duration::days(1))).fix();
+            let utc = naive - Duration::seconds(before.local_minus_utc() as i64);
+            tz.from_utc_datetime(&utc)
+        }
+    }
+}
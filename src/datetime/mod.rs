@@ -1,3 +1,5 @@
+mod local_time;
+
 mod format_duration;
 pub use format_duration::*;
 
@@ -24,3 +26,53 @@ pub use parse_date::*;
 
 mod format_date;
 pub use format_date::*;
+
+mod period;
+pub use period::*;
+
+mod start_of;
+pub use start_of::*;
+
+mod days_in_month;
+pub use days_in_month::*;
+
+mod day_of_year;
+pub use day_of_year::*;
+
+mod add_months;
+pub use add_months::*;
+
+mod add_weeks;
+pub use add_weeks::*;
+
+mod add_hours;
+pub use add_hours::*;
+
+mod time_unit;
+pub use time_unit::*;
+
+mod add;
+pub use add::*;
+
+mod diff_in;
+pub use diff_in::*;
+
+mod easter;
+pub use easter::*;
+
+mod holiday;
+pub use holiday::*;
+
+mod holiday_parse_error;
+pub use holiday_parse_error::*;
+
+mod holiday_parser;
+
+mod business_calendar;
+pub use business_calendar::*;
+
+mod start_of_day;
+pub use start_of_day::*;
+
+mod timezone;
+pub use timezone::*;
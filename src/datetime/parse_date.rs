@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 
-use chrono::{DateTime, Utc, NaiveDateTime};
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+
+use super::local_time::resolve_local;
 
 /// Parses a date string in various common formats.
 /// Returns None if the string cannot be parsed.
@@ -25,6 +27,25 @@ use chrono::{DateTime, Utc, NaiveDateTime};
 /// assert!(result.is_none());
 /// ```
 pub fn parse_date(date_str: &str) -> Option<DateTime<Utc>> {
+    parse_date_in(date_str, &Utc)
+}
+
+/// Parses a date string like [`parse_date`](crate::parse_date), reading times without an
+/// offset as wall-clock times in `timezone`. Strings with an offset or a trailing `Z` keep
+/// their instant and are converted to `timezone`.
+///
+/// ```
+/// use chrono::Timelike;
+/// use chrono_tz::America::Sao_Paulo;
+///
+/// let local = reddish::parse_date_in("2023-12-25 15:30:00", &Sao_Paulo).unwrap();
+/// assert_eq!(local.hour(), 15);
+/// assert_eq!(reddish::format_date_iso(&local), "2023-12-25T15:30:00-03:00");
+///
+/// let utc = reddish::parse_date_in("2023-12-25T15:30:00Z", &Sao_Paulo).unwrap();
+/// assert_eq!(utc.hour(), 12);
+/// ```
+pub fn parse_date_in<Tz: TimeZone>(date_str: &str, timezone: &Tz) -> Option<DateTime<Tz>> {
     // Try different common formats
     let formats = [
         "%Y-%m-%d %H:%M:%S",           // 2023-12-25 15:30:00
@@ -39,19 +60,28 @@ pub fn parse_date(date_str: &str) -> Option<DateTime<Utc>> {
 
     // First try parsing as RFC3339 (ISO 8601)
     if let Ok(dt) = DateTime::parse_from_rfc3339(date_str) {
-        return Some(dt.with_timezone(&Utc));
+        return Some(dt.with_timezone(timezone));
     }
 
+    // Formats ending in Z are UTC; the others are local wall-clock times
+    let resolve = |naive_dt: NaiveDateTime, format: &str| {
+        if format.ends_with('Z') {
+            timezone.from_utc_datetime(&naive_dt)
+        } else {
+            resolve_local(timezone, naive_dt)
+        }
+    };
+
     // Try each format
     for format in &formats {
         if let Ok(naive_dt) = NaiveDateTime::parse_from_str(date_str, format) {
-            return Some(DateTime::from_naive_utc_and_offset(naive_dt, Utc));
+            return Some(resolve(naive_dt, format));
         }
 
         // For date-only formats, try parsing as date and add midnight time
         if let Ok(naive_date) = chrono::NaiveDate::parse_from_str(date_str, format) {
             if let Some(naive_dt) = naive_date.and_hms_opt(0, 0, 0) {
-                return Some(DateTime::from_naive_utc_and_offset(naive_dt, Utc));
+                return Some(resolve(naive_dt, format));
             }
         }
     }
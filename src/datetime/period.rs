@@ -0,0 +1,31 @@
+#![allow(dead_code)]
+
+use chrono::Weekday;
+
+/// A calendar period used by [`start_of`](crate::start_of), [`end_of`](crate::end_of),
+/// [`start_of_next`](crate::start_of_next) and [`is_same_period`](crate::is_same_period).
+///
+/// ```
+/// use chrono::Weekday;
+/// use reddish::Period;
+///
+/// // Weeks starting on Sunday, as in the United States
+/// let us_week = Period::Week(Weekday::Sun);
+/// assert_ne!(us_week, Period::IsoWeek);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+    Minute,
+    Hour,
+    Day,
+    /// A week starting on the given weekday.
+    Week(Weekday),
+    /// An ISO 8601 week, which starts on Monday.
+    IsoWeek,
+    Month,
+    /// January–March, April–June, July–September or October–December.
+    Quarter,
+    /// January–June or July–December.
+    HalfYear,
+    Year,
+}
@@ -0,0 +1,137 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike};
+
+use super::add_months::shift_months;
+use super::local_time::resolve_local;
+use super::period::Period;
+
+/// Returns the first instant of the period containing `datetime`, in its own time zone.
+///
+/// Days and longer periods start at local midnight, or at the first wall-clock time that
+/// exists when a daylight saving change skips midnight.
+///
+/// # Panics
+///
+/// Panics if the period starts before the earliest date chrono supports.
+///
+/// ```
+/// use chrono::{TimeZone, Utc, Weekday};
+/// use reddish::Period;
+///
+/// // Wednesday, 15 May 2024
+/// let date = Utc.with_ymd_and_hms(2024, 5, 15, 13, 45, 30).unwrap();
+/// let at = |month, day, hour| Utc.with_ymd_and_hms(2024, month, day, hour, 0, 0).unwrap();
+/// assert_eq!(reddish::start_of(&date, Period::Hour), at(5, 15, 13));
+/// assert_eq!(reddish::start_of(&date, Period::IsoWeek), at(5, 13, 0));
+/// assert_eq!(reddish::start_of(&date, Period::Week(Weekday::Sun)), at(5, 12, 0));
+/// assert_eq!(reddish::start_of(&date, Period::Quarter), at(4, 1, 0));
+/// assert_eq!(reddish::start_of(&date, Period::HalfYear), at(1, 1, 0));
+/// ```
+pub fn start_of<Tz: TimeZone>(datetime: &DateTime<Tz>, period: Period) -> DateTime<Tz> {
+    let nanos = Duration::nanoseconds(datetime.nanosecond() as i64);
+    let seconds = Duration::seconds(datetime.second() as i64);
+    match period {
+        // Subtracting the elapsed time keeps the right instant when the hour repeats
+        Period::Minute => datetime.clone() - seconds - nanos,
+        Period::Hour => {
+            datetime.clone() - Duration::minutes(datetime.minute() as i64) - seconds - nanos
+        }
+        _ => {
+            let date = start_date(datetime.date_naive(), period);
+            resolve_local(&datetime.timezone(), date.and_hms_opt(0, 0, 0).unwrap())
+        }
+    }
+}
+
+/// Returns the first instant of the period after the one containing `datetime`: the
+/// exclusive end of the period. Returns `None` if that is past the latest date chrono supports.
+///
+/// ```
+/// use chrono::{DateTime, TimeZone, Utc};
+/// use reddish::Period;
+///
+/// let date = Utc.with_ymd_and_hms(2024, 11, 20, 8, 0, 0).unwrap();
+/// let next = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+/// assert_eq!(reddish::start_of_next(&date, Period::Quarter), Some(next));
+/// assert_eq!(reddish::start_of_next(&DateTime::<Utc>::MAX_UTC, Period::Year), None);
+/// ```
+pub fn start_of_next<Tz: TimeZone>(
+    datetime: &DateTime<Tz>,
+    period: Period,
+) -> Option<DateTime<Tz>> {
+    match period {
+        Period::Minute => start_of(datetime, period).checked_add_signed(Duration::minutes(1)),
+        Period::Hour => start_of(datetime, period).checked_add_signed(Duration::hours(1)),
+        _ => {
+            let start = start_date(datetime.date_naive(), period);
+            let next = match period {
+                Period::Day => start.checked_add_signed(Duration::days(1)),
+                Period::Week(_) | Period::IsoWeek => start.checked_add_signed(Duration::days(7)),
+                Period::Month => shift_months(start, 1),
+                Period::Quarter => shift_months(start, 3),
+                Period::HalfYear => shift_months(start, 6),
+                _ => shift_months(start, 12),
+            }?;
+            Some(resolve_local(&datetime.timezone(), next.and_hms_opt(0, 0, 0).unwrap()))
+        }
+    }
+}
+
+/// Returns the last instant of the period containing `datetime`: one nanosecond before
+/// [`start_of_next`](crate::start_of_next), so every instant in the period lies between
+/// [`start_of`](crate::start_of) and `end_of` inclusive. Returns `None` if the next period
+/// would start past the latest date chrono supports.
+///
+/// ```
+/// use chrono::{TimeZone, Timelike, Utc};
+/// use reddish::Period;
+///
+/// let date = Utc.with_ymd_and_hms(2024, 2, 10, 8, 0, 0).unwrap();
+/// let end = reddish::end_of(&date, Period::Month).unwrap();
+/// let last_second = Utc.with_ymd_and_hms(2024, 2, 29, 23, 59, 59).unwrap();
+/// assert_eq!(end, last_second + chrono::Duration::nanoseconds(999_999_999));
+/// assert_eq!(end.nanosecond(), 999_999_999);
+/// ```
+pub fn end_of<Tz: TimeZone>(datetime: &DateTime<Tz>, period: Period) -> Option<DateTime<Tz>> {
+    Some(start_of_next(datetime, period)? - Duration::nanoseconds(1))
+}
+
+/// Checks whether two datetimes fall in the same period, using the calendar of the first
+/// one's time zone.
+///
+/// ```
+/// use chrono::{TimeZone, Utc, Weekday};
+/// use reddish::Period;
+///
+/// let saturday = Utc.with_ymd_and_hms(2024, 5, 18, 9, 0, 0).unwrap();
+/// let sunday = Utc.with_ymd_and_hms(2024, 5, 19, 9, 0, 0).unwrap();
+/// assert!(reddish::is_same_period(&saturday, &sunday, Period::IsoWeek));
+/// assert!(!reddish::is_same_period(&saturday, &sunday, Period::Week(Weekday::Sun)));
+/// ```
+pub fn is_same_period<Tz1: TimeZone, Tz2: TimeZone>(
+    a: &DateTime<Tz1>,
+    b: &DateTime<Tz2>,
+    period: Period,
+) -> bool {
+    let b = b.with_timezone(&a.timezone());
+    start_of(a, period) == start_of(&b, period)
+}
+
+// The local date the period containing `date` starts on, for periods of a day or more
+fn start_date(date: NaiveDate, period: Period) -> NaiveDate {
+    let first_month = |months: u32| (date.month0() / months) * months + 1;
+    match period {
+        Period::Week(first_day) => {
+            let back =
+                (date.weekday().num_days_from_monday() + 7 - first_day.num_days_from_monday()) % 7;
+            date - Duration::days(back as i64)
+        }
+        Period::IsoWeek => date - Duration::days(date.weekday().num_days_from_monday() as i64),
+        Period::Month => date.with_day(1).unwrap(),
+        Period::Quarter => NaiveDate::from_ymd_opt(date.year(), first_month(3), 1).unwrap(),
+        Period::HalfYear => NaiveDate::from_ymd_opt(date.year(), first_month(6), 1).unwrap(),
+        Period::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1).unwrap(),
+        _ => date,
+    }
+}
@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, TimeZone};
+
+use super::period::Period;
+use super::start_of::{end_of, start_of};
+
+/// Returns the first instant of the day containing `datetime`, in its own time zone.
+///
+/// This is usually local midnight. Where a daylight saving change skips midnight, the day
+/// starts at the first wall-clock time that exists.
+///
+/// ```
+/// use chrono::{TimeZone, Timelike, Utc};
+/// use chrono_tz::America::Sao_Paulo;
+///
+/// let date = Utc.with_ymd_and_hms(2023, 12, 25, 15, 30, 0).unwrap();
+/// assert_eq!(reddish::start_of_day(&date), Utc.with_ymd_and_hms(2023, 12, 25, 0, 0, 0).unwrap());
+///
+/// // Clocks in São Paulo jumped from 00:00 to 01:00 on 4 November 2018
+/// let dst = Sao_Paulo.with_ymd_and_hms(2018, 11, 4, 12, 0, 0).unwrap();
+/// assert_eq!(reddish::start_of_day(&dst).hour(), 1);
+/// ```
+pub fn start_of_day<Tz: TimeZone>(datetime: &DateTime<Tz>) -> DateTime<Tz> {
+    start_of(datetime, Period::Day)
+}
+
+/// Returns the last instant of the day containing `datetime`, in its own time zone:
+/// one nanosecond before the next day starts. See [`end_of`](crate::end_of).
+///
+/// # Panics
+///
+/// Panics if `datetime` is in the last day chrono supports.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+///
+/// let date = Utc.with_ymd_and_hms(2023, 12, 25, 15, 30, 0).unwrap();
+/// let end = reddish::end_of_day(&date);
+/// let next_day = Utc.with_ymd_and_hms(2023, 12, 26, 0, 0, 0).unwrap();
+/// assert_eq!(end, next_day - chrono::Duration::nanoseconds(1));
+/// ```
+pub fn end_of_day<Tz: TimeZone>(datetime: &DateTime<Tz>) -> DateTime<Tz> {
+    end_of(datetime, Period::Day).expect("date out of range")
+}
@@ -1,8 +1,13 @@
 #![allow(dead_code)]
 
-use chrono::{DateTime, Utc, Duration, Weekday, Datelike};
+use chrono::{DateTime, TimeZone};
 
-/// Returns the start of the week (Monday) for a given date.
+use super::period::Period;
+use super::start_of::start_of;
+
+/// Returns the start of the week (Monday) for a given date, at local midnight in the
+/// date's own time zone. Use [`start_of`](crate::start_of) with
+/// [`Period::Week`](crate::Period::Week) for weeks starting on another day.
 ///
 /// ```
 /// use chrono::{Utc, TimeZone, Weekday, Datelike, Timelike};
@@ -16,19 +21,6 @@ use chrono::{DateTime, Utc, Duration, Weekday, Datelike};
 /// assert_eq!(result.minute(), 0);
 /// assert_eq!(result.second(), 0);
 /// ```
-pub fn start_of_week(datetime: &DateTime<Utc>) -> DateTime<Utc> {
-    let days_since_monday = match datetime.weekday() {
-        Weekday::Mon => 0,
-        Weekday::Tue => 1,
-        Weekday::Wed => 2,
-        Weekday::Thu => 3,
-        Weekday::Fri => 4,
-        Weekday::Sat => 5,
-        Weekday::Sun => 6,
-    };
-
-    let start_of_day = datetime.date_naive().and_hms_opt(0, 0, 0).unwrap();
-    let start_of_week = start_of_day - Duration::days(days_since_monday);
-
-    DateTime::from_naive_utc_and_offset(start_of_week, Utc)
+pub fn start_of_week<Tz: TimeZone>(datetime: &DateTime<Tz>) -> DateTime<Tz> {
+    start_of(datetime, Period::IsoWeek)
 }
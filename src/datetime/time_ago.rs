@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use chrono::{DateTime, Utc, Duration};
+use chrono::{DateTime, Duration, TimeZone, Utc};
 
 /// Formats a datetime as a relative time string (e.g., "2 hours ago").
 ///
@@ -19,9 +19,9 @@ use chrono::{DateTime, Utc, Duration};
 /// let result = reddish::time_ago(&thirty_seconds_ago);
 /// assert_eq!(result, "just now");
 /// ```
-pub fn time_ago(datetime: &DateTime<Utc>) -> String {
+pub fn time_ago<Tz: TimeZone>(datetime: &DateTime<Tz>) -> String {
     let now = Utc::now();
-    let duration = now.signed_duration_since(*datetime);
+    let duration = now.signed_duration_since(datetime);
 
     if duration < Duration::zero() {
         return "in the future".to_string();
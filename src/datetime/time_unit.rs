@@ -0,0 +1,18 @@
+#![allow(dead_code)]
+
+/// A unit of time for [`add`](crate::add) and [`diff_in`](crate::diff_in).
+///
+/// Seconds, minutes and hours are exact durations. Days and longer units follow the
+/// calendar of the datetime's time zone, so a day across a daylight saving change lasts
+/// 23 or 25 hours and a month lasts 28 to 31 days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
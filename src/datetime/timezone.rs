@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, TimeZone};
+use chrono_tz::Tz;
+
+/// Looks up an IANA time zone such as `"America/Sao_Paulo"` or `"Europe/London"` in the
+/// embedded tz database. Returns `None` for unknown names.
+///
+/// ```
+/// let zone = reddish::parse_timezone("America/Sao_Paulo").unwrap();
+/// assert_eq!(zone.name(), "America/Sao_Paulo");
+/// assert!(reddish::parse_timezone("Mars/Olympus_Mons").is_none());
+/// ```
+pub fn parse_timezone(name: &str) -> Option<Tz> {
+    name.parse().ok()
+}
+
+/// Converts a datetime to another time zone. The instant is unchanged; only the wall-clock
+/// time and offset differ.
+///
+/// ```
+/// use chrono::{TimeZone, Timelike, Utc};
+///
+/// let utc = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+/// let tokyo = reddish::to_timezone(&utc, &reddish::parse_timezone("Asia/Tokyo").unwrap());
+/// assert_eq!(tokyo.hour(), 21);
+/// assert_eq!(tokyo, utc);
+/// ```
+pub fn to_timezone<Tz1: TimeZone, Tz2: TimeZone>(
+    datetime: &DateTime<Tz1>,
+    timezone: &Tz2,
+) -> DateTime<Tz2> {
+    datetime.with_timezone(timezone)
+}
@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+
+use super::fake_data::{CITIES, STATES, STREET_NAMES, STREET_SUFFIXES};
+use super::faker::Faker;
+
+impl Faker {
+    /// Returns a random street address, such as `"1234 Maple Street"`.
+    pub fn street_address(&mut self) -> String {
+        let number = self.rng.gen_range(1..10_000);
+        format!("{} {} {}", number, self.pick(STREET_NAMES), self.pick(STREET_SUFFIXES))
+    }
+
+    /// Returns a random city name.
+    pub fn city(&mut self) -> String {
+        self.pick(CITIES).to_string()
+    }
+
+    /// Returns a random two-letter US state code.
+    pub fn state(&mut self) -> String {
+        self.pick(STATES).to_string()
+    }
+
+    /// Returns a random five-digit ZIP code.
+    pub fn zip_code(&mut self) -> String {
+        self.digits(5)
+    }
+
+    /// Returns a random single-line address, such as `"1234 Maple Street, Springfield, IL 62704"`.
+    ///
+    /// ```
+    /// let address = reddish::Faker::from_seed(3).address();
+    /// assert_eq!(address.split(", ").count(), 3);
+    /// ```
+    pub fn address(&mut self) -> String {
+        format!(
+            "{}, {}, {} {}",
+            self.street_address(),
+            self.city(),
+            self.state(),
+            self.zip_code()
+        )
+    }
+}
@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+
+use super::fake_data::{COMPANY_SUFFIXES, LAST_NAMES};
+use super::faker::Faker;
+
+impl Faker {
+    /// Returns a random company name, such as `"Silva Labs"`, `"Chen-Walker"` or
+    /// `"Harris, Patel and Kim"`.
+    ///
+    /// ```
+    /// let company = reddish::Faker::from_seed(5).company();
+    /// assert!(!company.is_empty());
+    /// ```
+    pub fn company(&mut self) -> String {
+        match self.rng.gen_range(0..3) {
+            0 => format!("{} {}", self.pick(LAST_NAMES), self.pick(COMPANY_SUFFIXES)),
+            1 => format!("{}-{}", self.pick(LAST_NAMES), self.pick(LAST_NAMES)),
+            _ => format!(
+                "{}, {} and {}",
+                self.pick(LAST_NAMES),
+                self.pick(LAST_NAMES),
+                self.pick(LAST_NAMES)
+            ),
+        }
+    }
+}
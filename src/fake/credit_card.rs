@@ -0,0 +1,107 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+
+use super::faker::Faker;
+
+/// Card networks supported by [`Faker::credit_card`](crate::Faker::credit_card).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardBrand {
+    /// 16 digits starting with `4`.
+    Visa,
+    /// 16 digits starting with `51` to `55`.
+    Mastercard,
+    /// 15 digits starting with `34` or `37`.
+    Amex,
+}
+
+impl Faker {
+    /// Returns a random card number of the given brand whose check digit passes the Luhn
+    /// algorithm. The numbers are not issued to anyone.
+    ///
+    /// ```
+    /// use reddish::{CardBrand, Faker};
+    ///
+    /// let number = Faker::from_seed(1).credit_card(CardBrand::Visa);
+    /// assert_eq!(number.len(), 16);
+    /// assert!(number.starts_with('4'));
+    /// assert!(reddish::is_luhn_valid(&number));
+    /// ```
+    pub fn credit_card(&mut self, brand: CardBrand) -> String {
+        let (prefix, length) = match brand {
+            CardBrand::Visa => ("4".to_string(), 16),
+            CardBrand::Mastercard => (self.rng.gen_range(51..=55).to_string(), 16),
+            CardBrand::Amex => (if self.rng.gen() { "34" } else { "37" }.to_string(), 15),
+        };
+        let mut number = prefix;
+        let body = self.digits(length - 1 - number.len());
+        number.push_str(&body);
+        let check = luhn_check_digit(&number);
+        number.push(char::from(b'0' + check));
+        number
+    }
+
+    /// Returns a random Luhn-valid card number of a random brand.
+    pub fn credit_card_number(&mut self) -> String {
+        let brand = match self.rng.gen_range(0..3) {
+            0 => CardBrand::Visa,
+            1 => CardBrand::Mastercard,
+            _ => CardBrand::Amex,
+        };
+        self.credit_card(brand)
+    }
+}
+
+/// Checks a number against the Luhn algorithm used by card numbers and many other
+/// identifiers. Spaces and dashes are ignored; any other non-digit fails the check.
+///
+/// ```
+/// assert!(reddish::is_luhn_valid("4539 1488 0343 6467"));
+/// assert!(!reddish::is_luhn_valid("4539 1488 0343 6468"));
+/// assert!(!reddish::is_luhn_valid(""));
+/// ```
+pub fn is_luhn_valid(number: &str) -> bool {
+    let mut digits = Vec::new();
+    for c in number.chars() {
+        match c {
+            ' ' | '-' => continue,
+            _ => match c.to_digit(10) {
+                Some(digit) => digits.push(digit as u8),
+                None => return false,
+            },
+        }
+    }
+    if digits.len() < 2 {
+        return false;
+    }
+    luhn_sum(&digits, false) % 10 == 0
+}
+
+// The digit that makes `partial` followed by it pass the Luhn check
+fn luhn_check_digit(partial: &str) -> u8 {
+    let digits: Vec<u8> = partial.bytes().map(|b| b - b'0').collect();
+    ((10 - luhn_sum(&digits, true) % 10) % 10) as u8
+}
+
+// Sums the digits from the right, doubling every second one. `double_first` says whether
+// the rightmost digit is doubled, which is the case when the check digit is still missing.
+fn luhn_sum(digits: &[u8], double_first: bool) -> u32 {
+    digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &digit)| {
+            let digit = digit as u32;
+            if (i % 2 == 0) == double_first {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
+            }
+        })
+        .sum()
+}
@@ -0,0 +1,42 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+
+use crate::{add, TimeUnit};
+use super::faker::Faker;
+
+impl Faker {
+    /// Returns a random instant between `start` and `end` inclusive, to the second.
+    /// The bounds may be given in either order, and may span the whole range chrono supports.
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap();
+    /// let date = reddish::Faker::from_seed(1).date_between(&start, &end);
+    /// assert!(start <= date && date <= end);
+    /// ```
+    pub fn date_between(&mut self, start: &DateTime<Utc>, end: &DateTime<Utc>) -> DateTime<Utc> {
+        let (start, end) = if start <= end { (start, end) } else { (end, start) };
+        let seconds = end.signed_duration_since(*start).num_seconds();
+        add(start, self.rng.gen_range(0..=seconds), TimeUnit::Second).unwrap_or(*end)
+    }
+
+    /// Returns a random instant within the last `days` days, going back no further than
+    /// the earliest date chrono supports.
+    pub fn past_date(&mut self, days: i64) -> DateTime<Utc> {
+        let now = Utc::now();
+        let start = add(&now, -days.max(0), TimeUnit::Day).unwrap_or(DateTime::<Utc>::MIN_UTC);
+        self.date_between(&start, &now)
+    }
+
+    /// Returns a random instant within the next `days` days, going no further than the
+    /// latest date chrono supports.
+    pub fn future_date(&mut self, days: i64) -> DateTime<Utc> {
+        let now = Utc::now();
+        let end = add(&now, days.max(0), TimeUnit::Day).unwrap_or(DateTime::<Utc>::MAX_UTC);
+        self.date_between(&now, &end)
+    }
+}
@@ -0,0 +1,60 @@
+#![allow(dead_code)]
+
+pub(super) const FIRST_NAMES: &[&str] = &[
+    "Aaliyah", "Adam", "Alice", "Amara", "Andre", "Ava", "Benjamin", "Carlos", "Charlotte", "Chen",
+    "Chloe", "Daniel", "David", "Diego", "Elena", "Elijah", "Emma", "Fatima", "Gabriel", "Grace",
+    "Hannah", "Hiro", "Isaac", "Isabella", "Jack", "James", "Julia", "Kai", "Layla", "Leo", "Liam",
+    "Lucas", "Maria", "Mateo", "Maya", "Mia", "Noah", "Nora", "Olivia", "Omar", "Priya", "Rafael",
+    "Ravi", "Rosa", "Samuel", "Sofia", "Thomas", "Valentina", "William", "Yuki", "Zara", "Zoe",
+];
+
+pub(super) const LAST_NAMES: &[&str] = &[
+    "Adams", "Almeida", "Anderson", "Baker", "Brown", "Campbell", "Carter", "Chen", "Clark",
+    "Costa", "Davis", "Diaz", "Evans", "Ferreira", "Garcia", "Gonzalez", "Green", "Hall", "Harris",
+    "Hernandez", "Hill", "Ito", "Jackson", "Johnson", "Kim", "King", "Lee", "Lewis", "Lopez",
+    "Martin", "Martinez", "Miller", "Moore", "Nguyen", "Oliveira", "Patel", "Perez", "Ramirez",
+    "Roberts", "Robinson", "Santos", "Scott", "Silva", "Singh", "Smith", "Taylor", "Thomas",
+    "Thompson", "Walker", "White", "Williams", "Wilson", "Wright", "Young",
+];
+
+pub(super) const STREET_NAMES: &[&str] = &[
+    "Ash", "Birch", "Cedar", "Cherry", "Chestnut", "Church", "Elm", "Forest", "Highland", "Hill",
+    "Jackson", "Lake", "Lincoln", "Madison", "Main", "Maple", "Meadow", "Mill", "Oak", "Park",
+    "Pine", "River", "Spring", "Sunset", "Valley", "Walnut", "Washington", "Willow",
+];
+
+pub(super) const STREET_SUFFIXES: &[&str] = &[
+    "Avenue", "Boulevard", "Court", "Drive", "Lane", "Place", "Road", "Street", "Terrace", "Way",
+];
+
+pub(super) const CITIES: &[&str] = &[
+    "Arlington", "Ashland", "Bristol", "Burlington", "Clayton", "Clinton", "Dayton", "Dover",
+    "Fairview", "Franklin", "Georgetown", "Greenville", "Hudson", "Kingston", "Lexington",
+    "Madison", "Marion", "Milford", "Newport", "Oakland", "Oxford", "Riverside", "Salem",
+    "Springfield", "Troy", "Winchester",
+];
+
+pub(super) const STATES: &[&str] = &[
+    "AL", "AK", "AZ", "AR", "CA", "CO", "CT", "DE", "FL", "GA", "HI", "ID", "IL", "IN", "IA", "KS",
+    "KY", "LA", "ME", "MD", "MA", "MI", "MN", "MS", "MO", "MT", "NE", "NV", "NH", "NJ", "NM", "NY",
+    "NC", "ND", "OH", "OK", "OR", "PA", "RI", "SC", "SD", "TN", "TX", "UT", "VT", "VA", "WA", "WV",
+    "WI", "WY",
+];
+
+// Domains reserved for documentation by RFC 2606, so fixtures never reach a real mailbox
+pub(super) const EMAIL_DOMAINS: &[&str] = &["example.com", "example.net", "example.org"];
+
+pub(super) const COMPANY_SUFFIXES: &[&str] = &[
+    "Inc", "LLC", "Group", "Ltd", "Labs", "Systems", "Partners", "Holdings", "Industries",
+    "and Sons",
+];
+
+pub(super) const LOREM_WORDS: &[&str] = &[
+    "lorem", "ipsum", "dolor", "sit", "amet", "consectetur", "adipiscing", "elit", "sed", "do",
+    "eiusmod", "tempor", "incididunt", "ut", "labore", "et", "dolore", "magna", "aliqua", "enim",
+    "ad", "minim", "veniam", "quis", "nostrud", "exercitation", "ullamco", "laboris", "nisi",
+    "aliquip", "ex", "ea", "commodo", "consequat", "duis", "aute", "irure", "in", "reprehenderit",
+    "voluptate", "velit", "esse", "cillum", "eu", "fugiat", "nulla", "pariatur", "excepteur",
+    "sint", "occaecat", "cupidatat", "non", "proident", "sunt", "culpa", "qui", "officia",
+    "deserunt", "mollit", "anim", "id", "est", "laborum",
+];
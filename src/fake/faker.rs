@@ -0,0 +1,50 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+
+use crate::Random;
+
+/// A generator of realistic fake data for tests and fixtures.
+///
+/// Every value is drawn from an internal [`Random`](crate::Random), so two `Faker`s created
+/// from the same seed produce exactly the same fixtures.
+///
+/// ```
+/// use reddish::Faker;
+///
+/// let mut a = Faker::from_seed(7);
+/// let mut b = Faker::from_seed(7);
+/// assert_eq!(a.name(), b.name());
+/// assert_eq!(a.email(), b.email());
+/// assert_eq!(a.address(), b.address());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Faker {
+    pub(super) rng: Random,
+}
+
+impl Faker {
+    /// Creates a faker seeded from the operating system's entropy source.
+    pub fn new() -> Self {
+        Faker { rng: Random::new() }
+    }
+
+    /// Creates a faker whose output is fully determined by `seed`.
+    pub fn from_seed(seed: u64) -> Self {
+        Faker {
+            rng: Random::from_seed(seed),
+        }
+    }
+
+    // Picks one entry of a non-empty data list
+    pub(super) fn pick(&mut self, list: &[&'static str]) -> &'static str {
+        list[self.rng.gen_range(0..list.len())]
+    }
+
+    // A string of `count` random decimal digits
+    pub(super) fn digits(&mut self, count: usize) -> String {
+        (0..count)
+            .map(|_| char::from(b'0' + self.rng.gen_range(0..10u8)))
+            .collect()
+    }
+}
@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use rand::Rng;
+
+use super::faker::Faker;
+
+impl Faker {
+    /// Returns a random public-looking IPv4 address: never in `0.0.0.0/8`, loopback,
+    /// multicast or the reserved range, nor in the private (`10/8`, `172.16/12`,
+    /// `192.168/16`), shared (`100.64/10`) or link-local (`169.254/16`) ranges.
+    ///
+    /// ```
+    /// let ip: std::net::Ipv4Addr = reddish::Faker::from_seed(1).ipv4().parse().unwrap();
+    /// assert!(!ip.is_loopback() && !ip.is_multicast() && !ip.is_private());
+    /// ```
+    pub fn ipv4(&mut self) -> String {
+        loop {
+            let first = self.rng.gen_range(1..224u8);
+            let ip = Ipv4Addr::new(first, self.rng.gen(), self.rng.gen(), self.rng.gen());
+            if !is_non_public(ip) {
+                return ip.to_string();
+            }
+        }
+    }
+
+    /// Returns a random global unicast IPv6 address (`2000::/3`).
+    ///
+    /// ```
+    /// let ip: std::net::Ipv6Addr = reddish::Faker::from_seed(1).ipv6().parse().unwrap();
+    /// assert_eq!(ip.segments()[0] & 0xe000, 0x2000);
+    /// ```
+    pub fn ipv6(&mut self) -> String {
+        let mut segments: [u16; 8] = self.rng.gen();
+        segments[0] = 0x2000 | (segments[0] & 0x1fff);
+        Ipv6Addr::from(segments).to_string()
+    }
+}
+
+// Loopback, private, shared (carrier-grade NAT) and link-local addresses
+fn is_non_public(ip: Ipv4Addr) -> bool {
+    let [a, b, _, _] = ip.octets();
+    let shared = a == 100 && (64..128).contains(&b);
+    ip.is_loopback() || ip.is_private() || ip.is_link_local() || shared
+}
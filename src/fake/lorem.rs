@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+
+use super::fake_data::LOREM_WORDS;
+use super::faker::Faker;
+use crate::capitalize;
+
+impl Faker {
+    /// Returns `count` lorem ipsum words separated by spaces.
+    ///
+    /// ```
+    /// let words = reddish::Faker::from_seed(1).lorem_words(4);
+    /// assert_eq!(words.split(' ').count(), 4);
+    /// ```
+    pub fn lorem_words(&mut self, count: usize) -> String {
+        (0..count).map(|_| self.pick(LOREM_WORDS)).collect::<Vec<_>>().join(" ")
+    }
+
+    /// Returns a capitalized lorem ipsum sentence of 6 to 12 words ending with a period.
+    ///
+    /// ```
+    /// let sentence = reddish::Faker::from_seed(1).lorem_sentence();
+    /// assert!(sentence.ends_with('.'));
+    /// assert!(sentence.chars().next().unwrap().is_uppercase());
+    /// ```
+    pub fn lorem_sentence(&mut self) -> String {
+        let count = self.rng.gen_range(6..=12);
+        format!("{}.", capitalize(&self.lorem_words(count)))
+    }
+
+    /// Returns a lorem ipsum paragraph of 3 to 6 sentences.
+    pub fn lorem_paragraph(&mut self) -> String {
+        let count = self.rng.gen_range(3..=6);
+        (0..count).map(|_| self.lorem_sentence()).collect::<Vec<_>>().join(" ")
+    }
+
+    /// Returns `count` lorem ipsum paragraphs.
+    ///
+    /// ```
+    /// let paragraphs = reddish::Faker::from_seed(1).lorem_paragraphs(3);
+    /// assert_eq!(paragraphs.len(), 3);
+    /// ```
+    pub fn lorem_paragraphs(&mut self, count: usize) -> Vec<String> {
+        (0..count).map(|_| self.lorem_paragraph()).collect()
+    }
+}
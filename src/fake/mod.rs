@@ -0,0 +1,15 @@
+mod fake_data;
+
+mod faker;
+pub use faker::*;
+
+mod credit_card;
+pub use credit_card::*;
+
+// The remaining modules only add methods to `Faker`
+mod address;
+mod company;
+mod date;
+mod internet;
+mod lorem;
+mod person;
@@ -0,0 +1,61 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+
+use super::fake_data::{EMAIL_DOMAINS, FIRST_NAMES, LAST_NAMES};
+use super::faker::Faker;
+
+impl Faker {
+    /// Returns a random first name.
+    ///
+    /// ```
+    /// let name = reddish::Faker::from_seed(1).first_name();
+    /// assert!(name.chars().next().unwrap().is_uppercase());
+    /// ```
+    pub fn first_name(&mut self) -> String {
+        self.pick(FIRST_NAMES).to_string()
+    }
+
+    /// Returns a random last name.
+    pub fn last_name(&mut self) -> String {
+        self.pick(LAST_NAMES).to_string()
+    }
+
+    /// Returns a random full name, such as `"Maria Silva"`.
+    ///
+    /// ```
+    /// let name = reddish::Faker::from_seed(1).name();
+    /// assert_eq!(name.split(' ').count(), 2);
+    /// ```
+    pub fn name(&mut self) -> String {
+        format!("{} {}", self.first_name(), self.last_name())
+    }
+
+    /// Returns a random email address on a domain reserved for documentation.
+    ///
+    /// ```
+    /// let email = reddish::Faker::from_seed(1).email();
+    /// assert!(email.contains('@'));
+    /// assert!(email.ends_with(".com") || email.ends_with(".net") || email.ends_with(".org"));
+    /// ```
+    pub fn email(&mut self) -> String {
+        let first = self.first_name().to_lowercase();
+        let last = self.last_name().to_lowercase();
+        let number = self.rng.gen_range(1..100);
+        let domain = self.pick(EMAIL_DOMAINS);
+        format!("{}.{}{}@{}", first, last, number, domain)
+    }
+
+    /// Returns a random US phone number in the fictional `555-01XX` range, such as
+    /// `"+1 (312) 555-0147"`.
+    ///
+    /// ```
+    /// let phone = reddish::Faker::from_seed(1).phone();
+    /// assert!(phone.starts_with("+1 ("));
+    /// assert_eq!(phone.len(), 17);
+    /// ```
+    pub fn phone(&mut self) -> String {
+        let area = self.rng.gen_range(201..990);
+        format!("+1 ({}) 555-01{}", area, self.digits(2))
+    }
+}
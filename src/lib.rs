@@ -50,6 +50,7 @@
 //! - **`crypto`** - Cryptographic functions (hash, encode/decode, etc.)
 //! - **`random`** - Random number generation and sampling
 //! - **`datetime`** - Date/time manipulation and formatting
+//! - **`fake`** - Seedable fake data for tests and fixtures
 //!
 //! ## 🔧 Feature Flags
 //!
@@ -142,3 +143,8 @@ pub use random::*;
 mod datetime;
 #[cfg(feature = "datetime")]
 pub use datetime::*;
+
+#[cfg(feature = "fake")]
+mod fake;
+#[cfg(feature = "fake")]
+pub use fake::*;
@@ -0,0 +1,71 @@
+#![allow(dead_code)]
+
+/// Characters that are easily confused with one another in many fonts.
+pub const AMBIGUOUS_CHARS: &str = "0O1lI";
+
+/// The set of characters a random string is drawn from.
+///
+/// ```
+/// use reddish::Charset;
+///
+/// assert_eq!(Charset::Hex.chars().len(), 16);
+/// assert_eq!(Charset::Custom("abcabc".to_string()).chars(), vec!['a', 'b', 'c']);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Charset {
+    /// `A-Z`, `a-z` and `0-9`.
+    #[default]
+    Alphanumeric,
+    /// `A-Z` and `a-z`.
+    Alphabetic,
+    /// `a-z`.
+    Lowercase,
+    /// `A-Z`.
+    Uppercase,
+    /// `0-9`.
+    Digits,
+    /// `0-9a-f`.
+    Hex,
+    /// `0-9A-F`.
+    HexUpper,
+    /// The RFC 4648 Base32 alphabet, `A-Z2-7`.
+    Base32,
+    /// `A-Z`, `a-z`, `0-9`, `-` and `_`, safe in URLs and file names.
+    UrlSafe,
+    /// Alphanumerics plus the printable ASCII symbols, for passwords.
+    AlphanumericSymbols,
+    /// Any alphabet; repeated characters are only counted once.
+    Custom(String),
+}
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+impl Charset {
+    /// Returns the distinct characters in this set, in order.
+    pub fn chars(&self) -> Vec<char> {
+        let parts: Vec<&str> = match self {
+            Charset::Alphanumeric => vec![UPPERCASE, LOWERCASE, DIGITS],
+            Charset::Alphabetic => vec![UPPERCASE, LOWERCASE],
+            Charset::Lowercase => vec![LOWERCASE],
+            Charset::Uppercase => vec![UPPERCASE],
+            Charset::Digits => vec![DIGITS],
+            Charset::Hex => vec!["0123456789abcdef"],
+            Charset::HexUpper => vec!["0123456789ABCDEF"],
+            Charset::Base32 => vec!["ABCDEFGHIJKLMNOPQRSTUVWXYZ234567"],
+            Charset::UrlSafe => vec![UPPERCASE, LOWERCASE, DIGITS, "-_"],
+            Charset::AlphanumericSymbols => vec![UPPERCASE, LOWERCASE, DIGITS, SYMBOLS],
+            Charset::Custom(alphabet) => vec![alphabet.as_str()],
+        };
+
+        let mut chars = Vec::new();
+        for c in parts.into_iter().flat_map(str::chars) {
+            if !chars.contains(&c) {
+                chars.push(c);
+            }
+        }
+        chars
+    }
+}
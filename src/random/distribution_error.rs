@@ -0,0 +1,40 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by [`random_int_in`](crate::random_int_in) and the distribution
+/// samplers such as [`random_normal`](crate::random_normal).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DistributionError {
+    /// The range contains no values, e.g. `5..5` or `10..=1`.
+    EmptyRange,
+    /// A distribution parameter is out of range, NaN or infinite.
+    InvalidParameter { name: &'static str, value: f64 },
+}
+
+impl fmt::Display for DistributionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DistributionError::EmptyRange => write!(f, "range is empty"),
+            DistributionError::InvalidParameter { name, value } => {
+                write!(f, "invalid distribution parameter {} = {}", name, value)
+            }
+        }
+    }
+}
+
+impl Error for DistributionError {}
+
+// Returns the value if `valid` holds for it and it is finite
+pub(crate) fn check_parameter(
+    name: &'static str,
+    value: f64,
+    valid: impl Fn(f64) -> bool,
+) -> Result<f64, DistributionError> {
+    if value.is_finite() && valid(value) {
+        Ok(value)
+    } else {
+        Err(DistributionError::InvalidParameter { name, value })
+    }
+}
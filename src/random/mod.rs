@@ -21,3 +21,98 @@ pub use uuid_gen::*;
 
 mod random_bool;
 pub use random_bool::*;
+
+mod weighted_error;
+pub use weighted_error::*;
+
+mod weighted_choice;
+pub use weighted_choice::*;
+
+mod weighted_sample;
+pub use weighted_sample::*;
+
+mod weighted_table;
+pub use weighted_table::*;
+
+mod sample_with_replacement;
+pub use sample_with_replacement::*;
+
+mod sample_iter;
+pub use sample_iter::*;
+
+mod random_choice_iter;
+pub use random_choice_iter::*;
+
+mod weighted_sample_iter;
+pub use weighted_sample_iter::*;
+
+mod distribution_error;
+pub use distribution_error::*;
+
+mod random_int_in;
+pub use random_int_in::*;
+
+mod random_normal;
+pub use random_normal::*;
+
+mod random_exponential;
+pub use random_exponential::*;
+
+mod random_poisson;
+pub use random_poisson::*;
+
+mod random_binomial;
+pub use random_binomial::*;
+
+mod random_geometric;
+pub use random_geometric::*;
+
+mod random_zipf;
+pub use random_zipf::*;
+
+mod random_pareto;
+pub use random_pareto::*;
+
+mod charset;
+pub use charset::*;
+
+mod random_string_error;
+pub use random_string_error::*;
+
+mod random_string_options;
+pub use random_string_options::*;
+
+mod random_string_from;
+pub use random_string_from::*;
+
+mod random_token;
+pub use random_token::*;
+
+mod uuid_v7;
+pub use uuid_v7::*;
+
+mod uuid_v5;
+pub use uuid_v5::*;
+
+mod ulid;
+pub use ulid::*;
+
+mod nanoid;
+pub use nanoid::*;
+
+mod snowflake_error;
+pub use snowflake_error::*;
+
+mod snowflake;
+pub use snowflake::*;
+
+mod wordlist;
+
+mod random_passphrase;
+pub use random_passphrase::*;
+
+mod random_name;
+pub use random_name::*;
+
+mod rng;
+pub use rng::*;
@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+use rand::rngs::OsRng;
+use rand::Rng;
+
+use super::charset::Charset;
+use super::random_string_error::RandomStringError;
+use super::random_string_from::random_string_from_with_rng;
+use super::random_string_options::RandomStringOptions;
+
+/// Generates a NanoID: 21 URL-safe characters from the operating system's random number
+/// generator, with about as much entropy as a UUID v4.
+///
+/// ```
+/// let id = reddish::nanoid();
+/// assert_eq!(id.len(), 21);
+/// assert!(id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+/// ```
+pub fn nanoid() -> String {
+    random_string_from_with_rng(21, &RandomStringOptions::new(Charset::UrlSafe), &mut OsRng)
+        .expect("the URL-safe alphabet is not empty")
+}
+
+/// Generates a NanoID of `size` characters from a custom alphabet, using the operating
+/// system's random number generator.
+///
+/// ```
+/// let id = reddish::nanoid_custom(10, "0123456789abcdef").unwrap();
+/// assert_eq!(id.len(), 10);
+/// assert!(id.chars().all(|c| c.is_ascii_hexdigit()));
+///
+/// assert!(reddish::nanoid_custom(10, "").is_err());
+/// ```
+pub fn nanoid_custom(size: usize, alphabet: &str) -> Result<String, RandomStringError> {
+    nanoid_custom_with_rng(size, alphabet, &mut OsRng)
+}
+
+/// Like [`nanoid_custom`](crate::nanoid_custom), drawing from the given random number generator.
+pub fn nanoid_custom_with_rng<R: Rng + ?Sized>(
+    size: usize,
+    alphabet: &str,
+    rng: &mut R,
+) -> Result<String, RandomStringError> {
+    let options = RandomStringOptions::new(Charset::Custom(alphabet.to_string()));
+    random_string_from_with_rng(size, &options, rng)
+}
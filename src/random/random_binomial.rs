@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+use rand_distr::{Binomial, Distribution};
+
+use super::distribution_error::{check_parameter, DistributionError};
+
+/// Samples the binomial distribution: the number of successes in `n` independent trials
+/// that each succeed with probability `p`.
+///
+/// ```
+/// let heads = reddish::random_binomial(10, 0.5).unwrap();
+/// assert!(heads <= 10);
+/// assert_eq!(reddish::random_binomial(10, 1.0), Ok(10));
+/// assert!(reddish::random_binomial(10, 1.5).is_err());
+/// ```
+pub fn random_binomial(n: u64, p: f64) -> Result<u64, DistributionError> {
+    random_binomial_with_rng(n, p, &mut rand::thread_rng())
+}
+
+/// Like [`random_binomial`](crate::random_binomial), drawing from the given random number
+/// generator.
+pub fn random_binomial_with_rng<R: Rng + ?Sized>(
+    n: u64,
+    p: f64,
+    rng: &mut R,
+) -> Result<u64, DistributionError> {
+    let p = check_parameter("p", p, |p| (0.0..=1.0).contains(&p))?;
+    let binomial = Binomial::new(n, p)
+        .map_err(|_| DistributionError::InvalidParameter { name: "p", value: p })?;
+    Ok(binomial.sample(rng))
+}
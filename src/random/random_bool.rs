@@ -9,7 +9,7 @@ use rand::Rng;
 /// assert!(result == true || result == false);
 /// ```
 pub fn random_bool() -> bool {
-    rand::thread_rng().gen_bool(0.5)
+    random_bool_with_rng(&mut rand::thread_rng())
 }
 
 /// Generates a random boolean value with a specified probability of being true.
@@ -25,8 +25,22 @@ pub fn random_bool() -> bool {
 /// assert_eq!(result, true);
 /// ```
 pub fn random_bool_with_probability(probability: f64) -> bool {
-    if probability < 0.0 || probability > 1.0 {
+    random_bool_with_probability_with_rng(probability, &mut rand::thread_rng())
+}
+
+/// Like [`random_bool`](crate::random_bool), drawing from the given random number generator.
+pub fn random_bool_with_rng<R: Rng + ?Sized>(rng: &mut R) -> bool {
+    rng.gen_bool(0.5)
+}
+
+/// Like [`random_bool_with_probability`](crate::random_bool_with_probability), drawing from
+/// the given random number generator.
+pub fn random_bool_with_probability_with_rng<R: Rng + ?Sized>(
+    probability: f64,
+    rng: &mut R,
+) -> bool {
+    if !(0.0..=1.0).contains(&probability) {
         panic!("probability must be between 0.0 and 1.0");
     }
-    rand::thread_rng().gen_bool(probability)
+    rng.gen_bool(probability)
 }
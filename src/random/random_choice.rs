@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 use rand::seq::SliceRandom;
+use rand::Rng;
 
 /// Selects a random element from a slice. Returns None if the slice is empty.
 ///
@@ -17,5 +18,13 @@ use rand::seq::SliceRandom;
 /// assert!(result.is_none());
 /// ```
 pub fn random_choice<T>(slice: &[T]) -> Option<&T> {
-    slice.choose(&mut rand::thread_rng())
+    random_choice_with_rng(slice, &mut rand::thread_rng())
+}
+
+/// Like [`random_choice`](crate::random_choice), drawing from the given random number generator.
+pub fn random_choice_with_rng<'a, T, R: Rng + ?Sized>(
+    slice: &'a [T],
+    rng: &mut R,
+) -> Option<&'a T> {
+    slice.choose(rng)
 }
@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+
+use super::sample_iter::sample_iter_with_rng;
+
+/// Picks one item uniformly at random from an iterator of unknown length, such as the
+/// lines of a file too large to load. Returns `None` if the iterator is empty.
+///
+/// ```
+/// let log = "GET /\nPOST /login\nGET /about\n";
+/// let line = reddish::random_choice_iter(log.lines()).unwrap();
+/// assert!(log.lines().any(|l| l == line));
+///
+/// assert_eq!(reddish::random_choice_iter(std::iter::empty::<u8>()), None);
+/// ```
+pub fn random_choice_iter<I: IntoIterator>(iter: I) -> Option<I::Item> {
+    random_choice_iter_with_rng(iter, &mut rand::thread_rng())
+}
+
+/// Like [`random_choice_iter`](crate::random_choice_iter), drawing from the given random number
+/// generator.
+pub fn random_choice_iter_with_rng<I: IntoIterator, R: Rng + ?Sized>(
+    iter: I,
+    rng: &mut R,
+) -> Option<I::Item> {
+    sample_iter_with_rng(iter, 1, rng).pop()
+}
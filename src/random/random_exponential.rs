@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+use rand_distr::{Distribution, Exp};
+
+use super::distribution_error::{check_parameter, DistributionError};
+
+/// Samples the exponential distribution with rate `lambda` (mean `1 / lambda`), e.g. the
+/// time between events in a Poisson process such as request arrivals.
+///
+/// ```
+/// let wait = reddish::random_exponential(2.0).unwrap();
+/// assert!(wait >= 0.0);
+/// assert!(reddish::random_exponential(0.0).is_err());
+/// ```
+pub fn random_exponential(lambda: f64) -> Result<f64, DistributionError> {
+    random_exponential_with_rng(lambda, &mut rand::thread_rng())
+}
+
+/// Like [`random_exponential`](crate::random_exponential), drawing from the given random number
+/// generator.
+pub fn random_exponential_with_rng<R: Rng + ?Sized>(
+    lambda: f64,
+    rng: &mut R,
+) -> Result<f64, DistributionError> {
+    let lambda = check_parameter("lambda", lambda, |l| l > 0.0)?;
+    let exp = Exp::new(lambda)
+        .map_err(|_| DistributionError::InvalidParameter { name: "lambda", value: lambda })?;
+    Ok(exp.sample(rng))
+}
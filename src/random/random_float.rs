@@ -14,8 +14,13 @@ use rand::Rng;
 /// assert!(result >= -10.0 && result < 10.0);
 /// ```
 pub fn random_float(min: f64, max: f64) -> f64 {
+    random_float_with_rng(min, max, &mut rand::thread_rng())
+}
+
+/// Like [`random_float`](crate::random_float), drawing from the given random number generator.
+pub fn random_float_with_rng<R: Rng + ?Sized>(min: f64, max: f64, rng: &mut R) -> f64 {
     if min >= max {
         panic!("min must be less than max");
     }
-    rand::thread_rng().gen_range(min..max)
+    rng.gen_range(min..max)
 }
@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+use rand_distr::{Distribution, Geometric};
+
+use super::distribution_error::{check_parameter, DistributionError};
+
+/// Samples the geometric distribution: the number of failures before the first success,
+/// when each trial succeeds with probability `p`. Useful for retry counts.
+///
+/// ```
+/// let retries = reddish::random_geometric(0.25).unwrap();
+/// assert_eq!(reddish::random_geometric(1.0), Ok(0));
+/// assert!(reddish::random_geometric(0.0).is_err());
+/// ```
+pub fn random_geometric(p: f64) -> Result<u64, DistributionError> {
+    random_geometric_with_rng(p, &mut rand::thread_rng())
+}
+
+/// Like [`random_geometric`](crate::random_geometric), drawing from the given random number
+/// generator.
+pub fn random_geometric_with_rng<R: Rng + ?Sized>(
+    p: f64,
+    rng: &mut R,
+) -> Result<u64, DistributionError> {
+    let p = check_parameter("p", p, |p| p > 0.0 && p <= 1.0)?;
+    let geometric = Geometric::new(p)
+        .map_err(|_| DistributionError::InvalidParameter { name: "p", value: p })?;
+    Ok(geometric.sample(rng))
+}
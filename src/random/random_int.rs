@@ -3,6 +3,8 @@
 use rand::Rng;
 
 /// Generates a random integer between min and max (inclusive).
+/// Panics if min is greater than max; [`random_int_in`](crate::random_int_in) returns an error
+/// instead.
 ///
 /// ```
 /// let result = reddish::random_int(1, 10);
@@ -14,8 +16,23 @@ use rand::Rng;
 /// assert!(result >= -5 && result <= 5);
 /// ```
 pub fn random_int(min: i32, max: i32) -> i32 {
+    random_int_with_rng(min, max, &mut rand::thread_rng())
+}
+
+/// Like [`random_int`](crate::random_int), drawing from the given random number generator.
+///
+/// ```
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha8Rng;
+///
+/// let mut a = ChaCha8Rng::seed_from_u64(7);
+/// let mut b = ChaCha8Rng::seed_from_u64(7);
+/// let first = reddish::random_int_with_rng(1, 100, &mut a);
+/// assert_eq!(first, reddish::random_int_with_rng(1, 100, &mut b));
+/// ```
+pub fn random_int_with_rng<R: Rng + ?Sized>(min: i32, max: i32, rng: &mut R) -> i32 {
     if min > max {
         panic!("min cannot be greater than max");
     }
-    rand::thread_rng().gen_range(min..=max)
+    rng.gen_range(min..=max)
 }
@@ -0,0 +1,80 @@
+#![allow(dead_code)]
+
+use std::ops::{Bound, RangeBounds};
+
+use rand::distributions::uniform::SampleUniform;
+use rand::Rng;
+
+use super::distribution_error::DistributionError;
+
+/// Integer types accepted by [`random_int_in`](crate::random_int_in).
+pub trait RandomInt: SampleUniform + Copy + PartialOrd {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_increment(self) -> Option<Self>;
+    fn checked_decrement(self) -> Option<Self>;
+}
+
+macro_rules! impl_random_int {
+    ($($t:ty),*) => {
+        $(
+            impl RandomInt for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn checked_increment(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn checked_decrement(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_random_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Generates a random integer of any primitive integer type within any range:
+/// `a..b`, `a..=b`, `a..`, `..b`, `..=b` or `..`. Unlike [`random_int`](crate::random_int),
+/// an empty range is reported as an error instead of panicking.
+///
+/// ```
+/// let n = reddish::random_int_in(1..=6).unwrap();
+/// assert!((1..=6).contains(&n));
+///
+/// let byte: u8 = reddish::random_int_in(..).unwrap();
+/// let index = reddish::random_int_in(0..10usize).unwrap();
+/// assert!(index < 10);
+///
+/// assert!(reddish::random_int_in(5..5).is_err());
+/// assert!(reddish::random_int_in(10..=1).is_err());
+/// ```
+pub fn random_int_in<T: RandomInt, B: RangeBounds<T>>(range: B) -> Result<T, DistributionError> {
+    random_int_in_with_rng(range, &mut rand::thread_rng())
+}
+
+/// Like [`random_int_in`](crate::random_int_in), drawing from the given random number generator.
+pub fn random_int_in_with_rng<T: RandomInt, B: RangeBounds<T>, R: Rng + ?Sized>(
+    range: B,
+    rng: &mut R,
+) -> Result<T, DistributionError> {
+    let low = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_increment().ok_or(DistributionError::EmptyRange)?,
+        Bound::Unbounded => T::MIN,
+    };
+    let high = match range.end_bound() {
+        Bound::Included(&end) => end,
+        Bound::Excluded(&end) => end.checked_decrement().ok_or(DistributionError::EmptyRange)?,
+        Bound::Unbounded => T::MAX,
+    };
+
+    if low > high {
+        return Err(DistributionError::EmptyRange);
+    }
+
+    Ok(rng.gen_range(low..=high))
+}
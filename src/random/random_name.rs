@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+
+use super::random_choice::random_choice_with_rng;
+use crate::kebab_case;
+
+const ADJECTIVES: &[&str] = &[
+    "admiring", "agile", "amazing", "awesome", "blissful", "bold", "brave", "bright", "busy",
+    "calm", "charming", "clever", "cool", "cranky", "crazy", "daring", "dazzling", "determined",
+    "eager", "ecstatic", "elastic", "elated", "elegant", "eloquent", "epic", "fervent", "festive",
+    "focused", "friendly", "gallant", "gentle", "gifted", "happy", "hopeful", "humble", "jolly",
+    "jovial", "keen", "kind", "laughing", "lucid", "lucky", "magical", "modest", "mystifying",
+    "nervous", "nice", "nifty", "nostalgic", "objective", "optimistic", "peaceful", "pensive",
+    "practical", "priceless", "quirky", "quizzical", "relaxed", "reverent", "serene", "sharp",
+    "silly", "sleepy", "stoic", "sweet", "tender", "thirsty", "trusting", "upbeat", "vibrant",
+    "vigilant", "wizardly", "wonderful", "youthful", "zealous", "zen",
+];
+
+const SURNAMES: &[&str] = &[
+    "agnesi", "archimedes", "babbage", "bardeen", "bell", "bohr", "boole", "brahmagupta", "cerf",
+    "curie", "darwin", "dijkstra", "einstein", "euclid", "euler", "faraday", "fermat", "fermi",
+    "feynman", "franklin", "galileo", "gauss", "goldberg", "goodall", "hamilton", "hawking",
+    "heisenberg", "hertz", "hodgkin", "hopper", "hypatia", "johnson", "kepler", "knuth", "lamarr",
+    "lamport", "leakey", "liskov", "lovelace", "maxwell", "meitner", "mendel", "mirzakhani",
+    "morse", "newton", "nobel", "noether", "pascal", "pasteur", "perlman", "planck", "poincare",
+    "ramanujan", "ritchie", "rubin", "sagan", "shannon", "shaw", "sinoussi", "snyder", "tesla",
+    "thompson", "torvalds", "turing", "vaughan", "volta", "wilson", "wing", "wozniak", "wright",
+    "yalow", "yonath",
+];
+
+/// Generates a human-friendly name such as `"brave-turing-42"`, made of an adjective, the
+/// surname of a notable scientist and a two-digit number, for naming temporary resources.
+///
+/// ```
+/// let name = reddish::random_name();
+/// let parts: Vec<&str> = name.split('-').collect();
+/// assert_eq!(parts.len(), 3);
+/// assert_eq!(parts[2].len(), 2);
+/// assert_eq!(name, reddish::kebab_case(&name));
+/// ```
+pub fn random_name() -> String {
+    random_name_with_rng(&mut rand::thread_rng())
+}
+
+/// Like [`random_name`](crate::random_name), drawing from the given random number generator.
+pub fn random_name_with_rng<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let adjective =
+        random_choice_with_rng(ADJECTIVES, rng).expect("the adjective list is not empty");
+    let surname = random_choice_with_rng(SURNAMES, rng).expect("the surname list is not empty");
+    let number = rng.gen_range(10..100);
+    kebab_case(&format!("{} {} {}", adjective, surname, number))
+}
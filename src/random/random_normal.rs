@@ -0,0 +1,61 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+use rand_distr::{Distribution, LogNormal, Normal};
+
+use super::distribution_error::{check_parameter, DistributionError};
+
+/// Samples the normal (Gaussian) distribution with the given mean and standard deviation.
+///
+/// ```
+/// let height = reddish::random_normal(170.0, 10.0).unwrap();
+/// assert!(height.is_finite());
+/// assert_eq!(reddish::random_normal(5.0, 0.0), Ok(5.0));
+/// assert!(reddish::random_normal(0.0, -1.0).is_err());
+/// ```
+pub fn random_normal(mean: f64, std_dev: f64) -> Result<f64, DistributionError> {
+    random_normal_with_rng(mean, std_dev, &mut rand::thread_rng())
+}
+
+/// Like [`random_normal`](crate::random_normal), drawing from the given random number generator.
+pub fn random_normal_with_rng<R: Rng + ?Sized>(
+    mean: f64,
+    std_dev: f64,
+    rng: &mut R,
+) -> Result<f64, DistributionError> {
+    let mean = check_parameter("mean", mean, |_| true)?;
+    let std_dev = check_parameter("std_dev", std_dev, |s| s >= 0.0)?;
+    let normal = Normal::new(mean, std_dev).map_err(|_| DistributionError::InvalidParameter {
+        name: "std_dev",
+        value: std_dev,
+    })?;
+    Ok(normal.sample(rng))
+}
+
+/// Samples the log-normal distribution, whose logarithm is normal with mean `mu` and
+/// standard deviation `sigma`. Useful for latencies and sizes, which are always positive
+/// and right-skewed.
+///
+/// ```
+/// let latency_ms = reddish::random_log_normal(3.0, 0.5).unwrap();
+/// assert!(latency_ms > 0.0);
+/// ```
+pub fn random_log_normal(mu: f64, sigma: f64) -> Result<f64, DistributionError> {
+    random_log_normal_with_rng(mu, sigma, &mut rand::thread_rng())
+}
+
+/// Like [`random_log_normal`](crate::random_log_normal), drawing from the given random number
+/// generator.
+pub fn random_log_normal_with_rng<R: Rng + ?Sized>(
+    mu: f64,
+    sigma: f64,
+    rng: &mut R,
+) -> Result<f64, DistributionError> {
+    let mu = check_parameter("mu", mu, |_| true)?;
+    let sigma = check_parameter("sigma", sigma, |s| s >= 0.0)?;
+    let log_normal = LogNormal::new(mu, sigma).map_err(|_| DistributionError::InvalidParameter {
+        name: "sigma",
+        value: sigma,
+    })?;
+    Ok(log_normal.sample(rng))
+}
@@ -0,0 +1,31 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+use rand_distr::{Distribution, Pareto};
+
+use super::distribution_error::{check_parameter, DistributionError};
+
+/// Samples the Pareto distribution with minimum value `scale` and tail index `shape`;
+/// smaller shapes give heavier tails. Models things like file sizes and wealth.
+///
+/// ```
+/// let size = reddish::random_pareto(1.0, 1.5).unwrap();
+/// assert!(size >= 1.0);
+/// assert!(reddish::random_pareto(0.0, 1.5).is_err());
+/// ```
+pub fn random_pareto(scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    random_pareto_with_rng(scale, shape, &mut rand::thread_rng())
+}
+
+/// Like [`random_pareto`](crate::random_pareto), drawing from the given random number generator.
+pub fn random_pareto_with_rng<R: Rng + ?Sized>(
+    scale: f64,
+    shape: f64,
+    rng: &mut R,
+) -> Result<f64, DistributionError> {
+    let scale = check_parameter("scale", scale, |s| s > 0.0)?;
+    let shape = check_parameter("shape", shape, |s| s > 0.0)?;
+    let pareto = Pareto::new(scale, shape)
+        .map_err(|_| DistributionError::InvalidParameter { name: "shape", value: shape })?;
+    Ok(pareto.sample(rng))
+}
@@ -0,0 +1,51 @@
+#![allow(dead_code)]
+
+use rand::rngs::OsRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::wordlist::WORDLIST;
+
+/// Generates a diceware-style passphrase of `words` words joined by `separator`, using the
+/// operating system's random number generator.
+///
+/// Words come from the EFF short wordlist of 1,296 words (CC BY 3.0, Electronic Frontier
+/// Foundation), one of which is `yo-yo`, so avoid `-` as a separator if the words need
+/// to be split again. See [`passphrase_entropy`](crate::passphrase_entropy) for the
+/// strength of the result.
+///
+/// ```
+/// let phrase = reddish::random_passphrase(5, " ");
+/// assert_eq!(phrase.split(' ').count(), 5);
+/// assert!(phrase.split(' ').all(|word| (3..=5).contains(&word.len())));
+/// ```
+pub fn random_passphrase(words: usize, separator: &str) -> String {
+    random_passphrase_with_rng(words, separator, &mut OsRng)
+}
+
+/// Like [`random_passphrase`](crate::random_passphrase), drawing from the given random number
+/// generator.
+pub fn random_passphrase_with_rng<R: Rng + ?Sized>(
+    words: usize,
+    separator: &str,
+    rng: &mut R,
+) -> String {
+    (0..words)
+        .map(|_| *WORDLIST.choose(rng).expect("the wordlist is not empty"))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Returns the entropy in bits of a passphrase of `words` words from
+/// [`random_passphrase`](crate::random_passphrase).
+///
+/// Each word adds log2(1296) ≈ 10.34 bits, so six words give about 62 bits.
+///
+/// ```
+/// let bits = reddish::passphrase_entropy(6);
+/// assert!((bits - 62.04).abs() < 0.01);
+/// assert_eq!(reddish::passphrase_entropy(0), 0.0);
+/// ```
+pub fn passphrase_entropy(words: usize) -> f64 {
+    words as f64 * (WORDLIST.len() as f64).log2()
+}
@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+use rand_distr::{Distribution, Poisson};
+
+use super::distribution_error::{check_parameter, DistributionError};
+
+/// Samples the Poisson distribution with mean `lambda`: the number of events in a fixed
+/// interval when they occur independently at a constant rate.
+///
+/// ```
+/// let arrivals = reddish::random_poisson(4.0).unwrap();
+/// assert!(arrivals < 1000);
+/// assert!(reddish::random_poisson(-1.0).is_err());
+/// ```
+pub fn random_poisson(lambda: f64) -> Result<u64, DistributionError> {
+    random_poisson_with_rng(lambda, &mut rand::thread_rng())
+}
+
+/// Like [`random_poisson`](crate::random_poisson), drawing from the given random number generator.
+pub fn random_poisson_with_rng<R: Rng + ?Sized>(
+    lambda: f64,
+    rng: &mut R,
+) -> Result<u64, DistributionError> {
+    let lambda = check_parameter("lambda", lambda, |l| l > 0.0)?;
+    let poisson = Poisson::new(lambda)
+        .map_err(|_| DistributionError::InvalidParameter { name: "lambda", value: lambda })?;
+    let sample: f64 = poisson.sample(rng);
+    Ok(sample as u64)
+}
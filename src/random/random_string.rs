@@ -15,9 +15,10 @@ use rand::{Rng, distributions::Alphanumeric};
 /// assert_eq!(result.len(), 0);
 /// ```
 pub fn random_string(length: usize) -> String {
-    rand::thread_rng()
-        .sample_iter(&Alphanumeric)
-        .take(length)
-        .map(char::from)
-        .collect()
+    random_string_with_rng(length, &mut rand::thread_rng())
+}
+
+/// Like [`random_string`](crate::random_string), drawing from the given random number generator.
+pub fn random_string_with_rng<R: Rng + ?Sized>(length: usize, rng: &mut R) -> String {
+    (0..length).map(|_| char::from(rng.sample(Alphanumeric))).collect()
 }
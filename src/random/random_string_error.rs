@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by [`random_string_from`](crate::random_string_from).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RandomStringError {
+    /// No characters are left to choose from, e.g. an empty custom alphabet.
+    EmptyCharset,
+    /// The string is too short to contain one character of every required class.
+    TooShort { length: usize, required: usize },
+}
+
+impl fmt::Display for RandomStringError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RandomStringError::EmptyCharset => write!(f, "the character set is empty"),
+            RandomStringError::TooShort { length, required } => {
+                write!(
+                    f,
+                    "length {} is too short to include {} character classes",
+                    length, required
+                )
+            }
+        }
+    }
+}
+
+impl Error for RandomStringError {}
@@ -0,0 +1,100 @@
+#![allow(dead_code)]
+
+use rand::rngs::OsRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::random_string_error::RandomStringError;
+use super::random_string_options::RandomStringOptions;
+
+/// Generates a random string of `length` characters according to `options`.
+///
+/// Every character of the alphabet is equally likely. When `require_each_class` is set,
+/// one character of each class is placed at a random position and the rest are drawn
+/// from the whole alphabet. When `secure` is set the operating system's random number
+/// generator is used instead of the thread-local one.
+///
+/// ```
+/// use reddish::{Charset, RandomStringOptions};
+///
+/// let hex = reddish::random_string_from(16, &RandomStringOptions::new(Charset::Hex)).unwrap();
+/// assert_eq!(hex.len(), 16);
+/// assert!(hex.chars().all(|c| c.is_ascii_hexdigit()));
+///
+/// let options =
+///     RandomStringOptions::new(Charset::AlphanumericSymbols).with_require_each_class(true);
+/// let password = reddish::random_string_from(12, &options).unwrap();
+/// assert!(password.chars().any(|c| c.is_ascii_lowercase()));
+/// assert!(password.chars().any(|c| c.is_ascii_uppercase()));
+/// assert!(password.chars().any(|c| c.is_ascii_digit()));
+/// assert!(password.chars().any(|c| c.is_ascii_punctuation()));
+/// ```
+///
+/// ```
+/// use reddish::{Charset, RandomStringError, RandomStringOptions};
+///
+/// let empty = RandomStringOptions::new(Charset::Custom(String::new()));
+/// assert_eq!(reddish::random_string_from(8, &empty), Err(RandomStringError::EmptyCharset));
+/// ```
+pub fn random_string_from(
+    length: usize,
+    options: &RandomStringOptions,
+) -> Result<String, RandomStringError> {
+    if options.secure {
+        random_string_from_with_rng(length, options, &mut OsRng)
+    } else {
+        random_string_from_with_rng(length, options, &mut rand::thread_rng())
+    }
+}
+
+/// Like [`random_string_from`](crate::random_string_from), drawing from the given random
+/// number generator. The `secure` option is ignored.
+pub fn random_string_from_with_rng<R: Rng + ?Sized>(
+    length: usize,
+    options: &RandomStringOptions,
+    rng: &mut R,
+) -> Result<String, RandomStringError> {
+    let alphabet = options.alphabet();
+    if alphabet.is_empty() {
+        return Err(RandomStringError::EmptyCharset);
+    }
+
+    let classes: Vec<Vec<char>> = if options.require_each_class {
+        let mut classes: Vec<Vec<char>> = vec![Vec::new(); 4];
+        for &c in &alphabet {
+            classes[class_of(c)].push(c);
+        }
+        classes.retain(|class| !class.is_empty());
+        classes
+    } else {
+        Vec::new()
+    };
+    if length < classes.len() {
+        return Err(RandomStringError::TooShort {
+            length,
+            required: classes.len(),
+        });
+    }
+
+    let mut chars: Vec<char> =
+        classes.iter().map(|class| class[rng.gen_range(0..class.len())]).collect();
+    while chars.len() < length {
+        chars.push(alphabet[rng.gen_range(0..alphabet.len())]);
+    }
+    if !classes.is_empty() {
+        chars.shuffle(rng);
+    }
+    Ok(chars.into_iter().collect())
+}
+
+fn class_of(c: char) -> usize {
+    if c.is_lowercase() {
+        0
+    } else if c.is_uppercase() {
+        1
+    } else if c.is_numeric() {
+        2
+    } else {
+        3
+    }
+}
@@ -0,0 +1,67 @@
+#![allow(dead_code)]
+
+use super::charset::{Charset, AMBIGUOUS_CHARS};
+
+/// Settings for [`random_string_from`](crate::random_string_from).
+///
+/// ```
+/// use reddish::{Charset, RandomStringOptions};
+///
+/// let options = RandomStringOptions::new(Charset::Alphanumeric)
+///     .with_exclude_ambiguous(true)
+///     .with_require_each_class(true)
+///     .with_secure(true);
+/// assert!(!options.alphabet().contains(&'0'));
+/// assert!(!options.alphabet().contains(&'l'));
+/// assert_eq!(options.alphabet().len(), 62 - 5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RandomStringOptions {
+    /// The characters to draw from.
+    pub charset: Charset,
+    /// Leaves out look-alike characters such as `0`/`O` and `1`/`l`/`I`.
+    pub exclude_ambiguous: bool,
+    /// Guarantees at least one lowercase letter, uppercase letter, digit and symbol,
+    /// for each of those classes the alphabet contains.
+    pub require_each_class: bool,
+    /// Draws from the operating system's random number generator, for API keys,
+    /// session IDs and reset tokens.
+    pub secure: bool,
+}
+
+impl RandomStringOptions {
+    /// Creates options drawing from `charset` with every other setting off.
+    pub fn new(charset: Charset) -> Self {
+        RandomStringOptions {
+            charset,
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether look-alike characters are excluded.
+    pub fn with_exclude_ambiguous(mut self, exclude: bool) -> Self {
+        self.exclude_ambiguous = exclude;
+        self
+    }
+
+    /// Sets whether every character class in the alphabet must appear.
+    pub fn with_require_each_class(mut self, require: bool) -> Self {
+        self.require_each_class = require;
+        self
+    }
+
+    /// Sets whether the operating system's random number generator is used.
+    pub fn with_secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    /// Returns the characters that will actually be drawn from.
+    pub fn alphabet(&self) -> Vec<char> {
+        let mut chars = self.charset.chars();
+        if self.exclude_ambiguous {
+            chars.retain(|c| !AMBIGUOUS_CHARS.contains(*c));
+        }
+        chars
+    }
+}
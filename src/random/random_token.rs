@@ -0,0 +1,23 @@
+#![allow(dead_code)]
+
+use rand::rngs::OsRng;
+
+use super::charset::Charset;
+use super::random_string_from::random_string_from_with_rng;
+use super::random_string_options::RandomStringOptions;
+
+/// Generates a URL-safe token of `length` characters from the operating system's random
+/// number generator, suitable for API keys, session IDs and password reset links.
+///
+/// Each character carries 6 bits of entropy, so 22 characters are enough for 128 bits.
+///
+/// ```
+/// let token = reddish::random_token(32);
+/// assert_eq!(token.len(), 32);
+/// assert!(token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+/// assert_ne!(token, reddish::random_token(32));
+/// ```
+pub fn random_token(length: usize) -> String {
+    random_string_from_with_rng(length, &RandomStringOptions::new(Charset::UrlSafe), &mut OsRng)
+        .expect("the URL-safe alphabet is not empty")
+}
@@ -0,0 +1,35 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+use rand_distr::{Distribution, Zipf};
+
+use super::distribution_error::{check_parameter, DistributionError};
+
+/// Samples the Zipf distribution over the ranks `1..=n` with exponent `s`, where rank `k`
+/// is drawn with probability proportional to `1 / k^s`. Models popularity, such as which
+/// cache key or product page is requested next.
+///
+/// ```
+/// let rank = reddish::random_zipf(1000, 1.1).unwrap();
+/// assert!((1..=1000).contains(&rank));
+/// assert!(reddish::random_zipf(0, 1.0).is_err());
+/// ```
+pub fn random_zipf(n: u64, s: f64) -> Result<u64, DistributionError> {
+    random_zipf_with_rng(n, s, &mut rand::thread_rng())
+}
+
+/// Like [`random_zipf`](crate::random_zipf), drawing from the given random number generator.
+pub fn random_zipf_with_rng<R: Rng + ?Sized>(
+    n: u64,
+    s: f64,
+    rng: &mut R,
+) -> Result<u64, DistributionError> {
+    if n == 0 {
+        return Err(DistributionError::InvalidParameter { name: "n", value: 0.0 });
+    }
+    let s = check_parameter("s", s, |s| s >= 0.0)?;
+    let zipf =
+        Zipf::new(n, s).map_err(|_| DistributionError::InvalidParameter { name: "s", value: s })?;
+    let rank: f64 = zipf.sample(rng);
+    Ok(rank as u64)
+}
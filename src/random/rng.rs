@@ -0,0 +1,260 @@
+#![allow(dead_code)]
+
+use std::ops::RangeBounds;
+
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use super::distribution_error::DistributionError;
+use super::random_binomial::random_binomial_with_rng;
+use super::random_bool::{random_bool_with_probability_with_rng, random_bool_with_rng};
+use super::random_choice::random_choice_with_rng;
+use super::random_choice_iter::random_choice_iter_with_rng;
+use super::random_exponential::random_exponential_with_rng;
+use super::random_float::random_float_with_rng;
+use super::random_geometric::random_geometric_with_rng;
+use super::random_int::random_int_with_rng;
+use super::random_int_in::{random_int_in_with_rng, RandomInt};
+use super::random_name::random_name_with_rng;
+use super::random_normal::{random_log_normal_with_rng, random_normal_with_rng};
+use super::random_pareto::random_pareto_with_rng;
+use super::random_passphrase::random_passphrase_with_rng;
+use super::random_poisson::random_poisson_with_rng;
+use super::random_string::random_string_with_rng;
+use super::random_string_error::RandomStringError;
+use super::random_string_from::random_string_from_with_rng;
+use super::random_string_options::RandomStringOptions;
+use super::random_zipf::random_zipf_with_rng;
+use super::sample::sample_with_rng;
+use super::sample_iter::sample_iter_with_rng;
+use super::sample_with_replacement::sample_with_replacement_with_rng;
+use super::shuffle::shuffle_with_rng;
+use super::uuid_gen::uuid_with_rng;
+use super::uuid_v7::uuid_v7_with_rng;
+use super::weighted_choice::weighted_choice_with_rng;
+use super::weighted_error::WeightedError;
+use super::weighted_sample::weighted_sample_with_rng;
+
+/// A seedable random number generator offering the crate's random functions as methods.
+///
+/// Two `Random`s created from the same seed produce exactly the same sequence on every
+/// platform, for a given version of this crate and its dependencies, which makes tests
+/// reproducible. Upgrading `rand` or `rand_distr` may change the values drawn for a seed.
+/// `Random` also implements [`RngCore`], so it can be passed to any `_with_rng` function
+/// or to the `rand` crate.
+///
+/// ```
+/// use reddish::Random;
+///
+/// let mut a = Random::from_seed(42);
+/// let mut b = Random::from_seed(42);
+/// assert_eq!(a.int(1, 100), b.int(1, 100));
+/// assert_eq!(a.string(8), b.string(8));
+///
+/// let mut deck = vec![1, 2, 3, 4, 5];
+/// a.shuffle(&mut deck);
+/// let mut same = vec![1, 2, 3, 4, 5];
+/// b.shuffle(&mut same);
+/// assert_eq!(deck, same);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Random {
+    rng: ChaCha8Rng,
+}
+
+impl Random {
+    /// Creates a generator seeded from the operating system's entropy source.
+    pub fn new() -> Self {
+        Random {
+            rng: ChaCha8Rng::from_entropy(),
+        }
+    }
+
+    /// Creates a generator whose output is fully determined by `seed`.
+    pub fn from_seed(seed: u64) -> Self {
+        Random {
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// See [`random_int`](crate::random_int).
+    pub fn int(&mut self, min: i32, max: i32) -> i32 {
+        random_int_with_rng(min, max, self)
+    }
+
+    /// See [`random_int_in`](crate::random_int_in).
+    pub fn int_in<T: RandomInt, B: RangeBounds<T>>(
+        &mut self,
+        range: B,
+    ) -> Result<T, DistributionError> {
+        random_int_in_with_rng(range, self)
+    }
+
+    /// See [`random_float`](crate::random_float).
+    pub fn float(&mut self, min: f64, max: f64) -> f64 {
+        random_float_with_rng(min, max, self)
+    }
+
+    /// See [`random_bool`](crate::random_bool).
+    pub fn bool(&mut self) -> bool {
+        random_bool_with_rng(self)
+    }
+
+    /// See [`random_bool_with_probability`](crate::random_bool_with_probability).
+    pub fn bool_with_probability(&mut self, probability: f64) -> bool {
+        random_bool_with_probability_with_rng(probability, self)
+    }
+
+    /// See [`random_choice`](crate::random_choice).
+    pub fn choice<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        random_choice_with_rng(slice, self)
+    }
+
+    /// See [`shuffle`](crate::shuffle).
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        shuffle_with_rng(slice, self)
+    }
+
+    /// See [`sample`](crate::sample).
+    pub fn sample<T: Clone>(&mut self, slice: &[T], n: usize) -> Vec<T> {
+        sample_with_rng(slice, n, self)
+    }
+
+    /// See [`sample_with_replacement`](crate::sample_with_replacement).
+    pub fn sample_with_replacement<T: Clone>(
+        &mut self,
+        slice: &[T],
+        n: usize,
+    ) -> Result<Vec<T>, WeightedError> {
+        sample_with_replacement_with_rng(slice, n, self)
+    }
+
+    /// See [`weighted_choice`](crate::weighted_choice).
+    pub fn weighted_choice<'a, T>(
+        &mut self,
+        items: &'a [T],
+        weights: &[f64],
+    ) -> Result<&'a T, WeightedError> {
+        weighted_choice_with_rng(items, weights, self)
+    }
+
+    /// See [`sample_iter`](crate::sample_iter).
+    pub fn sample_iter<I: IntoIterator>(&mut self, iter: I, k: usize) -> Vec<I::Item> {
+        sample_iter_with_rng(iter, k, self)
+    }
+
+    /// See [`random_choice_iter`](crate::random_choice_iter).
+    pub fn choice_iter<I: IntoIterator>(&mut self, iter: I) -> Option<I::Item> {
+        random_choice_iter_with_rng(iter, self)
+    }
+
+    /// See [`weighted_sample`](crate::weighted_sample).
+    pub fn weighted_sample<T: Clone>(
+        &mut self,
+        items: &[T],
+        weights: &[f64],
+        n: usize,
+    ) -> Result<Vec<T>, WeightedError> {
+        weighted_sample_with_rng(items, weights, n, self)
+    }
+
+    /// See [`random_string`](crate::random_string).
+    pub fn string(&mut self, length: usize) -> String {
+        random_string_with_rng(length, self)
+    }
+
+    /// See [`random_string_from`](crate::random_string_from). The `secure` option is ignored,
+    /// since the point of a seeded generator is to be predictable.
+    pub fn string_from(
+        &mut self,
+        length: usize,
+        options: &RandomStringOptions,
+    ) -> Result<String, RandomStringError> {
+        random_string_from_with_rng(length, options, self)
+    }
+
+    /// See [`random_normal`](crate::random_normal).
+    pub fn normal(&mut self, mean: f64, std_dev: f64) -> Result<f64, DistributionError> {
+        random_normal_with_rng(mean, std_dev, self)
+    }
+
+    /// See [`random_log_normal`](crate::random_log_normal).
+    pub fn log_normal(&mut self, mu: f64, sigma: f64) -> Result<f64, DistributionError> {
+        random_log_normal_with_rng(mu, sigma, self)
+    }
+
+    /// See [`random_exponential`](crate::random_exponential).
+    pub fn exponential(&mut self, lambda: f64) -> Result<f64, DistributionError> {
+        random_exponential_with_rng(lambda, self)
+    }
+
+    /// See [`random_poisson`](crate::random_poisson).
+    pub fn poisson(&mut self, lambda: f64) -> Result<u64, DistributionError> {
+        random_poisson_with_rng(lambda, self)
+    }
+
+    /// See [`random_binomial`](crate::random_binomial).
+    pub fn binomial(&mut self, n: u64, p: f64) -> Result<u64, DistributionError> {
+        random_binomial_with_rng(n, p, self)
+    }
+
+    /// See [`random_geometric`](crate::random_geometric).
+    pub fn geometric(&mut self, p: f64) -> Result<u64, DistributionError> {
+        random_geometric_with_rng(p, self)
+    }
+
+    /// See [`random_zipf`](crate::random_zipf).
+    pub fn zipf(&mut self, n: u64, s: f64) -> Result<u64, DistributionError> {
+        random_zipf_with_rng(n, s, self)
+    }
+
+    /// See [`random_pareto`](crate::random_pareto).
+    pub fn pareto(&mut self, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+        random_pareto_with_rng(scale, shape, self)
+    }
+
+    /// See [`uuid`](crate::uuid).
+    pub fn uuid(&mut self) -> String {
+        uuid_with_rng(self)
+    }
+
+    /// See [`random_passphrase`](crate::random_passphrase).
+    pub fn passphrase(&mut self, words: usize, separator: &str) -> String {
+        random_passphrase_with_rng(words, separator, self)
+    }
+
+    /// See [`random_name`](crate::random_name).
+    pub fn name(&mut self) -> String {
+        random_name_with_rng(self)
+    }
+
+    /// See [`uuid_v7`](crate::uuid_v7). The timestamp still comes from the system clock.
+    pub fn uuid_v7(&mut self) -> String {
+        uuid_v7_with_rng(self)
+    }
+}
+
+impl Default for Random {
+    fn default() -> Self {
+        Random::new()
+    }
+}
+
+impl RngCore for Random {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 use rand::seq::SliceRandom;
+use rand::Rng;
 
 /// Selects n random elements from a slice without replacement.
 /// Returns a vector with the selected elements.
@@ -22,9 +23,11 @@ use rand::seq::SliceRandom;
 /// assert_eq!(result.len(), 3); // Should return all available elements
 /// ```
 pub fn sample<T: Clone>(slice: &[T], n: usize) -> Vec<T> {
+    sample_with_rng(slice, n, &mut rand::thread_rng())
+}
+
+/// Like [`sample`](crate::sample), drawing from the given random number generator.
+pub fn sample_with_rng<T: Clone, R: Rng + ?Sized>(slice: &[T], n: usize, rng: &mut R) -> Vec<T> {
     let sample_size = n.min(slice.len());
-    slice
-        .choose_multiple(&mut rand::thread_rng(), sample_size)
-        .cloned()
-        .collect()
+    slice.choose_multiple(rng, sample_size).cloned().collect()
 }
@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+
+/// Picks `k` items uniformly at random from an iterator of unknown length, without
+/// replacement, keeping only `k` items in memory.
+///
+/// Uses reservoir sampling with Li's Algorithm L, which skips over runs of items instead
+/// of drawing a random number for each one. Returns every item when the iterator yields
+/// fewer than `k`. The order of the returned items is not significant.
+///
+/// ```
+/// let picked = reddish::sample_iter(1..=1_000_000, 5);
+/// assert_eq!(picked.len(), 5);
+/// assert!(picked.iter().all(|n| (1..=1_000_000).contains(n)));
+///
+/// assert_eq!(reddish::sample_iter(vec!["a", "b"], 5).len(), 2);
+/// ```
+pub fn sample_iter<I: IntoIterator>(iter: I, k: usize) -> Vec<I::Item> {
+    sample_iter_with_rng(iter, k, &mut rand::thread_rng())
+}
+
+/// Like [`sample_iter`](crate::sample_iter), drawing from the given random number generator.
+pub fn sample_iter_with_rng<I: IntoIterator, R: Rng + ?Sized>(
+    iter: I,
+    k: usize,
+    rng: &mut R,
+) -> Vec<I::Item> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut iter = iter.into_iter();
+    let mut reservoir: Vec<I::Item> = iter.by_ref().take(k).collect();
+    if reservoir.len() < k {
+        return reservoir;
+    }
+
+    let mut w = (open_unit(rng).ln() / k as f64).exp();
+    loop {
+        // Number of items to pass over before the next one enters the reservoir
+        let skip = (open_unit(rng).ln() / (1.0 - w).ln()).floor();
+        match iter.nth(skip as usize) {
+            Some(item) => reservoir[rng.gen_range(0..k)] = item,
+            None => return reservoir,
+        }
+        w *= (open_unit(rng).ln() / k as f64).exp();
+    }
+}
+
+// A uniform float in (0, 1], safe to take the logarithm of
+pub(crate) fn open_unit<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    1.0 - rng.gen::<f64>()
+}
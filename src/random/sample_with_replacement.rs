@@ -0,0 +1,35 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+
+use super::weighted_error::WeightedError;
+
+/// Picks `n` items uniformly at random with replacement, so the same item can appear
+/// more than once and `n` may exceed the number of items. Returns
+/// [`WeightedError::Empty`] when asked for items from an empty slice.
+///
+/// ```
+/// let rolls = reddish::sample_with_replacement(&[1, 2, 3, 4, 5, 6], 10).unwrap();
+/// assert_eq!(rolls.len(), 10);
+/// assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+///
+/// assert!(reddish::sample_with_replacement::<i32>(&[], 1).is_err());
+/// assert_eq!(reddish::sample_with_replacement::<i32>(&[], 0), Ok(vec![]));
+/// ```
+pub fn sample_with_replacement<T: Clone>(slice: &[T], n: usize) -> Result<Vec<T>, WeightedError> {
+    sample_with_replacement_with_rng(slice, n, &mut rand::thread_rng())
+}
+
+/// Like [`sample_with_replacement`](crate::sample_with_replacement), drawing from the given
+/// random number generator.
+pub fn sample_with_replacement_with_rng<T: Clone, R: Rng + ?Sized>(
+    slice: &[T],
+    n: usize,
+    rng: &mut R,
+) -> Result<Vec<T>, WeightedError> {
+    if slice.is_empty() && n > 0 {
+        return Err(WeightedError::Empty);
+    }
+
+    Ok((0..n).map(|_| slice[rng.gen_range(0..slice.len())].clone()).collect())
+}
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 use rand::seq::SliceRandom;
+use rand::Rng;
 
 /// Shuffles a vector in place using the Fisher-Yates algorithm.
 ///
@@ -16,5 +17,22 @@ use rand::seq::SliceRandom;
 /// }
 /// ```
 pub fn shuffle<T>(vec: &mut Vec<T>) {
-    vec.shuffle(&mut rand::thread_rng());
+    shuffle_with_rng(vec, &mut rand::thread_rng());
+}
+
+/// Like [`shuffle`](crate::shuffle), drawing from the given random number generator, so the
+/// same seed always produces the same order.
+///
+/// ```
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha8Rng;
+///
+/// let mut a = vec![1, 2, 3, 4, 5];
+/// let mut b = a.clone();
+/// reddish::shuffle_with_rng(&mut a, &mut ChaCha8Rng::seed_from_u64(42));
+/// reddish::shuffle_with_rng(&mut b, &mut ChaCha8Rng::seed_from_u64(42));
+/// assert_eq!(a, b);
+/// ```
+pub fn shuffle_with_rng<T, R: Rng + ?Sized>(slice: &mut [T], rng: &mut R) {
+    slice.shuffle(rng);
 }
@@ -0,0 +1,149 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::snowflake_error::SnowflakeError;
+
+const WORKER_BITS: u32 = 10;
+const SEQUENCE_BITS: u32 = 12;
+const MAX_WORKER_ID: u16 = (1 << WORKER_BITS) - 1;
+const MAX_SEQUENCE: u16 = (1 << SEQUENCE_BITS) - 1;
+const MAX_TIMESTAMP: i64 = (1 << 41) - 1;
+
+/// The epoch used by Twitter's Snowflake IDs, 2010-11-04T01:42:54.657Z.
+pub const SNOWFLAKE_TWITTER_EPOCH_MS: i64 = 1_288_834_974_657;
+
+/// A Snowflake-style 64-bit ID generator.
+///
+/// Each ID holds 41 bits of milliseconds since a custom epoch, a 10-bit worker id and a
+/// 12-bit sequence number, so IDs are time-ordered and unique across up to 1024 workers.
+/// A worker issues up to 4096 IDs per millisecond and then waits for the next one.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use reddish::SnowflakeGenerator;
+///
+/// let epoch = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+/// let mut generator = SnowflakeGenerator::new(epoch, 7).unwrap();
+/// let a = generator.next_id();
+/// let b = generator.next_id();
+/// assert!(a < b);
+/// assert_eq!(reddish::snowflake_worker_id(a), 7);
+/// assert!(generator.timestamp_of(a) <= Utc::now());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SnowflakeGenerator {
+    epoch: DateTime<Utc>,
+    worker_id: u16,
+    last_millis: i64,
+    sequence: u16,
+}
+
+impl SnowflakeGenerator {
+    /// Creates a generator for `worker_id` (0 to 1023) counting from `epoch`, which must
+    /// lie within the last 2^41 milliseconds (about 69.7 years).
+    pub fn new(epoch: DateTime<Utc>, worker_id: u16) -> Result<Self, SnowflakeError> {
+        if worker_id > MAX_WORKER_ID {
+            return Err(SnowflakeError::InvalidWorkerId(worker_id));
+        }
+        let now = Utc::now();
+        if epoch > now {
+            return Err(SnowflakeError::EpochInFuture);
+        }
+        if (now - epoch).num_milliseconds() > MAX_TIMESTAMP {
+            return Err(SnowflakeError::EpochTooOld);
+        }
+        Ok(SnowflakeGenerator {
+            epoch,
+            worker_id,
+            last_millis: -1,
+            sequence: 0,
+        })
+    }
+
+    /// Returns the epoch IDs are counted from.
+    pub fn epoch(&self) -> DateTime<Utc> {
+        self.epoch
+    }
+
+    /// Returns the worker id embedded in every ID.
+    pub fn worker_id(&self) -> u16 {
+        self.worker_id
+    }
+
+    /// Generates the next ID.
+    ///
+    /// If the clock goes backwards the generator keeps using the last timestamp it saw,
+    /// so IDs never decrease.
+    ///
+    /// # Panics
+    ///
+    /// Panics once more than 2^41 milliseconds have passed since the epoch, since later
+    /// IDs would wrap around and sort before earlier ones.
+    pub fn next_id(&mut self) -> u64 {
+        let mut millis = self.elapsed_millis().max(self.last_millis);
+        if millis == self.last_millis {
+            if self.sequence == MAX_SEQUENCE {
+                while millis <= self.last_millis {
+                    std::thread::yield_now();
+                    millis = self.elapsed_millis();
+                }
+                self.sequence = 0;
+            } else {
+                self.sequence += 1;
+            }
+        } else {
+            self.sequence = 0;
+        }
+        assert!(
+            millis <= MAX_TIMESTAMP,
+            "snowflake timestamp exhausted: more than 2^41 ms have passed since the epoch"
+        );
+        self.last_millis = millis;
+
+        (millis as u64) << (WORKER_BITS + SEQUENCE_BITS)
+            | (self.worker_id as u64) << SEQUENCE_BITS
+            | self.sequence as u64
+    }
+
+    /// Extracts the creation time of an ID made by this generator.
+    pub fn timestamp_of(&self, id: u64) -> DateTime<Utc> {
+        snowflake_timestamp(id, self.epoch)
+    }
+
+    fn elapsed_millis(&self) -> i64 {
+        (Utc::now() - self.epoch).num_milliseconds()
+    }
+}
+
+/// Extracts the creation time of a Snowflake ID, given the epoch it was generated with.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+///
+/// let epoch = Utc.timestamp_millis_opt(reddish::SNOWFLAKE_TWITTER_EPOCH_MS).unwrap();
+/// let time = reddish::snowflake_timestamp(1541815603606036480, epoch);
+/// assert_eq!(time, Utc.timestamp_millis_opt(1656432460105).unwrap());
+/// ```
+pub fn snowflake_timestamp(id: u64, epoch: DateTime<Utc>) -> DateTime<Utc> {
+    epoch + Duration::milliseconds((id >> (WORKER_BITS + SEQUENCE_BITS)) as i64)
+}
+
+/// Extracts the 10-bit worker id of a Snowflake ID.
+///
+/// ```
+/// assert_eq!(reddish::snowflake_worker_id((5 << 22) | (42 << 12) | 3), 42);
+/// ```
+pub fn snowflake_worker_id(id: u64) -> u16 {
+    ((id >> SEQUENCE_BITS) as u16) & MAX_WORKER_ID
+}
+
+/// Extracts the 12-bit sequence number of a Snowflake ID.
+///
+/// ```
+/// assert_eq!(reddish::snowflake_sequence((5 << 22) | (42 << 12) | 3), 3);
+/// ```
+pub fn snowflake_sequence(id: u64) -> u16 {
+    (id as u16) & MAX_SEQUENCE
+}
+
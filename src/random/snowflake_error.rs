@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by [`SnowflakeGenerator::new`](crate::SnowflakeGenerator::new).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnowflakeError {
+    /// The worker id does not fit in 10 bits.
+    InvalidWorkerId(u16),
+    /// The custom epoch lies in the future.
+    EpochInFuture,
+    /// The custom epoch is so far back that the current time no longer fits in the
+    /// 41-bit timestamp, about 69.7 years.
+    EpochTooOld,
+}
+
+impl fmt::Display for SnowflakeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SnowflakeError::InvalidWorkerId(id) => {
+                write!(f, "worker id {} is out of range: it must be at most 1023", id)
+            }
+            SnowflakeError::EpochInFuture => write!(f, "the epoch is in the future"),
+            SnowflakeError::EpochTooOld => {
+                write!(f, "the epoch is more than 2^41 milliseconds (about 69.7 years) in the past")
+            }
+        }
+    }
+}
+
+impl Error for SnowflakeError {}
@@ -0,0 +1,121 @@
+#![allow(dead_code)]
+
+use std::cell::RefCell;
+
+use chrono::{DateTime, TimeZone, Utc};
+use rand::Rng;
+
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const RANDOM_BITS: u32 = 80;
+const RANDOM_MASK: u128 = (1 << RANDOM_BITS) - 1;
+const MAX_TIMESTAMP: u64 = (1 << 48) - 1;
+
+thread_local! {
+    static GENERATOR: RefCell<UlidGenerator> = RefCell::new(UlidGenerator::new());
+}
+
+/// Generates a ULID: a 26-character, lexicographically sortable identifier made of a
+/// 48-bit millisecond timestamp and 80 random bits, in Crockford Base32.
+///
+/// IDs generated on the same thread are strictly increasing, even within one millisecond.
+///
+/// ```
+/// let a = reddish::ulid();
+/// let b = reddish::ulid();
+/// assert_eq!(a.len(), 26);
+/// assert!(a < b);
+/// ```
+pub fn ulid() -> String {
+    GENERATOR.with(|generator| generator.borrow_mut().generate())
+}
+
+/// A monotonic ULID generator.
+///
+/// When several IDs are generated in the same millisecond, or the clock goes backwards,
+/// the previous random part is incremented instead of drawing a new one, so every ID is
+/// greater than the one before it.
+///
+/// ```
+/// use reddish::UlidGenerator;
+///
+/// let mut generator = UlidGenerator::new();
+/// let ids: Vec<String> = (0..100).map(|_| generator.generate()).collect();
+/// assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UlidGenerator {
+    last: Option<u128>,
+}
+
+impl UlidGenerator {
+    /// Creates a generator that has not produced any ID yet.
+    pub fn new() -> Self {
+        UlidGenerator { last: None }
+    }
+
+    /// Generates the next ULID.
+    pub fn generate(&mut self) -> String {
+        self.generate_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Like [`generate`](UlidGenerator::generate), drawing the random part from the given
+    /// random number generator.
+    pub fn generate_with_rng<R: Rng + ?Sized>(&mut self, rng: &mut R) -> String {
+        let now = (Utc::now().timestamp_millis().max(0) as u64).min(MAX_TIMESTAMP);
+
+        let value = match self.last {
+            Some(last) if (last >> RANDOM_BITS) as u64 >= now => {
+                // Same millisecond or a clock step backwards: keep counting from the last ID.
+                // Overflowing the random part carries into the timestamp.
+                last + 1
+            }
+            _ => ((now as u128) << RANDOM_BITS) | (rng.gen::<u128>() & RANDOM_MASK),
+        };
+        self.last = Some(value);
+        encode(value)
+    }
+}
+
+/// Extracts the creation time of a ULID, accurate to the millisecond.
+///
+/// Decoding is case-insensitive. Returns `None` if the string is not a valid ULID.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+///
+/// let time = reddish::ulid_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+/// assert_eq!(time, Utc.timestamp_millis_opt(1469922850259).unwrap());
+/// assert_eq!(reddish::ulid_timestamp("not-a-ulid"), None);
+/// ```
+pub fn ulid_timestamp(ulid: &str) -> Option<DateTime<Utc>> {
+    let millis = (decode(ulid)? >> RANDOM_BITS) as i64;
+    Utc.timestamp_millis_opt(millis).single()
+}
+
+fn encode(value: u128) -> String {
+    (0..26)
+        .map(|i| CROCKFORD[((value >> (125 - 5 * i)) & 31) as usize] as char)
+        .collect()
+}
+
+fn decode(ulid: &str) -> Option<u128> {
+    if ulid.len() != 26 {
+        return None;
+    }
+
+    let mut value: u128 = 0;
+    for (i, c) in ulid.chars().enumerate() {
+        let c = match c.to_ascii_uppercase() {
+            'O' => '0',
+            'I' | 'L' => '1',
+            c => c,
+        };
+        let digit = CROCKFORD.iter().position(|&d| d as char == c)? as u128;
+        // The first character only holds 3 bits
+        if i == 0 && digit > 7 {
+            return None;
+        }
+        value = (value << 5) | digit;
+    }
+    Some(value)
+}
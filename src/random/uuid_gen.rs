@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
-use uuid::Uuid;
+use rand::Rng;
+use uuid::{Builder, Uuid};
 
 /// Generates a random UUID v4.
 ///
@@ -18,3 +19,9 @@ use uuid::Uuid;
 pub fn uuid() -> String {
     Uuid::new_v4().to_string()
 }
+
+/// Like [`uuid`](crate::uuid), building the version 4 UUID from the given random number
+/// generator's bytes.
+pub fn uuid_with_rng<R: Rng + ?Sized>(rng: &mut R) -> String {
+    Builder::from_random_bytes(rng.gen()).into_uuid().to_string()
+}
@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+use sha1::{Digest, Sha1};
+use uuid::{Builder, Uuid};
+
+/// The RFC 4122 namespace for fully qualified domain names.
+pub const UUID_NAMESPACE_DNS: &str = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+/// The RFC 4122 namespace for URLs.
+pub const UUID_NAMESPACE_URL: &str = "6ba7b811-9dad-11d1-80b4-00c04fd430c8";
+/// The RFC 4122 namespace for ISO object identifiers.
+pub const UUID_NAMESPACE_OID: &str = "6ba7b812-9dad-11d1-80b4-00c04fd430c8";
+/// The RFC 4122 namespace for X.500 distinguished names.
+pub const UUID_NAMESPACE_X500: &str = "6ba7b814-9dad-11d1-80b4-00c04fd430c8";
+
+/// Generates a name-based UUID v5 from a namespace UUID and a name.
+///
+/// The same namespace and name always give the same UUID. Returns `None` if `namespace`
+/// is not a valid UUID.
+///
+/// ```
+/// let id = reddish::uuid_v5(reddish::UUID_NAMESPACE_DNS, "python.org").unwrap();
+/// assert_eq!(id, "886313e1-3b8a-5372-9b90-0c9aee199e5d");
+/// assert_eq!(reddish::uuid_v5("not a uuid", "python.org"), None);
+/// ```
+pub fn uuid_v5(namespace: &str, name: &str) -> Option<String> {
+    let namespace = Uuid::parse_str(namespace).ok()?;
+
+    let mut hasher = Sha1::new();
+    hasher.update(namespace.as_bytes());
+    hasher.update(name.as_bytes());
+    let digest = hasher.finalize();
+
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    Some(Builder::from_sha1_bytes(bytes).into_uuid().to_string())
+}
@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+use chrono::{DateTime, TimeZone, Utc};
+use rand::Rng;
+use uuid::{Builder, Uuid};
+
+/// Generates a UUID v7: a 48-bit Unix timestamp in milliseconds followed by random bits.
+///
+/// UUIDs created in different milliseconds sort in creation order, which keeps database
+/// indexes compact when used as primary keys.
+///
+/// ```
+/// let id = reddish::uuid_v7();
+/// assert_eq!(id.len(), 36);
+/// assert_eq!(&id[14..15], "7");
+/// ```
+pub fn uuid_v7() -> String {
+    uuid_v7_with_rng(&mut rand::thread_rng())
+}
+
+/// Like [`uuid_v7`](crate::uuid_v7), taking the random bits from the given random number generator.
+pub fn uuid_v7_with_rng<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let millis = Utc::now().timestamp_millis().max(0) as u64;
+    Builder::from_unix_timestamp_millis(millis, &rng.gen()).into_uuid().to_string()
+}
+
+/// Extracts the creation time embedded in a version 1, 6 or 7 UUID.
+///
+/// Returns `None` if the string is not a UUID or its version carries no timestamp.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+///
+/// let time = reddish::uuid_timestamp("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap();
+/// assert_eq!(time, Utc.timestamp_millis_opt(1645557742000).unwrap());
+/// assert_eq!(reddish::uuid_timestamp(&reddish::uuid()), None);
+/// ```
+pub fn uuid_timestamp(uuid: &str) -> Option<DateTime<Utc>> {
+    let (seconds, nanos) = Uuid::parse_str(uuid).ok()?.get_timestamp()?.to_unix();
+    Utc.timestamp_opt(i64::try_from(seconds).ok()?, nanos).single()
+}
@@ -0,0 +1,51 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+
+use super::weighted_error::{validate_weights, WeightedError};
+
+/// Picks one item at random, where each item's chance is proportional to its weight.
+/// Items with a weight of zero are never picked.
+///
+/// ```
+/// let servers = ["primary", "replica"];
+/// let server = reddish::weighted_choice(&servers, &[3.0, 1.0]).unwrap();
+/// assert!(servers.contains(server));
+///
+/// let error = reddish::weighted_choice(&["only"], &[0.0, 1.0]).unwrap_err();
+/// assert_eq!(error.to_string(), "1 items but 2 weights");
+/// ```
+///
+/// ```
+/// let always = reddish::weighted_choice(&["a", "b", "c"], &[0.0, 5.0, 0.0]).unwrap();
+/// assert_eq!(*always, "b");
+/// ```
+pub fn weighted_choice<'a, T>(items: &'a [T], weights: &[f64]) -> Result<&'a T, WeightedError> {
+    weighted_choice_with_rng(items, weights, &mut rand::thread_rng())
+}
+
+/// Like [`weighted_choice`](crate::weighted_choice), drawing from the given random number
+/// generator.
+pub fn weighted_choice_with_rng<'a, T, R: Rng + ?Sized>(
+    items: &'a [T],
+    weights: &[f64],
+    rng: &mut R,
+) -> Result<&'a T, WeightedError> {
+    let total = validate_weights(items.len(), weights)?;
+    let target = rng.gen::<f64>() * total;
+
+    let mut cumulative = 0.0;
+    let mut last_positive = 0;
+    for (index, &weight) in weights.iter().enumerate() {
+        if weight > 0.0 {
+            cumulative += weight;
+            last_positive = index;
+            if target < cumulative {
+                return Ok(&items[index]);
+            }
+        }
+    }
+
+    // Rounding can leave the target just past the final cumulative sum
+    Ok(&items[last_positive])
+}
@@ -0,0 +1,74 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by the weighted random functions such as
+/// [`weighted_choice`](crate::weighted_choice) and
+/// [`WeightedTable::new`](crate::WeightedTable::new).
+#[derive(Debug, Clone, PartialEq)]
+pub enum WeightedError {
+    /// There are no items to choose from.
+    Empty,
+    /// The number of weights does not match the number of items.
+    LengthMismatch { items: usize, weights: usize },
+    /// A weight is negative, NaN or infinite.
+    InvalidWeight { index: usize, weight: f64 },
+    /// Every weight is zero, so no item can be chosen.
+    AllZero,
+}
+
+impl fmt::Display for WeightedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WeightedError::Empty => write!(f, "no items to choose from"),
+            WeightedError::LengthMismatch { items, weights } => {
+                write!(f, "{} items but {} weights", items, weights)
+            }
+            WeightedError::InvalidWeight { index, weight } => {
+                write!(
+                    f,
+                    "invalid weight {} at index {}: weights must be finite and non-negative",
+                    weight, index
+                )
+            }
+            WeightedError::AllZero => write!(f, "all weights are zero"),
+        }
+    }
+}
+
+impl Error for WeightedError {}
+
+// Checks the weights against the items and returns their sum
+pub(crate) fn validate_weights(items: usize, weights: &[f64]) -> Result<f64, WeightedError> {
+    if items == 0 {
+        return Err(WeightedError::Empty);
+    }
+    if items != weights.len() {
+        return Err(WeightedError::LengthMismatch {
+            items,
+            weights: weights.len(),
+        });
+    }
+
+    let mut total = 0.0;
+    for (index, &weight) in weights.iter().enumerate() {
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(WeightedError::InvalidWeight { index, weight });
+        }
+        total += weight;
+    }
+
+    if total == 0.0 {
+        return Err(WeightedError::AllZero);
+    }
+    // Finite weights can still overflow when summed
+    if !total.is_finite() {
+        return Err(WeightedError::InvalidWeight {
+            index: weights.len() - 1,
+            weight: weights[weights.len() - 1],
+        });
+    }
+
+    Ok(total)
+}
@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use std::cmp::Ordering;
+
+use rand::Rng;
+
+use super::weighted_error::{validate_weights, WeightedError};
+
+/// Picks `n` distinct items without replacement, favouring items with larger weights,
+/// using the Efraimidis–Spirakis algorithm. Items with a weight of zero are never picked,
+/// so fewer than `n` items are returned when fewer have a positive weight.
+///
+/// ```
+/// let variants = ["a", "b", "c", "d"];
+/// let picked = reddish::weighted_sample(&variants, &[10.0, 5.0, 1.0, 0.0], 2).unwrap();
+/// assert_eq!(picked.len(), 2);
+/// assert!(!picked.contains(&"d"));
+/// assert_ne!(picked[0], picked[1]);
+/// ```
+pub fn weighted_sample<T: Clone>(
+    items: &[T],
+    weights: &[f64],
+    n: usize,
+) -> Result<Vec<T>, WeightedError> {
+    weighted_sample_with_rng(items, weights, n, &mut rand::thread_rng())
+}
+
+/// Like [`weighted_sample`](crate::weighted_sample), drawing from the given random number
+/// generator.
+pub fn weighted_sample_with_rng<T: Clone, R: Rng + ?Sized>(
+    items: &[T],
+    weights: &[f64],
+    n: usize,
+    rng: &mut R,
+) -> Result<Vec<T>, WeightedError> {
+    validate_weights(items.len(), weights)?;
+
+    // Each item gets the key u^(1/w); the n largest keys form the sample. Comparing
+    // ln(u)/w instead keeps precision for tiny weights.
+    let mut keyed: Vec<(f64, usize)> = weights
+        .iter()
+        .enumerate()
+        .filter(|(_, &weight)| weight > 0.0)
+        .map(|(index, &weight)| {
+            let u = 1.0 - rng.gen::<f64>();
+            (u.ln() / weight, index)
+        })
+        .collect();
+
+    keyed.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(Ordering::Equal));
+    Ok(keyed.into_iter().take(n).map(|(_, index)| items[index].clone()).collect())
+}
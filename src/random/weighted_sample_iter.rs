@@ -0,0 +1,93 @@
+#![allow(dead_code)]
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+use rand::Rng;
+
+use super::sample_iter::open_unit;
+use super::weighted_error::WeightedError;
+
+/// Picks `k` distinct items without replacement from an iterator of `(item, weight)` pairs
+/// of unknown length, favouring larger weights and keeping only `k` items in memory.
+///
+/// This is the streaming form of [`weighted_sample`](crate::weighted_sample) (the A-Res
+/// algorithm of Efraimidis and Spirakis). Items with a weight of zero are never picked.
+/// A negative, NaN or infinite weight stops the sampling with
+/// [`WeightedError::InvalidWeight`](crate::WeightedError::InvalidWeight).
+/// The order of the returned items is not significant.
+///
+/// ```
+/// let requests = vec![("/", 1.0), ("/checkout", 50.0), ("/health", 0.0)];
+/// let picked = reddish::weighted_sample_iter(requests, 1).unwrap();
+/// assert_eq!(picked.len(), 1);
+/// assert_ne!(picked[0], "/health");
+/// ```
+pub fn weighted_sample_iter<T, I>(iter: I, k: usize) -> Result<Vec<T>, WeightedError>
+where
+    I: IntoIterator<Item = (T, f64)>,
+{
+    weighted_sample_iter_with_rng(iter, k, &mut rand::thread_rng())
+}
+
+/// Like [`weighted_sample_iter`](crate::weighted_sample_iter), drawing from the given random number
+/// generator.
+pub fn weighted_sample_iter_with_rng<T, I, R>(
+    iter: I,
+    k: usize,
+    rng: &mut R,
+) -> Result<Vec<T>, WeightedError>
+where
+    I: IntoIterator<Item = (T, f64)>,
+    R: Rng + ?Sized,
+{
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Min-heap on the keys, so the weakest kept item is the one to evict
+    let mut heap: BinaryHeap<Reverse<Keyed<T>>> = BinaryHeap::with_capacity(k);
+    for (index, (item, weight)) in iter.into_iter().enumerate() {
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(WeightedError::InvalidWeight { index, weight });
+        }
+        if weight == 0.0 {
+            continue;
+        }
+
+        let key = open_unit(rng).ln() / weight;
+        if heap.len() < k {
+            heap.push(Reverse(Keyed { key, item }));
+        } else if heap.peek().map_or(false, |smallest| key > smallest.0.key) {
+            heap.pop();
+            heap.push(Reverse(Keyed { key, item }));
+        }
+    }
+
+    Ok(heap.into_iter().map(|Reverse(keyed)| keyed.item).collect())
+}
+
+struct Keyed<T> {
+    key: f64,
+    item: T,
+}
+
+impl<T> PartialEq for Keyed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<T> Eq for Keyed<T> {}
+
+impl<T> PartialOrd for Keyed<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Keyed<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.partial_cmp(&other.key).unwrap_or(Ordering::Equal)
+    }
+}
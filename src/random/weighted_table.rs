@@ -0,0 +1,98 @@
+#![allow(dead_code)]
+
+use rand::Rng;
+
+use super::weighted_error::{validate_weights, WeightedError};
+
+/// A precomputed table for drawing weighted random items in constant time, using
+/// Vose's alias method. Building the table is O(n); each draw is O(1), which pays off
+/// over [`weighted_choice`](crate::weighted_choice) when drawing many times from the
+/// same weights, as in load balancing.
+///
+/// ```
+/// use reddish::WeightedTable;
+///
+/// let backends = WeightedTable::new(vec!["us-east", "eu-west"], &[0.9, 0.1]).unwrap();
+/// let backend = backends.choose();
+/// assert!(*backend == "us-east" || *backend == "eu-west");
+/// assert_eq!(backends.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WeightedTable<T> {
+    items: Vec<T>,
+    probability: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl<T> WeightedTable<T> {
+    /// Builds a table from items and their weights.
+    pub fn new(items: Vec<T>, weights: &[f64]) -> Result<Self, WeightedError> {
+        let total = validate_weights(items.len(), weights)?;
+        let n = weights.len();
+
+        let mut scaled: Vec<f64> = weights.iter().map(|w| w * n as f64 / total).collect();
+        let mut probability = vec![0.0; n];
+        let mut alias = vec![0; n];
+
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..n).partition(|&i| scaled[i] < 1.0);
+
+        while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+            small.pop();
+            probability[less] = scaled[less];
+            alias[less] = more;
+
+            scaled[more] = (scaled[more] + scaled[less]) - 1.0;
+            if scaled[more] < 1.0 {
+                large.pop();
+                small.push(more);
+            }
+        }
+
+        // Whatever remains is 1 up to rounding error
+        for index in large.into_iter().chain(small) {
+            probability[index] = 1.0;
+        }
+
+        Ok(WeightedTable {
+            items,
+            probability,
+            alias,
+        })
+    }
+
+    /// Draws an item.
+    pub fn choose(&self) -> &T {
+        self.choose_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Draws an item using the given random number generator.
+    pub fn choose_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> &T {
+        &self.items[self.choose_index_with_rng(rng)]
+    }
+
+    /// Draws the index of an item using the given random number generator.
+    pub fn choose_index_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let column = rng.gen_range(0..self.items.len());
+        if rng.gen::<f64>() < self.probability[column] {
+            column
+        } else {
+            self.alias[column]
+        }
+    }
+
+    /// Returns the number of items in the table.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Always `false`, since a table cannot be built without items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the items in the order they were given.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+}
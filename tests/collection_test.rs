@@ -1,5 +1,7 @@
 extern crate reddish;
 use reddish::{chunk, flatten, group_by, unique, partition, zip, count_by};
+use reddish::{flatten_deep, flatten_depth, flat_map, unflatten, shape_of, Nested};
+use reddish::{windows, paginate, split_into, chunk_by_weight};
 
 #[test]
 fn test_chunk() {
@@ -212,3 +214,268 @@ fn test_count_by_single_group() {
     assert_eq!(result.get(&0), Some(&4));
     assert_eq!(result.get(&1), None);
 }
+
+#[test]
+fn test_flatten_deep() {
+    let nested = vec![vec![vec![1, 2], vec![3]], vec![vec![], vec![4, 5]]];
+    let result = flatten_deep(&nested);
+    assert_eq!(result, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_flatten_deep_options() {
+    let nested = vec![Some(vec![1, 2]), None, Some(vec![3])];
+    let result = flatten_deep(nested);
+    assert_eq!(result, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_flatten_deep_strings() {
+    let nested = vec![vec!["a".to_string()], vec!["b".to_string(), "c".to_string()]];
+    let result = flatten_deep(&nested);
+    assert_eq!(result, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_flatten_deep_iterator() {
+    let result = flatten_deep((1..4).map(|n| vec![n; n as usize]));
+    assert_eq!(result, vec![1, 2, 2, 3, 3, 3]);
+}
+
+#[test]
+fn test_flatten_deep_nested_enum() {
+    let nested = vec![
+        Nested::Value(1),
+        Nested::List(vec![Nested::Value(2), Nested::List(vec![Nested::Value(3)])]),
+    ];
+    let result = flatten_deep(&nested);
+    assert_eq!(result, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_flatten_deep_empty() {
+    let nested: Vec<Vec<Vec<i32>>> = vec![];
+    assert_eq!(flatten_deep(&nested), Vec::<i32>::new());
+}
+
+#[test]
+fn test_flatten_depth() {
+    use Nested::{List, Value};
+
+    let nested = vec![Value(1), List(vec![Value(2), List(vec![Value(3), List(vec![Value(4)])])])];
+
+    assert_eq!(flatten_depth(&nested, 0), nested);
+    assert_eq!(
+        flatten_depth(&nested, 1),
+        vec![Value(1), Value(2), List(vec![Value(3), List(vec![Value(4)])])]
+    );
+    assert_eq!(
+        flatten_depth(&nested, 2),
+        vec![Value(1), Value(2), Value(3), List(vec![Value(4)])]
+    );
+    assert_eq!(
+        flatten_depth(&nested, 10),
+        vec![Value(1), Value(2), Value(3), Value(4)]
+    );
+}
+
+#[test]
+fn test_flatten_depth_plain_vecs() {
+    use Nested::{List, Value};
+
+    let nested = vec![vec![vec![1, 2], vec![3]], vec![], vec![vec![4]]];
+    assert_eq!(flatten_depth(&nested, 0), vec![
+        List(vec![List(vec![Value(1), Value(2)]), List(vec![Value(3)])]),
+        List(vec![]),
+        List(vec![List(vec![Value(4)])]),
+    ]);
+    assert_eq!(
+        flatten_depth(&nested, 1),
+        vec![List(vec![Value(1), Value(2)]), List(vec![Value(3)]), List(vec![Value(4)])]
+    );
+    assert_eq!(flatten_depth(&nested, 2), vec![Value(1), Value(2), Value(3), Value(4)]);
+    assert_eq!(flatten_depth(&nested, 5), flatten_depth(&nested, 2));
+}
+
+#[test]
+fn test_flatten_depth_options_and_iterators() {
+    use Nested::{List, Value};
+
+    // Each Option is one level, like Iterator::flatten
+    let nested = vec![Some(vec![1, 2]), None, Some(vec![3])];
+    assert_eq!(flatten_depth(&nested, 1), vec![List(vec![Value(1), Value(2)]), List(vec![Value(3)])]);
+    assert_eq!(flatten_depth(nested, 2), vec![Value(1), Value(2), Value(3)]);
+
+    let result = flatten_depth((1..4).map(|n| vec![vec![n]; n as usize]), 2);
+    assert_eq!(result, vec![Value(1), Value(2), Value(2), Value(3), Value(3), Value(3)]);
+
+    let words = vec![vec!["a", "b"], vec!["c"]];
+    assert_eq!(flatten_depth(&words, 1), vec![Value("a"), Value("b"), Value("c")]);
+}
+
+#[test]
+fn test_nested_depth() {
+    use Nested::{List, Value};
+
+    assert_eq!(Value(1).depth(), 0);
+    assert_eq!(List(vec![Value(1), List(vec![List(vec![Value(2)])])]).depth(), 3);
+}
+
+#[test]
+fn test_flat_map() {
+    let numbers = vec![1, 2, 3];
+    let result = flat_map(&numbers, |&n| vec![n; n]);
+    assert_eq!(result, vec![1, 2, 2, 3, 3, 3]);
+}
+
+#[test]
+fn test_flat_map_options() {
+    let words = vec!["1", "two", "3"];
+    let result = flat_map(&words, |s| s.parse::<i32>().ok());
+    assert_eq!(result, vec![1, 3]);
+}
+
+#[test]
+fn test_unflatten() {
+    let flat = vec![1, 2, 3, 4, 5];
+    let result = unflatten(&flat, &[1, 0, 4]);
+    assert_eq!(result, Some(vec![vec![1], vec![], vec![2, 3, 4, 5]]));
+}
+
+#[test]
+fn test_unflatten_shape_mismatch() {
+    let flat = vec![1, 2, 3];
+    assert_eq!(unflatten(&flat, &[1, 1]), None);
+    assert_eq!(unflatten(&flat, &[4]), None);
+}
+
+#[test]
+fn test_unflatten_shape_overflow() {
+    let flat = vec![1, 2, 3];
+    assert_eq!(unflatten(&flat, &[usize::MAX, 4]), None);
+    assert_eq!(unflatten(&flat, &[usize::MAX, usize::MAX, 5]), None);
+}
+
+#[test]
+fn test_unflatten_roundtrip() {
+    let nested = vec![vec!["a", "b"], vec![], vec!["c"], vec!["d", "e", "f"]];
+    let shape = shape_of(&nested);
+    let flat = flatten(&nested);
+    assert_eq!(unflatten(&flat, &shape), Some(nested));
+}
+
+#[test]
+fn test_windows_sliding() {
+    let vec = vec![1, 2, 3, 4];
+    let result = windows(&vec, 2, 1);
+    assert_eq!(result, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+}
+
+#[test]
+fn test_windows_tumbling() {
+    let vec = vec![1, 2, 3, 4, 5];
+    let result = windows(&vec, 2, 2);
+    assert_eq!(result, vec![vec![1, 2], vec![3, 4]]);
+}
+
+#[test]
+fn test_windows_edge_cases() {
+    let vec = vec![1, 2, 3];
+    assert_eq!(windows(&vec, 0, 1), Vec::<Vec<i32>>::new());
+    assert_eq!(windows(&vec, 1, 0), Vec::<Vec<i32>>::new());
+    assert_eq!(windows(&vec, 4, 1), Vec::<Vec<i32>>::new());
+    assert_eq!(windows(&vec, 3, 5), vec![vec![1, 2, 3]]);
+}
+
+#[test]
+fn test_paginate() {
+    let vec: Vec<i32> = (1..=10).collect();
+
+    let first = paginate(&vec, 1, 3).unwrap();
+    assert_eq!(first.items, vec![1, 2, 3]);
+    assert_eq!(first.total_items, 10);
+    assert_eq!(first.total_pages, 4);
+    assert!(first.has_next());
+    assert!(!first.has_prev());
+
+    let last = paginate(&vec, 4, 3).unwrap();
+    assert_eq!(last.items, vec![10]);
+    assert!(!last.has_next());
+    assert!(last.has_prev());
+}
+
+#[test]
+fn test_paginate_out_of_range() {
+    let vec = vec![1, 2, 3];
+    let page = paginate(&vec, 5, 2).unwrap();
+    assert!(page.items.is_empty());
+    assert_eq!(page.total_pages, 2);
+    assert!(!page.has_next());
+}
+
+#[test]
+fn test_paginate_invalid() {
+    let vec = vec![1, 2, 3];
+    assert!(paginate(&vec, 0, 2).is_none());
+    assert!(paginate(&vec, 1, 0).is_none());
+}
+
+#[test]
+fn test_paginate_empty() {
+    let vec: Vec<i32> = vec![];
+    let page = paginate(&vec, 1, 10).unwrap();
+    assert_eq!(page.total_pages, 0);
+    assert!(page.items.is_empty());
+    assert!(!page.has_next());
+    assert!(!page.has_prev());
+}
+
+#[test]
+fn test_paginate_large_per_page() {
+    let vec = vec![1, 2];
+    let page = paginate(&vec, 1, usize::MAX).unwrap();
+    assert_eq!(page.items, vec![1, 2]);
+    assert_eq!(page.total_pages, 1);
+    assert!(!page.has_next());
+
+    let page = paginate(&vec, usize::MAX, usize::MAX).unwrap();
+    assert!(page.items.is_empty());
+}
+
+#[test]
+fn test_split_into() {
+    let vec: Vec<i32> = (1..=10).collect();
+    let result = split_into(&vec, 4);
+    assert_eq!(result, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8], vec![9, 10]]);
+}
+
+#[test]
+fn test_split_into_edge_cases() {
+    let vec = vec![1, 2, 3];
+    assert_eq!(split_into(&vec, 0), Vec::<Vec<i32>>::new());
+    assert_eq!(split_into(&vec, 1), vec![vec![1, 2, 3]]);
+
+    let empty: Vec<i32> = vec![];
+    assert_eq!(split_into(&empty, 2), vec![Vec::<i32>::new(), vec![]]);
+}
+
+#[test]
+fn test_chunk_by_weight() {
+    let sizes = vec![3, 4, 2, 5, 1];
+    let result = chunk_by_weight(&sizes, 6, |&n| n);
+    assert_eq!(result, vec![vec![3], vec![4, 2], vec![5, 1]]);
+}
+
+#[test]
+fn test_chunk_by_weight_oversized_item() {
+    let sizes = vec![1, 10, 1];
+    let result = chunk_by_weight(&sizes, 5, |&n| n);
+    assert_eq!(result, vec![vec![1], vec![10], vec![1]]);
+}
+
+#[test]
+fn test_chunk_by_weight_empty() {
+    let vec: Vec<usize> = vec![];
+    let result = chunk_by_weight(&vec, 5, |&n| n);
+    assert_eq!(result, Vec::<Vec<usize>>::new());
+}
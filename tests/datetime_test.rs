@@ -2,9 +2,11 @@ extern crate reddish;
 use reddish::{
     format_duration, time_ago, is_weekend, days_between, add_days,
     start_of_week, end_of_month, parse_date, format_date,
-    format_date_human, format_date_iso
+    format_date_human, format_date_iso, start_of_day, end_of_day, parse_timezone, to_timezone, parse_date_in,
+    start_of, end_of, start_of_next, is_same_period, Period, add_months, add_years, add_weeks, add_hours, add, diff_in, TimeUnit,
+    days_in_month, is_leap_year, day_of_year, iso_week, BusinessCalendar, Holiday, HolidayRule, Observance, HolidayParseError, easter_sunday
 };
-use chrono::{Utc, TimeZone, Duration, Weekday, Datelike, Timelike};
+use chrono::{DateTime, Utc, TimeZone, Duration, Weekday, Datelike, Timelike, FixedOffset};
 
 #[test]
 fn test_format_duration() {
@@ -333,3 +335,617 @@ fn test_duration_edge_cases() {
     assert_eq!(format_duration(3600), "1h");
     assert_eq!(format_duration(3661), "1h 1m 1s");
 }
+
+#[test]
+fn test_helpers_use_local_calendar() {
+    let sao_paulo = parse_timezone("America/Sao_Paulo").unwrap();
+    // Saturday 22:00 in São Paulo is Sunday 01:00 UTC
+    let saturday_night = sao_paulo.with_ymd_and_hms(2023, 12, 2, 22, 0, 0).unwrap();
+    assert_eq!(saturday_night.weekday(), Weekday::Sat);
+    assert_eq!(saturday_night.with_timezone(&Utc).weekday(), Weekday::Sun);
+    assert!(is_weekend(&saturday_night));
+
+    // Sunday 22:00 in São Paulo is Monday UTC, but the local week has not started yet
+    let sunday_night = sao_paulo.with_ymd_and_hms(2023, 12, 3, 22, 0, 0).unwrap();
+    assert_eq!(start_of_week(&sunday_night), sao_paulo.with_ymd_and_hms(2023, 11, 27, 0, 0, 0).unwrap());
+
+    let new_years_eve = sao_paulo.with_ymd_and_hms(2023, 12, 31, 23, 0, 0).unwrap();
+    assert_eq!(end_of_month(&new_years_eve), sao_paulo.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() - Duration::nanoseconds(1));
+    assert_eq!(format_date_human(&new_years_eve), "December 31, 2023 at 11:00 PM");
+    assert_eq!(format_date(&new_years_eve, "%Y-%m-%d %H:%M %Z"), "2023-12-31 23:00 -03");
+}
+
+#[test]
+fn test_helpers_with_fixed_offsets() {
+    let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    let date = tokyo.with_ymd_and_hms(2024, 2, 29, 8, 0, 0).unwrap();
+    assert_eq!(start_of_day(&date), tokyo.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap());
+    assert_eq!(end_of_day(&date), tokyo.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap() - Duration::nanoseconds(1));
+    assert_eq!(end_of_month(&date).day(), 29);
+    assert_eq!(format_date_iso(&date), "2024-02-29T08:00:00+09:00");
+    assert_eq!(add_days(&date, 1).day(), 1);
+}
+
+#[test]
+fn test_day_boundaries_across_dst() {
+    let new_york = parse_timezone("America/New_York").unwrap();
+
+    // Spring forward: 10 March 2024 lasts 23 hours
+    let spring = new_york.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+    let start = start_of_day(&spring);
+    let end = end_of_day(&spring);
+    assert_eq!(start.hour(), 0);
+    assert_eq!((end - start).num_seconds() + 1, 23 * 3600);
+
+    // Fall back: 3 November 2024 lasts 25 hours
+    let fall = new_york.with_ymd_and_hms(2024, 11, 3, 12, 0, 0).unwrap();
+    assert_eq!((end_of_day(&fall) - start_of_day(&fall)).num_seconds() + 1, 25 * 3600);
+
+    // Midnight itself is skipped in São Paulo on 4 November 2018
+    let sao_paulo = parse_timezone("America/Sao_Paulo").unwrap();
+    let skipped = sao_paulo.with_ymd_and_hms(2018, 11, 4, 15, 0, 0).unwrap();
+    let start = start_of_day(&skipped);
+    assert_eq!((start.hour(), start.minute()), (1, 0));
+    assert_eq!(start.with_timezone(&Utc), Utc.with_ymd_and_hms(2018, 11, 4, 3, 0, 0).unwrap());
+}
+
+#[test]
+fn test_add_days_keeps_wall_clock_time() {
+    let london = parse_timezone("Europe/London").unwrap();
+    let before = london.with_ymd_and_hms(2024, 3, 30, 1, 30, 0).unwrap();
+
+    let after = add_days(&before, 1);
+    assert_eq!((after.day(), after.hour(), after.minute()), (31, 2, 30));
+    // 01:30 on 31 March does not exist in London, so it moves past the gap
+    assert_eq!(after - before, Duration::hours(24));
+
+    let week_later = add_days(&before, 7);
+    assert_eq!((week_later.day(), week_later.hour()), (6, 1));
+    assert_eq!(week_later - before, Duration::hours(7 * 24 - 1));
+    assert_eq!(add_days(&week_later, -7), before);
+}
+
+#[test]
+fn test_timezone_conversion() {
+    assert!(parse_timezone("Europe/Lisbon").is_some());
+    assert!(parse_timezone("UTC").is_some());
+    assert!(parse_timezone("Nowhere/Special").is_none());
+
+    let utc = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+    let sydney = to_timezone(&utc, &parse_timezone("Australia/Sydney").unwrap());
+    assert_eq!(sydney.hour(), 22);
+    assert_eq!(sydney, utc);
+    assert_eq!(to_timezone(&sydney, &Utc), utc);
+    assert_eq!(days_between(&sydney, &utc), 0);
+}
+
+#[test]
+fn test_parse_date_in() {
+    let berlin = parse_timezone("Europe/Berlin").unwrap();
+    let summer = parse_date_in("2024-07-01 09:00:00", &berlin).unwrap();
+    assert_eq!(summer.with_timezone(&Utc).hour(), 7);
+
+    let winter = parse_date_in("2024-01-15", &berlin).unwrap();
+    assert_eq!(winter.with_timezone(&Utc), Utc.with_ymd_and_hms(2024, 1, 14, 23, 0, 0).unwrap());
+
+    let explicit = parse_date_in("2024-01-15T10:00:00+05:00", &berlin).unwrap();
+    assert_eq!(explicit.hour(), 6);
+    assert_eq!(parse_date_in("2024-01-15T10:00:00Z", &berlin).unwrap().hour(), 11);
+    assert!(parse_date_in("not a date", &berlin).is_none());
+}
+
+#[test]
+fn test_start_of_each_period() {
+    // Thursday, 15 August 2024
+    let date = Utc.with_ymd_and_hms(2024, 8, 15, 14, 37, 52).unwrap() + Duration::milliseconds(250);
+    let at = |y, mo, d, h, mi| Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap();
+
+    assert_eq!(start_of(&date, Period::Minute), at(2024, 8, 15, 14, 37));
+    assert_eq!(start_of(&date, Period::Hour), at(2024, 8, 15, 14, 0));
+    assert_eq!(start_of(&date, Period::Day), at(2024, 8, 15, 0, 0));
+    assert_eq!(start_of(&date, Period::IsoWeek), at(2024, 8, 12, 0, 0));
+    assert_eq!(start_of(&date, Period::Week(Weekday::Mon)), at(2024, 8, 12, 0, 0));
+    assert_eq!(start_of(&date, Period::Week(Weekday::Sun)), at(2024, 8, 11, 0, 0));
+    assert_eq!(start_of(&date, Period::Week(Weekday::Thu)), at(2024, 8, 15, 0, 0));
+    assert_eq!(start_of(&date, Period::Week(Weekday::Fri)), at(2024, 8, 9, 0, 0));
+    assert_eq!(start_of(&date, Period::Month), at(2024, 8, 1, 0, 0));
+    assert_eq!(start_of(&date, Period::Quarter), at(2024, 7, 1, 0, 0));
+    assert_eq!(start_of(&date, Period::HalfYear), at(2024, 7, 1, 0, 0));
+    assert_eq!(start_of(&date, Period::Year), at(2024, 1, 1, 0, 0));
+}
+
+#[test]
+fn test_start_of_next_and_end_of() {
+    let date = Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap();
+    let new_year = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    for period in [Period::Minute, Period::Hour, Period::Day, Period::Month, Period::Quarter, Period::HalfYear, Period::Year] {
+        assert_eq!(start_of_next(&date, period), Some(new_year), "{:?}", period);
+        assert_eq!(end_of(&date, period), Some(new_year - Duration::nanoseconds(1)), "{:?}", period);
+    }
+    // 31 December 2024 is a Tuesday
+    assert_eq!(start_of_next(&date, Period::IsoWeek), Utc.with_ymd_and_hms(2025, 1, 6, 0, 0, 0).single());
+    assert_eq!(start_of_next(&date, Period::Week(Weekday::Sun)), Utc.with_ymd_and_hms(2025, 1, 5, 0, 0, 0).single());
+
+    let february = Utc.with_ymd_and_hms(2023, 2, 14, 0, 0, 0).unwrap();
+    assert_eq!(end_of(&february, Period::Month).unwrap().day(), 28);
+    assert_eq!(Some(end_of_month(&february)), end_of(&february, Period::Month));
+    assert_eq!(Some(end_of_day(&february)), end_of(&february, Period::Day));
+
+    let last = DateTime::<Utc>::MAX_UTC;
+    for period in [Period::Minute, Period::Hour, Period::Day, Period::IsoWeek, Period::Month, Period::Year] {
+        assert_eq!(start_of_next(&last, period), None, "{:?}", period);
+        assert_eq!(end_of(&last, period), None, "{:?}", period);
+    }
+}
+
+#[test]
+fn test_every_instant_lies_within_its_period() {
+    let date = Utc.with_ymd_and_hms(2024, 5, 31, 23, 59, 59).unwrap() + Duration::nanoseconds(999_999_999);
+    for period in [Period::Minute, Period::Hour, Period::Day, Period::IsoWeek, Period::Week(Weekday::Sat), Period::Month, Period::Quarter, Period::HalfYear, Period::Year] {
+        let start = start_of(&date, period);
+        let end = end_of(&date, period).unwrap();
+        assert!(start <= date && date <= end, "{:?}", period);
+        assert_eq!(Some(end + Duration::nanoseconds(1)), start_of_next(&date, period));
+    }
+}
+
+#[test]
+fn test_periods_across_dst() {
+    let new_york = parse_timezone("America/New_York").unwrap();
+    // 01:30 happens twice on 3 November 2024; take the second one (EST)
+    let repeated = new_york.with_ymd_and_hms(2024, 11, 3, 1, 30, 0).latest().unwrap();
+    let hour = start_of(&repeated, Period::Hour);
+    assert_eq!(repeated - hour, Duration::minutes(30));
+    assert_eq!(start_of_next(&repeated, Period::Hour).unwrap() - repeated, Duration::minutes(30));
+
+    let day = start_of(&repeated, Period::Day);
+    assert_eq!(start_of_next(&repeated, Period::Day).unwrap() - day, Duration::hours(25));
+
+    // The March 2024 month is an hour shorter than 31 days
+    let march = new_york.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+    let month = start_of_next(&march, Period::Month).unwrap() - start_of(&march, Period::Month);
+    assert_eq!(month, Duration::hours(31 * 24 - 1));
+}
+
+#[test]
+fn test_is_same_period() {
+    let a = Utc.with_ymd_and_hms(2024, 3, 31, 23, 0, 0).unwrap();
+    let b = Utc.with_ymd_and_hms(2024, 4, 1, 1, 0, 0).unwrap();
+    assert!(!is_same_period(&a, &b, Period::Day));
+    assert!(!is_same_period(&a, &b, Period::Month));
+    assert!(!is_same_period(&a, &b, Period::Quarter));
+    assert!(is_same_period(&a, &b, Period::HalfYear));
+    assert!(is_same_period(&a, &b, Period::Year));
+    assert!(is_same_period(&a, &a, Period::Minute));
+
+    // The second datetime is read in the first one's zone: both are 1 April in Tokyo
+    let tokyo = parse_timezone("Asia/Tokyo").unwrap();
+    let in_tokyo = a.with_timezone(&tokyo);
+    assert!(is_same_period(&in_tokyo, &b, Period::Day));
+    assert!(!is_same_period(&a, &b.with_timezone(&tokyo), Period::Day));
+}
+
+#[test]
+fn test_add_months_clamps_to_month_end() {
+    let jan_31 = Utc.with_ymd_and_hms(2023, 1, 31, 8, 15, 0).unwrap();
+    assert_eq!(add_months(&jan_31, 1).unwrap(), Utc.with_ymd_and_hms(2023, 2, 28, 8, 15, 0).unwrap());
+    assert_eq!(add_months(&jan_31, 2).unwrap(), Utc.with_ymd_and_hms(2023, 3, 31, 8, 15, 0).unwrap());
+    assert_eq!(add_months(&jan_31, 13).unwrap(), Utc.with_ymd_and_hms(2024, 2, 29, 8, 15, 0).unwrap());
+    assert_eq!(add_months(&jan_31, -2).unwrap(), Utc.with_ymd_and_hms(2022, 11, 30, 8, 15, 0).unwrap());
+    assert_eq!(add_months(&jan_31, -13).unwrap(), Utc.with_ymd_and_hms(2021, 12, 31, 8, 15, 0).unwrap());
+    assert_eq!(add_months(&jan_31, 0), Some(jan_31));
+
+    let leap_day = Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap();
+    assert_eq!(add_years(&leap_day, 1).unwrap().day(), 28);
+    assert_eq!(add_years(&leap_day, -4).unwrap().day(), 29);
+    assert_eq!(add_years(&leap_day, 76).unwrap().day(), 28);
+}
+
+#[test]
+fn test_add_months_keeps_local_time() {
+    let london = parse_timezone("Europe/London").unwrap();
+    let winter = london.with_ymd_and_hms(2024, 2, 15, 9, 0, 0).unwrap();
+    let summer = add_months(&winter, 4).unwrap();
+    assert_eq!((summer.month(), summer.hour()), (6, 9));
+    assert_eq!(summer.with_timezone(&Utc).hour(), 8);
+}
+
+#[test]
+fn test_add_weeks_hours_and_units() {
+    let new_york = parse_timezone("America/New_York").unwrap();
+    let before_dst = new_york.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap();
+
+    assert_eq!(add_weeks(&before_dst, 1).unwrap().hour(), 12);
+    assert_eq!(add_weeks(&before_dst, -1).unwrap().day(), 2);
+    // Exact hours move the wall clock when the offset changes
+    assert_eq!(add_hours(&before_dst, 24).unwrap().hour(), 13);
+    assert_eq!(add(&before_dst, 24, TimeUnit::Hour), add_hours(&before_dst, 24));
+    assert_eq!(add(&before_dst, 1, TimeUnit::Day).unwrap().hour(), 12);
+
+    let date = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(add(&date, 61, TimeUnit::Second).unwrap(), Utc.with_ymd_and_hms(2024, 1, 1, 0, 1, 1).unwrap());
+    assert_eq!(add(&date, 2, TimeUnit::Week).unwrap(), Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap());
+    assert_eq!(add(&date, 5, TimeUnit::Month).unwrap(), Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap());
+    assert_eq!(add(&date, -2, TimeUnit::Quarter).unwrap(), Utc.with_ymd_and_hms(2023, 7, 1, 0, 0, 0).unwrap());
+    assert_eq!(add(&date, 10, TimeUnit::Year).unwrap(), Utc.with_ymd_and_hms(2034, 1, 1, 0, 0, 0).unwrap());
+}
+
+#[test]
+fn test_calendar_arithmetic_out_of_range() {
+    let date = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    for &unit in &[
+        TimeUnit::Second, TimeUnit::Minute, TimeUnit::Hour, TimeUnit::Day,
+        TimeUnit::Week, TimeUnit::Month, TimeUnit::Quarter, TimeUnit::Year,
+    ] {
+        assert_eq!(add(&date, i64::MAX, unit), None, "{:?}", unit);
+        assert_eq!(add(&date, i64::MIN, unit), None, "{:?}", unit);
+    }
+    assert_eq!(add_months(&date, i64::MIN), None);
+    assert_eq!(add_years(&date, 300_000), None);
+    assert_eq!(add_weeks(&date, i64::MAX / 7), None);
+    assert_eq!(add_hours(&date, i64::MAX), None);
+
+    // The last representable year still works
+    let max_year = chrono::NaiveDate::MAX.year() as i64;
+    assert_eq!(add_years(&date, max_year - 2024).unwrap().year() as i64, max_year);
+    assert_eq!(add_years(&date, max_year - 2023), None);
+
+    // diff_in stays finite near the edges of the range
+    let far = add_years(&date, 200_000).unwrap();
+    assert_eq!(diff_in(&date, &far, TimeUnit::Year), 200_000);
+}
+
+#[test]
+#[should_panic(expected = "date out of range")]
+fn test_add_days_out_of_range_panics() {
+    add_days(&Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(), i64::MAX);
+}
+
+#[test]
+fn test_diff_in_calendar_units() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 31, 12, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2025, 3, 30, 12, 0, 0).unwrap();
+    assert_eq!(diff_in(&start, &end, TimeUnit::Month), 13);
+    assert_eq!(diff_in(&start, &end, TimeUnit::Quarter), 4);
+    assert_eq!(diff_in(&start, &end, TimeUnit::Year), 1);
+    assert_eq!(diff_in(&end, &start, TimeUnit::Month), -13);
+
+    let mar_15 = Utc.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap();
+    assert_eq!(diff_in(&mar_15, &Utc.with_ymd_and_hms(2024, 4, 15, 11, 59, 59).unwrap(), TimeUnit::Month), 0);
+    assert_eq!(diff_in(&mar_15, &Utc.with_ymd_and_hms(2024, 4, 15, 12, 0, 0).unwrap(), TimeUnit::Month), 1);
+
+    assert_eq!(diff_in(&mar_15, &Utc.with_ymd_and_hms(2024, 3, 29, 11, 0, 0).unwrap(), TimeUnit::Day), 13);
+    assert_eq!(diff_in(&mar_15, &Utc.with_ymd_and_hms(2024, 3, 29, 11, 0, 0).unwrap(), TimeUnit::Week), 1);
+    assert_eq!(diff_in(&mar_15, &Utc.with_ymd_and_hms(2024, 3, 15, 14, 30, 0).unwrap(), TimeUnit::Minute), 150);
+    assert_eq!(diff_in(&mar_15, &Utc.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap(), TimeUnit::Hour), -1);
+    assert_eq!(diff_in(&mar_15, &mar_15, TimeUnit::Second), 0);
+}
+
+#[test]
+fn test_diff_in_days_across_dst() {
+    let new_york = parse_timezone("America/New_York").unwrap();
+    let before = new_york.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap();
+    let after = new_york.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+    // Only 23 hours apart, but one calendar day
+    assert_eq!(days_between(&before, &after), 0);
+    assert_eq!(diff_in(&before, &after, TimeUnit::Day), 1);
+    assert_eq!(diff_in(&after, &before, TimeUnit::Day), -1);
+}
+
+#[test]
+fn test_calendar_accessors() {
+    assert!(is_leap_year(2024) && is_leap_year(2000) && is_leap_year(1600));
+    assert!(!is_leap_year(2100) && !is_leap_year(2023));
+
+    let months = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    for (index, &days) in months.iter().enumerate() {
+        let date = Utc.with_ymd_and_hms(2024, index as u32 + 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(days_in_month(&date), days);
+    }
+    assert_eq!(days_in_month(&Utc.with_ymd_and_hms(2100, 2, 1, 0, 0, 0).unwrap()), 28);
+
+    assert_eq!(day_of_year(&Utc.with_ymd_and_hms(2023, 12, 31, 0, 0, 0).unwrap()), 365);
+    assert_eq!(day_of_year(&Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()), 61);
+
+    assert_eq!(iso_week(&Utc.with_ymd_and_hms(2021, 1, 3, 0, 0, 0).unwrap()), (2020, 53));
+    assert_eq!(iso_week(&Utc.with_ymd_and_hms(2021, 1, 4, 0, 0, 0).unwrap()), (2021, 1));
+    assert_eq!(iso_week(&Utc.with_ymd_and_hms(2026, 12, 31, 0, 0, 0).unwrap()), (2026, 53));
+
+    // Accessors read the local calendar
+    let tokyo = parse_timezone("Asia/Tokyo").unwrap();
+    let late_utc = Utc.with_ymd_and_hms(2023, 12, 31, 20, 0, 0).unwrap();
+    assert_eq!(day_of_year(&late_utc.with_timezone(&tokyo)), 1);
+}
+
+fn holiday_dates(calendar: &BusinessCalendar, year: i32) -> Vec<chrono::NaiveDate> {
+    calendar.holidays_in(year).into_iter().map(|(date, _)| date).collect()
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> chrono::NaiveDate {
+    chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn test_easter_sunday() {
+    let known = [(1818, 3, 22), (1943, 4, 25), (2000, 4, 23), (2019, 4, 21), (2024, 3, 31), (2038, 4, 25)];
+    for &(year, month, day) in &known {
+        assert_eq!(easter_sunday(year), Some(ymd(year, month, day)));
+    }
+}
+
+#[test]
+fn test_holiday_rules() {
+    let last_monday_of_may = HolidayRule::NthWeekday { month: 5, weekday: Weekday::Mon, n: -1 };
+    assert_eq!(last_monday_of_may.date_in(2024), Some(ymd(2024, 5, 27)));
+    assert_eq!(last_monday_of_may.date_in(2021), Some(ymd(2021, 5, 31)));
+
+    let fifth_friday = HolidayRule::NthWeekday { month: 2, weekday: Weekday::Fri, n: 5 };
+    assert_eq!(fifth_friday.date_in(2024), None);
+    assert_eq!(HolidayRule::NthWeekday { month: 2, weekday: Weekday::Thu, n: 5 }.date_in(2024), Some(ymd(2024, 2, 29)));
+    assert_eq!(HolidayRule::NthWeekday { month: 2, weekday: Weekday::Thu, n: 0 }.date_in(2024), None);
+
+    assert_eq!(HolidayRule::Fixed { month: 2, day: 29 }.date_in(2023), None);
+    assert_eq!(HolidayRule::Easter(60).date_in(2024), Some(ymd(2024, 5, 30)));
+    assert_eq!(HolidayRule::Easter(i64::MAX).date_in(2024), None);
+    assert_eq!(HolidayRule::Easter(-1_000_000_000).date_in(2024), None);
+    assert_eq!(HolidayRule::Date(ymd(2025, 1, 9)).date_in(2025), Some(ymd(2025, 1, 9)));
+    assert_eq!(HolidayRule::Date(ymd(2025, 1, 9)).date_in(2026), None);
+
+    let limited = Holiday::new("Old", HolidayRule::Fixed { month: 3, day: 1 }).with_years(Some(2000), Some(2010));
+    assert_eq!(limited.date_in(1999), None);
+    assert_eq!(limited.date_in(2010), Some(ymd(2010, 3, 1)));
+    assert_eq!(limited.date_in(2011), None);
+}
+
+#[test]
+fn test_us_holidays() {
+    let us = BusinessCalendar::for_country("US").unwrap();
+    assert_eq!(
+        holiday_dates(&us, 2024),
+        vec![
+            ymd(2024, 1, 1), ymd(2024, 1, 15), ymd(2024, 2, 19), ymd(2024, 5, 27), ymd(2024, 6, 19), ymd(2024, 7, 4),
+            ymd(2024, 9, 2), ymd(2024, 10, 14), ymd(2024, 11, 11), ymd(2024, 11, 28), ymd(2024, 12, 25),
+        ]
+    );
+
+    // New Year's Day 2022 was a Saturday, observed on Friday 31 December 2021
+    assert!(holiday_dates(&us, 2021).contains(&ymd(2021, 12, 31)));
+    assert!(!holiday_dates(&us, 2022).contains(&ymd(2022, 1, 1)));
+    // Independence Day 2021 was a Sunday, observed on Monday
+    assert!(holiday_dates(&us, 2021).contains(&ymd(2021, 7, 5)));
+    // Juneteenth only since 2021
+    assert!(!holiday_dates(&us, 2020).iter().any(|date| date.month() == 6));
+}
+
+#[test]
+fn test_uk_substitute_days() {
+    let uk = BusinessCalendar::united_kingdom();
+    // Christmas and Boxing Day 2021 were Saturday and Sunday
+    let days = holiday_dates(&uk, 2021);
+    assert!(days.contains(&ymd(2021, 12, 27)) && days.contains(&ymd(2021, 12, 28)));
+    assert!(!days.contains(&ymd(2021, 12, 25)));
+    assert_eq!(
+        holiday_dates(&uk, 2024),
+        vec![
+            ymd(2024, 1, 1), ymd(2024, 3, 29), ymd(2024, 4, 1), ymd(2024, 5, 6), ymd(2024, 5, 27), ymd(2024, 8, 26),
+            ymd(2024, 12, 25), ymd(2024, 12, 26),
+        ]
+    );
+    // The 2022 Spring bank holiday moved to 2 June for the Platinum Jubilee
+    assert_eq!(
+        holiday_dates(&uk, 2022),
+        vec![
+            ymd(2022, 1, 3), ymd(2022, 4, 15), ymd(2022, 4, 18), ymd(2022, 5, 2), ymd(2022, 6, 2), ymd(2022, 6, 3),
+            ymd(2022, 8, 29), ymd(2022, 9, 19), ymd(2022, 12, 26), ymd(2022, 12, 27),
+        ]
+    );
+    // The 2020 Early May bank holiday moved to Friday 8 May for VE Day
+    assert!(holiday_dates(&uk, 2020).contains(&ymd(2020, 5, 8)));
+    assert!(!holiday_dates(&uk, 2020).contains(&ymd(2020, 5, 4)));
+}
+
+#[test]
+fn test_br_holidays() {
+    let br = BusinessCalendar::for_country("br").unwrap();
+    let days = holiday_dates(&br, 2024);
+    assert!(days.contains(&ymd(2024, 2, 12)) && days.contains(&ymd(2024, 2, 13)));
+    assert!(days.contains(&ymd(2024, 5, 30)));
+    assert!(days.contains(&ymd(2024, 11, 20)));
+    assert!(!holiday_dates(&br, 2023).contains(&ymd(2023, 11, 20)));
+    // No observed-day rules: Tiradentes on a Sunday is simply lost
+    assert!(days.contains(&ymd(2024, 4, 21)));
+    assert!(!days.contains(&ymd(2024, 4, 22)));
+    assert!(BusinessCalendar::for_country("fr").is_none());
+}
+
+#[test]
+fn test_add_business_days() {
+    let us = BusinessCalendar::united_states();
+    // Wednesday 27 November 2024, the day before Thanksgiving
+    let wednesday = Utc.with_ymd_and_hms(2024, 11, 27, 15, 30, 0).unwrap();
+    assert_eq!(us.add_business_days(&wednesday, 1).unwrap(), Utc.with_ymd_and_hms(2024, 11, 29, 15, 30, 0).unwrap());
+    assert_eq!(us.add_business_days(&wednesday, 3).unwrap(), Utc.with_ymd_and_hms(2024, 12, 3, 15, 30, 0).unwrap());
+    assert_eq!(us.add_business_days(&wednesday, 0), Some(wednesday));
+
+    let monday = Utc.with_ymd_and_hms(2024, 12, 2, 15, 30, 0).unwrap();
+    assert_eq!(us.add_business_days(&monday, -1).unwrap(), Utc.with_ymd_and_hms(2024, 11, 29, 15, 30, 0).unwrap());
+    assert_eq!(us.add_business_days(&monday, -2), Some(wednesday));
+
+    // Crosses the new year: Christmas, then New Year's Day
+    let before_christmas = Utc.with_ymd_and_hms(2024, 12, 24, 9, 0, 0).unwrap();
+    assert_eq!(us.add_business_days(&before_christmas, 5).unwrap(), Utc.with_ymd_and_hms(2025, 1, 2, 9, 0, 0).unwrap());
+
+    // Saturday plus one business day is Monday
+    let saturday = Utc.with_ymd_and_hms(2024, 6, 8, 9, 0, 0).unwrap();
+    assert_eq!(BusinessCalendar::new().add_business_days(&saturday, 1).unwrap().day(), 10);
+}
+
+#[test]
+fn test_add_business_days_matches_day_by_day_walk() {
+    let us = BusinessCalendar::united_states();
+    let start = Utc.with_ymd_and_hms(2023, 12, 29, 9, 0, 0).unwrap();
+    for &direction in &[1i64, -1] {
+        let mut date = start;
+        let mut counted = 0;
+        while counted < 600 {
+            date += Duration::days(direction);
+            if us.is_business_day(&date) {
+                counted += 1;
+                assert_eq!(us.add_business_days(&start, counted * direction), Some(date));
+                assert_eq!(us.business_days_between(&start, &date), counted * direction);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_add_business_days_large_counts() {
+    let calendar = BusinessCalendar::new();
+    let monday = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+    // Without holidays every five business days are exactly one week
+    assert_eq!(calendar.add_business_days(&monday, 5_000_000), Some(monday + Duration::weeks(1_000_000)));
+    assert_eq!(calendar.add_business_days(&monday, -5_000_000), Some(monday - Duration::weeks(1_000_000)));
+
+    let uk = BusinessCalendar::united_kingdom();
+    let tuesday = Utc.with_ymd_and_hms(2024, 1, 2, 9, 0, 0).unwrap();
+    let far = uk.add_business_days(&tuesday, 2_000_000).unwrap();
+    assert!(uk.is_business_day(&far));
+    assert_eq!(uk.business_days_between(&tuesday, &far), 2_000_000);
+    assert_eq!(uk.business_days_between(&far, &tuesday), -2_000_000);
+    assert_eq!(uk.add_business_days(&far, -2_000_000), Some(tuesday));
+
+    assert_eq!(calendar.add_business_days(&monday, i64::MAX), None);
+    assert_eq!(calendar.add_business_days(&monday, i64::MIN), None);
+    assert_eq!(uk.add_business_days(&tuesday, 100_000_000), None);
+    let last_day = Utc.from_utc_datetime(&chrono::NaiveDate::MAX.and_hms_opt(9, 0, 0).unwrap());
+    assert_eq!(calendar.next_business_day(&last_day), None);
+}
+
+#[test]
+fn test_business_days_between() {
+    let calendar = BusinessCalendar::new();
+    let monday = Utc.with_ymd_and_hms(2024, 6, 3, 9, 0, 0).unwrap();
+    let friday = Utc.with_ymd_and_hms(2024, 6, 7, 9, 0, 0).unwrap();
+    let saturday = Utc.with_ymd_and_hms(2024, 6, 8, 9, 0, 0).unwrap();
+    let sunday = Utc.with_ymd_and_hms(2024, 6, 9, 9, 0, 0).unwrap();
+
+    assert_eq!(calendar.business_days_between(&monday, &friday), 4);
+    assert_eq!(calendar.business_days_between(&monday, &saturday), 4);
+    assert_eq!(calendar.business_days_between(&saturday, &sunday), 0);
+    assert_eq!(calendar.business_days_between(&monday, &monday), 0);
+    assert_eq!(calendar.business_days_between(&friday, &monday), -4);
+
+    // Inverse of add_business_days between business days
+    let uk = BusinessCalendar::united_kingdom();
+    let start = Utc.with_ymd_and_hms(2024, 3, 20, 9, 0, 0).unwrap();
+    for days in -30..30 {
+        let end = uk.add_business_days(&start, days).unwrap();
+        assert_eq!(uk.business_days_between(&start, &end), days);
+    }
+}
+
+#[test]
+fn test_next_business_day_keeps_local_time() {
+    let calendar = BusinessCalendar::new();
+    let london = parse_timezone("Europe/London").unwrap();
+    // Friday before the clocks went forward on Sunday 31 March 2024
+    let friday = london.with_ymd_and_hms(2024, 3, 29, 18, 0, 0).unwrap();
+    let monday = calendar.next_business_day(&friday).unwrap();
+    assert_eq!((monday.day(), monday.hour()), (1, 18));
+    assert!(calendar.is_business_day(&monday));
+    assert!(calendar.is_weekend_day(&(friday + Duration::days(1))));
+
+    let uk = BusinessCalendar::united_kingdom();
+    assert!(uk.is_holiday(&friday));
+    assert!(!uk.is_business_day(&friday));
+    assert_eq!(uk.next_business_day(&friday).unwrap().day(), 2);
+}
+
+#[test]
+fn test_custom_weekend_and_holidays() {
+    let calendar = BusinessCalendar::new()
+        .with_weekend(&[Weekday::Fri, Weekday::Sat])
+        .with_holiday(Holiday::new("Founding Day", HolidayRule::Fixed { month: 2, day: 22 }).with_observance(Observance::Nearest));
+    assert_eq!(calendar.weekend(), vec![Weekday::Fri, Weekday::Sat]);
+    assert_eq!(calendar.holidays().len(), 1);
+
+    // 22 February 2025 was a Saturday; with a Friday–Saturday weekend the nearest working day is Sunday
+    assert_eq!(holiday_dates(&calendar, 2025), vec![ymd(2025, 2, 23)]);
+    let thursday = Utc.with_ymd_and_hms(2025, 2, 20, 9, 0, 0).unwrap();
+    assert_eq!(calendar.next_business_day(&thursday).unwrap().day(), 24);
+}
+
+#[test]
+#[should_panic]
+fn test_weekend_cannot_cover_the_whole_week() {
+    let every_day = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun];
+    BusinessCalendar::new().with_weekend(&every_day);
+}
+
+#[test]
+fn test_holiday_rules_text_format() {
+    let calendar = BusinessCalendar::from_rules(
+        "# Shop calendar\n\
+         weekend sun\n\
+         \n\
+         01-01 observed next: New Year\n\
+         Second TUE of March: Stocktaking\n\
+         easter: Easter Sunday\n\
+         2024-07-15: Moving day   # one-off\n\
+         08-01 from 2020 until 2022 except 2021: Anniversary\n",
+    )
+    .unwrap();
+    assert_eq!(calendar.weekend(), vec![Weekday::Sun]);
+    assert_eq!(
+        holiday_dates(&calendar, 2024),
+        vec![ymd(2024, 1, 1), ymd(2024, 3, 12), ymd(2024, 3, 31), ymd(2024, 7, 15)]
+    );
+    assert!(holiday_dates(&calendar, 2022).contains(&ymd(2022, 8, 1)));
+    assert!(calendar.holidays_in(2021).iter().all(|(_, holiday)| holiday.name != "Anniversary"));
+    assert!(!holiday_dates(&calendar, 2023).contains(&ymd(2023, 8, 1)));
+    // New Year 2023 was a Sunday, the only weekend day
+    assert!(holiday_dates(&calendar, 2023).contains(&ymd(2023, 1, 2)));
+
+    assert_eq!(BusinessCalendar::from_rules("12-25").unwrap_err(), HolidayParseError::MissingName { line: 1 });
+    assert_eq!(BusinessCalendar::from_rules("\n12-25:  ").unwrap_err(), HolidayParseError::MissingName { line: 2 });
+    assert_eq!(
+        BusinessCalendar::from_rules("02-30: Never").unwrap_err(),
+        HolidayParseError::InvalidRule { line: 1, rule: "02-30".to_string() }
+    );
+    assert_eq!(
+        BusinessCalendar::from_rules("sixth mon of may: Never").unwrap_err(),
+        HolidayParseError::InvalidRule { line: 1, rule: "sixth mon of may".to_string() }
+    );
+    assert_eq!(
+        BusinessCalendar::from_rules("12-25 observed later: Christmas").unwrap_err(),
+        HolidayParseError::InvalidOption { line: 1, option: "observed later".to_string() }
+    );
+    assert_eq!(
+        BusinessCalendar::from_rules("12-25 from: Christmas").unwrap_err(),
+        HolidayParseError::InvalidOption { line: 1, option: "from".to_string() }
+    );
+    assert_eq!(
+        BusinessCalendar::from_rules("12-25 except 2020,x: Christmas").unwrap_err(),
+        HolidayParseError::InvalidOption { line: 1, option: "except 2020,x".to_string() }
+    );
+    assert_eq!(
+        BusinessCalendar::from_rules("weekend sat funday").unwrap_err(),
+        HolidayParseError::InvalidWeekday { line: 1, day: "funday".to_string() }
+    );
+    assert_eq!(
+        BusinessCalendar::from_rules("easter+9999999999999999: Never").unwrap_err(),
+        HolidayParseError::InvalidRule { line: 1, rule: "easter+9999999999999999".to_string() }
+    );
+    assert_eq!(
+        BusinessCalendar::from_rules("easter-366: Never").unwrap_err(),
+        HolidayParseError::InvalidRule { line: 1, rule: "easter-366".to_string() }
+    );
+    assert_eq!(
+        BusinessCalendar::from_rules("12-25: Christmas\nweekend mon tue wed thu fri sat sun").unwrap_err(),
+        HolidayParseError::NoWorkingDays { line: 2 }
+    );
+    assert_eq!(
+        HolidayParseError::InvalidRule { line: 3, rule: "x".to_string() }.to_string(),
+        "line 3: invalid holiday rule 'x'"
+    );
+}
@@ -0,0 +1,183 @@
+#![cfg(feature = "fake")]
+
+extern crate reddish;
+use reddish::{Faker, CardBrand, is_luhn_valid};
+use chrono::{TimeZone, Utc};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[test]
+fn test_faker_is_reproducible() {
+    let mut a = Faker::from_seed(2024);
+    let mut b = Faker::from_seed(2024);
+    for _ in 0..20 {
+        assert_eq!(a.name(), b.name());
+        assert_eq!(a.email(), b.email());
+        assert_eq!(a.phone(), b.phone());
+        assert_eq!(a.address(), b.address());
+        assert_eq!(a.lorem_paragraph(), b.lorem_paragraph());
+        assert_eq!(a.ipv4(), b.ipv4());
+        assert_eq!(a.ipv6(), b.ipv6());
+        assert_eq!(a.credit_card_number(), b.credit_card_number());
+        assert_eq!(a.company(), b.company());
+    }
+
+    let mut c = Faker::from_seed(2025);
+    let names_a: Vec<String> = (0..10).map(|_| a.name()).collect();
+    let names_c: Vec<String> = (0..10).map(|_| c.name()).collect();
+    assert_ne!(names_a, names_c);
+}
+
+#[test]
+fn test_faker_person() {
+    let mut faker = Faker::from_seed(1);
+    for _ in 0..50 {
+        let name = faker.name();
+        let parts: Vec<&str> = name.split(' ').collect();
+        assert_eq!(parts.len(), 2);
+        assert!(parts.iter().all(|part| part.chars().next().unwrap().is_uppercase()));
+
+        let email = faker.email();
+        let (local, domain) = email.split_once('@').unwrap();
+        assert!(local.contains('.'));
+        assert!(local.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.'));
+        assert!(["example.com", "example.net", "example.org"].contains(&domain));
+
+        let phone = faker.phone();
+        assert_eq!(phone.len(), 17);
+        assert_eq!(&phone[..4], "+1 (");
+        assert_eq!(&phone[7..13], ") 555-");
+        assert!(phone[13..].starts_with("01"));
+    }
+}
+
+#[test]
+fn test_faker_address() {
+    let mut faker = Faker::from_seed(2);
+    for _ in 0..50 {
+        let street = faker.street_address();
+        let number: u32 = street.split(' ').next().unwrap().parse().unwrap();
+        assert!((1..10_000).contains(&number));
+
+        let state = faker.state();
+        assert_eq!(state.len(), 2);
+        assert!(state.chars().all(|c| c.is_ascii_uppercase()));
+
+        let zip = faker.zip_code();
+        assert_eq!(zip.len(), 5);
+        assert!(zip.chars().all(|c| c.is_ascii_digit()));
+
+        assert!(!faker.city().is_empty());
+        assert_eq!(faker.address().split(", ").count(), 3);
+    }
+}
+
+#[test]
+fn test_faker_lorem() {
+    let mut faker = Faker::from_seed(3);
+    assert_eq!(faker.lorem_words(0), "");
+    assert_eq!(faker.lorem_words(7).split(' ').count(), 7);
+
+    for _ in 0..20 {
+        let sentence = faker.lorem_sentence();
+        let words = sentence.trim_end_matches('.').split(' ').count();
+        assert!((6..=12).contains(&words));
+        assert!(sentence.ends_with('.'));
+
+        let paragraph = faker.lorem_paragraph();
+        let sentences = paragraph.matches('.').count();
+        assert!((3..=6).contains(&sentences));
+    }
+    assert_eq!(faker.lorem_paragraphs(4).len(), 4);
+}
+
+#[test]
+fn test_faker_ip_addresses() {
+    let mut faker = Faker::from_seed(4);
+    for _ in 0..200 {
+        let v4: Ipv4Addr = faker.ipv4().parse().unwrap();
+        assert!(!v4.is_loopback() && !v4.is_multicast() && !v4.is_unspecified());
+        assert!(v4.octets()[0] >= 1 && v4.octets()[0] < 224);
+        assert!(!v4.is_private() && !v4.is_link_local());
+        assert!(!(v4.octets()[0] == 100 && (64..128).contains(&v4.octets()[1])));
+
+        let v6: Ipv6Addr = faker.ipv6().parse().unwrap();
+        assert_eq!(v6.segments()[0] & 0xe000, 0x2000);
+    }
+}
+
+#[test]
+fn test_faker_credit_cards() {
+    let mut faker = Faker::from_seed(5);
+    for _ in 0..100 {
+        let visa = faker.credit_card(CardBrand::Visa);
+        assert_eq!(visa.len(), 16);
+        assert!(visa.starts_with('4'));
+        assert!(is_luhn_valid(&visa));
+
+        let mastercard = faker.credit_card(CardBrand::Mastercard);
+        assert_eq!(mastercard.len(), 16);
+        assert!(("51".."56").contains(&&mastercard[..2]));
+        assert!(is_luhn_valid(&mastercard));
+
+        let amex = faker.credit_card(CardBrand::Amex);
+        assert_eq!(amex.len(), 15);
+        assert!(amex.starts_with("34") || amex.starts_with("37"));
+        assert!(is_luhn_valid(&amex));
+
+        assert!(is_luhn_valid(&faker.credit_card_number()));
+    }
+}
+
+#[test]
+fn test_is_luhn_valid() {
+    assert!(is_luhn_valid("79927398713"));
+    assert!(!is_luhn_valid("79927398710"));
+    assert!(is_luhn_valid("4111-1111-1111-1111"));
+    assert!(is_luhn_valid("3782 822463 10005"));
+    assert!(!is_luhn_valid("4111 1111 1111 111a"));
+    assert!(!is_luhn_valid("0"));
+    assert!(is_luhn_valid("00"));
+}
+
+#[test]
+fn test_faker_dates() {
+    let mut faker = Faker::from_seed(6);
+    let start = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2000, 1, 31, 23, 59, 59).unwrap();
+    for _ in 0..100 {
+        let date = faker.date_between(&start, &end);
+        assert!(start <= date && date <= end);
+        let reversed = faker.date_between(&end, &start);
+        assert!(start <= reversed && reversed <= end);
+    }
+    assert_eq!(faker.date_between(&start, &start), start);
+
+    let now = Utc::now();
+    let past = faker.past_date(30);
+    assert!(past <= Utc::now() && past >= now - chrono::Duration::days(30));
+    let future = faker.future_date(30);
+    assert!(future >= now && future <= Utc::now() + chrono::Duration::days(30));
+}
+
+#[test]
+fn test_faker_dates_with_extreme_ranges() {
+    let mut faker = Faker::from_seed(11);
+    let (min, max) = (chrono::DateTime::<Utc>::MIN_UTC, chrono::DateTime::<Utc>::MAX_UTC);
+    for _ in 0..50 {
+        let date = faker.date_between(&min, &max);
+        assert!(min <= date && date <= max);
+        assert!(faker.past_date(i64::MAX) <= Utc::now());
+        assert!(faker.past_date(i64::MIN) <= Utc::now());
+        assert!(faker.future_date(i64::MAX) >= Utc::now() - chrono::Duration::seconds(1));
+        assert!(faker.future_date(400_000 * 365) >= Utc::now() - chrono::Duration::seconds(1));
+    }
+}
+
+#[test]
+fn test_faker_company() {
+    let mut faker = Faker::from_seed(7);
+    let companies: Vec<String> = (0..100).map(|_| faker.company()).collect();
+    assert!(companies.iter().any(|c| c.contains(" and ")));
+    assert!(companies.iter().any(|c| c.contains('-')));
+    assert!(companies.iter().all(|c| !c.is_empty()));
+}
@@ -1,5 +1,10 @@
 extern crate reddish;
 use reddish::{random_int, random_float, random_choice, shuffle, sample, random_string, uuid, random_bool, random_bool_with_probability};
+use reddish::{random_int_with_rng, random_float_with_rng, random_choice_with_rng, shuffle_with_rng, sample_with_rng, random_string_with_rng, uuid_with_rng, random_bool_with_rng, random_bool_with_probability_with_rng, Random};
+use reddish::{weighted_choice, weighted_choice_with_rng, weighted_sample, weighted_sample_with_rng, WeightedTable, sample_with_replacement, sample_with_replacement_with_rng, WeightedError};
+use reddish::{random_int_in, random_int_in_with_rng, random_normal_with_rng, random_log_normal_with_rng, random_exponential_with_rng, random_poisson_with_rng, random_binomial_with_rng, random_geometric_with_rng, random_zipf_with_rng, random_pareto_with_rng, random_normal, random_pareto, DistributionError, random_string_from, random_string_from_with_rng, random_token, Charset, RandomStringOptions, RandomStringError, AMBIGUOUS_CHARS, uuid_v7, uuid_v7_with_rng, uuid_timestamp, uuid_v5, UUID_NAMESPACE_DNS, UUID_NAMESPACE_URL, ulid, ulid_timestamp, UlidGenerator, nanoid, nanoid_custom, nanoid_custom_with_rng, SnowflakeGenerator, SnowflakeError, snowflake_timestamp, snowflake_worker_id, snowflake_sequence, random_passphrase, random_passphrase_with_rng, passphrase_entropy, random_name, random_name_with_rng, sample_iter, sample_iter_with_rng, random_choice_iter, random_choice_iter_with_rng, weighted_sample_iter, weighted_sample_iter_with_rng};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 
 #[test]
 fn test_random_int() {
@@ -314,3 +319,683 @@ fn test_sampling_without_replacement() {
         }
     }
 }
+
+#[test]
+fn test_with_rng_variants_are_reproducible() {
+    let mut a = ChaCha8Rng::seed_from_u64(2024);
+    let mut b = ChaCha8Rng::seed_from_u64(2024);
+
+    assert_eq!(random_int_with_rng(0, 1000, &mut a), random_int_with_rng(0, 1000, &mut b));
+    assert_eq!(random_float_with_rng(0.0, 1.0, &mut a), random_float_with_rng(0.0, 1.0, &mut b));
+    assert_eq!(random_bool_with_rng(&mut a), random_bool_with_rng(&mut b));
+    assert_eq!(random_bool_with_probability_with_rng(0.3, &mut a), random_bool_with_probability_with_rng(0.3, &mut b));
+    assert_eq!(random_string_with_rng(16, &mut a), random_string_with_rng(16, &mut b));
+    assert_eq!(uuid_with_rng(&mut a), uuid_with_rng(&mut b));
+
+    let items: Vec<i32> = (0..50).collect();
+    assert_eq!(random_choice_with_rng(&items, &mut a), random_choice_with_rng(&items, &mut b));
+    assert_eq!(sample_with_rng(&items, 10, &mut a), sample_with_rng(&items, 10, &mut b));
+
+    let mut first = items.clone();
+    let mut second = items.clone();
+    shuffle_with_rng(&mut first, &mut a);
+    shuffle_with_rng(&mut second, &mut b);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_with_rng_variants_respect_bounds() {
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    for _ in 0..100 {
+        let n = random_int_with_rng(-5, 5, &mut rng);
+        assert!((-5..=5).contains(&n));
+        let f = random_float_with_rng(1.0, 2.0, &mut rng);
+        assert!((1.0..2.0).contains(&f));
+    }
+    assert_eq!(random_int_with_rng(3, 3, &mut rng), 3);
+    assert!(!random_bool_with_probability_with_rng(0.0, &mut rng));
+    assert!(random_bool_with_probability_with_rng(1.0, &mut rng));
+    assert_eq!(random_choice_with_rng::<i32, _>(&[], &mut rng), None);
+    assert_eq!(sample_with_rng(&[1, 2, 3], 10, &mut rng).len(), 3);
+
+    let uuid = uuid_with_rng(&mut rng);
+    assert_eq!(uuid.len(), 36);
+    assert_eq!(&uuid[14..15], "4");
+}
+
+#[test]
+#[should_panic(expected = "min cannot be greater than max")]
+fn test_random_int_with_rng_invalid_range() {
+    random_int_with_rng(5, 1, &mut ChaCha8Rng::seed_from_u64(0));
+}
+
+#[test]
+fn test_random_from_seed_is_reproducible() {
+    let mut a = Random::from_seed(7);
+    let mut b = Random::from_seed(7);
+    let run = |r: &mut Random| {
+        let mut deck: Vec<u32> = (1..=52).collect();
+        r.shuffle(&mut deck);
+        (r.int(1, 6), r.float(0.0, 1.0), r.bool(), r.string(12), r.uuid(), deck, r.sample(&[1, 2, 3, 4], 2), r.choice(&["a", "b", "c"]).copied())
+    };
+    assert_eq!(run(&mut a), run(&mut b));
+
+    let mut c = Random::from_seed(8);
+    assert_ne!(run(&mut Random::from_seed(7)), run(&mut c));
+}
+
+#[test]
+fn test_random_matches_with_rng_functions() {
+    // A Random seeded with n draws exactly what the underlying ChaCha8 generator would
+    let mut random = Random::from_seed(99);
+    let mut rng = ChaCha8Rng::seed_from_u64(99);
+    assert_eq!(random.int(0, 1_000_000), random_int_with_rng(0, 1_000_000, &mut rng));
+    assert_eq!(random.string(20), random_string_with_rng(20, &mut rng));
+
+    // Random can itself be passed to the _with_rng functions
+    let mut a = Random::from_seed(5);
+    let mut b = Random::from_seed(5);
+    assert_eq!(random_int_with_rng(0, 100, &mut a), b.int(0, 100));
+}
+
+#[test]
+fn test_random_new_is_usable() {
+    let mut random = Random::default();
+    let n = random.int(1, 10);
+    assert!((1..=10).contains(&n));
+    assert!(random.bool_with_probability(1.0));
+    assert_eq!(random.string(5).len(), 5);
+}
+
+fn frequencies(counts: &[usize]) -> Vec<f64> {
+    let total: usize = counts.iter().sum();
+    counts.iter().map(|&c| c as f64 / total as f64).collect()
+}
+
+#[test]
+fn test_weighted_choice_distribution() {
+    let items = [0usize, 1, 2, 3];
+    let weights = [1.0, 2.0, 0.0, 7.0];
+    let mut rng = ChaCha8Rng::seed_from_u64(40);
+    let mut counts = [0usize; 4];
+    for _ in 0..20_000 {
+        counts[*weighted_choice_with_rng(&items, &weights, &mut rng).unwrap()] += 1;
+    }
+    let freq = frequencies(&counts);
+    assert!((freq[0] - 0.1).abs() < 0.02);
+    assert!((freq[1] - 0.2).abs() < 0.02);
+    assert_eq!(counts[2], 0);
+    assert!((freq[3] - 0.7).abs() < 0.02);
+}
+
+#[test]
+fn test_weighted_choice_errors() {
+    assert_eq!(weighted_choice::<i32>(&[], &[]), Err(WeightedError::Empty));
+    assert_eq!(weighted_choice(&[1, 2], &[1.0]), Err(WeightedError::LengthMismatch { items: 2, weights: 1 }));
+    assert_eq!(weighted_choice(&[1, 2], &[1.0, -1.0]), Err(WeightedError::InvalidWeight { index: 1, weight: -1.0 }));
+    assert!(matches!(weighted_choice(&[1, 2], &[f64::NAN, 1.0]), Err(WeightedError::InvalidWeight { index: 0, .. })));
+    assert!(matches!(weighted_choice(&[1], &[f64::INFINITY]), Err(WeightedError::InvalidWeight { index: 0, .. })));
+    assert_eq!(weighted_choice(&[1, 2], &[0.0, 0.0]), Err(WeightedError::AllZero));
+    assert!(matches!(weighted_choice(&[1, 2], &[f64::MAX, f64::MAX]), Err(WeightedError::InvalidWeight { .. })));
+    assert_eq!(WeightedError::AllZero.to_string(), "all weights are zero");
+}
+
+#[test]
+fn test_weighted_sample_without_replacement() {
+    let items: Vec<usize> = (0..5).collect();
+    let weights = [5.0, 4.0, 3.0, 2.0, 0.0];
+    let mut rng = ChaCha8Rng::seed_from_u64(41);
+
+    for _ in 0..200 {
+        let mut picked = weighted_sample_with_rng(&items, &weights, 3, &mut rng).unwrap();
+        assert_eq!(picked.len(), 3);
+        assert!(!picked.contains(&4));
+        picked.sort();
+        picked.dedup();
+        assert_eq!(picked.len(), 3);
+    }
+
+    // Only four items have positive weight
+    assert_eq!(weighted_sample(&items, &weights, 10).unwrap().len(), 4);
+    assert_eq!(weighted_sample(&items, &weights, 0).unwrap(), Vec::<usize>::new());
+    assert_eq!(weighted_sample(&items, &[1.0; 4], 1), Err(WeightedError::LengthMismatch { items: 5, weights: 4 }));
+}
+
+#[test]
+fn test_weighted_sample_favours_heavy_items() {
+    let items = ["heavy", "light"];
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    let mut heavy_first = 0;
+    for _ in 0..10_000 {
+        if weighted_sample_with_rng(&items, &[9.0, 1.0], 1, &mut rng).unwrap()[0] == "heavy" {
+            heavy_first += 1;
+        }
+    }
+    assert!((heavy_first as f64 / 10_000.0 - 0.9).abs() < 0.02);
+}
+
+#[test]
+fn test_weighted_table_distribution() {
+    let table = WeightedTable::new(vec!['a', 'b', 'c', 'd'], &[0.5, 0.25, 0.25, 0.0]).unwrap();
+    let mut rng = ChaCha8Rng::seed_from_u64(43);
+    let mut counts = [0usize; 4];
+    for _ in 0..40_000 {
+        counts[table.choose_index_with_rng(&mut rng)] += 1;
+    }
+    let freq = frequencies(&counts);
+    assert!((freq[0] - 0.5).abs() < 0.015);
+    assert!((freq[1] - 0.25).abs() < 0.015);
+    assert!((freq[2] - 0.25).abs() < 0.015);
+    assert_eq!(counts[3], 0);
+
+    assert_eq!(table.len(), 4);
+    assert!(!table.is_empty());
+    assert_eq!(table.items(), &['a', 'b', 'c', 'd']);
+    assert!(table.items().contains(table.choose()));
+}
+
+#[test]
+fn test_weighted_table_single_and_errors() {
+    let table = WeightedTable::new(vec!["only"], &[3.0]).unwrap();
+    assert_eq!(*table.choose(), "only");
+    assert!(WeightedTable::<i32>::new(vec![], &[]).is_err());
+    assert_eq!(WeightedTable::new(vec![1, 2], &[0.0, 0.0]).unwrap_err(), WeightedError::AllZero);
+}
+
+#[test]
+fn test_sample_with_replacement() {
+    let mut rng = ChaCha8Rng::seed_from_u64(44);
+    let draws = sample_with_replacement_with_rng(&[1, 2], 100, &mut rng).unwrap();
+    assert_eq!(draws.len(), 100);
+    assert!(draws.contains(&1) && draws.contains(&2));
+    assert_eq!(sample_with_replacement(&[7], 3).unwrap(), vec![7, 7, 7]);
+    assert_eq!(sample_with_replacement::<u8>(&[], 2), Err(WeightedError::Empty));
+}
+
+#[test]
+fn test_random_weighted_methods_are_reproducible() {
+    let mut a = Random::from_seed(3);
+    let mut b = Random::from_seed(3);
+    let items = ["x", "y", "z"];
+    let weights = [1.0, 2.0, 3.0];
+    assert_eq!(a.weighted_choice(&items, &weights), b.weighted_choice(&items, &weights));
+    assert_eq!(a.weighted_sample(&items, &weights, 2), b.weighted_sample(&items, &weights, 2));
+    assert_eq!(a.sample_with_replacement(&items, 5), b.sample_with_replacement(&items, 5));
+}
+
+fn mean_and_variance(samples: &[f64]) -> (f64, f64) {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    (mean, variance)
+}
+
+#[test]
+fn test_random_int_in_ranges() {
+    let mut rng = ChaCha8Rng::seed_from_u64(50);
+    for _ in 0..500 {
+        assert!((1..=6).contains(&random_int_in_with_rng(1..=6, &mut rng).unwrap()));
+        assert!((0..10u8).contains(&random_int_in_with_rng(0..10u8, &mut rng).unwrap()));
+        assert!(random_int_in_with_rng(250u8.., &mut rng).unwrap() >= 250);
+        assert!(random_int_in_with_rng(..-100i64, &mut rng).unwrap() < -100);
+        assert!(random_int_in_with_rng(..=3u16, &mut rng).unwrap() <= 3);
+    }
+    assert_eq!(random_int_in(7..8), Ok(7));
+    assert_eq!(random_int_in(u64::MAX..=u64::MAX), Ok(u64::MAX));
+    let _: i128 = random_int_in(..).unwrap();
+    let _: usize = random_int_in(..).unwrap();
+}
+
+#[test]
+fn test_random_int_in_covers_whole_range() {
+    let mut rng = ChaCha8Rng::seed_from_u64(51);
+    let mut seen = [false; 5];
+    for _ in 0..200 {
+        seen[random_int_in_with_rng(0..5usize, &mut rng).unwrap()] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn test_random_int_in_empty_ranges() {
+    assert_eq!(random_int_in(5..5), Err(DistributionError::EmptyRange));
+    assert_eq!(random_int_in(5..=4), Err(DistributionError::EmptyRange));
+    assert_eq!(random_int_in(..0u32), Err(DistributionError::EmptyRange));
+    assert_eq!(random_int_in(..i8::MIN), Err(DistributionError::EmptyRange));
+    assert_eq!(DistributionError::EmptyRange.to_string(), "range is empty");
+}
+
+#[test]
+fn test_continuous_distributions_moments() {
+    let mut rng = ChaCha8Rng::seed_from_u64(52);
+    let n = 20_000;
+
+    let normal: Vec<f64> = (0..n).map(|_| random_normal_with_rng(10.0, 2.0, &mut rng).unwrap()).collect();
+    let (mean, variance) = mean_and_variance(&normal);
+    assert!((mean - 10.0).abs() < 0.1);
+    assert!((variance - 4.0).abs() < 0.2);
+
+    let log_normal: Vec<f64> = (0..n).map(|_| random_log_normal_with_rng(0.0, 0.5, &mut rng).unwrap()).collect();
+    assert!(log_normal.iter().all(|&x| x > 0.0));
+    let logs: Vec<f64> = log_normal.iter().map(|x| x.ln()).collect();
+    let (mean, variance) = mean_and_variance(&logs);
+    assert!(mean.abs() < 0.02);
+    assert!((variance - 0.25).abs() < 0.02);
+
+    let exponential: Vec<f64> = (0..n).map(|_| random_exponential_with_rng(4.0, &mut rng).unwrap()).collect();
+    assert!((mean_and_variance(&exponential).0 - 0.25).abs() < 0.01);
+
+    // Pareto(scale 1, shape 3) has mean 1.5
+    let pareto: Vec<f64> = (0..n).map(|_| random_pareto_with_rng(1.0, 3.0, &mut rng).unwrap()).collect();
+    assert!(pareto.iter().all(|&x| x >= 1.0));
+    assert!((mean_and_variance(&pareto).0 - 1.5).abs() < 0.05);
+}
+
+#[test]
+fn test_discrete_distributions_moments() {
+    let mut rng = ChaCha8Rng::seed_from_u64(53);
+    let n = 20_000;
+    let as_f64 = |v: Vec<u64>| v.into_iter().map(|x| x as f64).collect::<Vec<f64>>();
+
+    let poisson = as_f64((0..n).map(|_| random_poisson_with_rng(3.0, &mut rng).unwrap()).collect());
+    let (mean, variance) = mean_and_variance(&poisson);
+    assert!((mean - 3.0).abs() < 0.06);
+    assert!((variance - 3.0).abs() < 0.15);
+
+    let binomial: Vec<u64> = (0..n).map(|_| random_binomial_with_rng(20, 0.3, &mut rng).unwrap()).collect();
+    assert!(binomial.iter().all(|&x| x <= 20));
+    assert!((mean_and_variance(&as_f64(binomial)).0 - 6.0).abs() < 0.06);
+
+    // Failures before the first success: mean (1 - p) / p
+    let geometric = as_f64((0..n).map(|_| random_geometric_with_rng(0.25, &mut rng).unwrap()).collect());
+    assert!((mean_and_variance(&geometric).0 - 3.0).abs() < 0.1);
+
+    let zipf: Vec<u64> = (0..n).map(|_| random_zipf_with_rng(10, 1.0, &mut rng).unwrap()).collect();
+    assert!(zipf.iter().all(|&k| (1..=10).contains(&k)));
+    let ones = zipf.iter().filter(|&&k| k == 1).count() as f64 / n as f64;
+    let twos = zipf.iter().filter(|&&k| k == 2).count() as f64 / n as f64;
+    // P(1) = 1 / H(10) ~ 0.341, and rank 1 is twice as likely as rank 2
+    assert!((ones - 0.341).abs() < 0.015);
+    assert!((ones / twos - 2.0).abs() < 0.15);
+}
+
+#[test]
+fn test_distribution_parameter_errors() {
+    let mut rng = ChaCha8Rng::seed_from_u64(54);
+    let invalid = |name: &'static str, value: f64| Err(DistributionError::InvalidParameter { name, value });
+
+    assert_eq!(random_normal_with_rng(0.0, -1.0, &mut rng), invalid("std_dev", -1.0));
+    assert!(matches!(random_normal(f64::NAN, 1.0), Err(DistributionError::InvalidParameter { name: "mean", .. })));
+    assert_eq!(random_log_normal_with_rng(0.0, -0.5, &mut rng), invalid("sigma", -0.5));
+    assert_eq!(random_exponential_with_rng(0.0, &mut rng), invalid("lambda", 0.0));
+    assert_eq!(random_poisson_with_rng(-2.0, &mut rng), Err(DistributionError::InvalidParameter { name: "lambda", value: -2.0 }));
+    assert_eq!(random_binomial_with_rng(5, -0.1, &mut rng), Err(DistributionError::InvalidParameter { name: "p", value: -0.1 }));
+    assert_eq!(random_geometric_with_rng(1.5, &mut rng), Err(DistributionError::InvalidParameter { name: "p", value: 1.5 }));
+    assert_eq!(random_zipf_with_rng(0, 1.0, &mut rng), Err(DistributionError::InvalidParameter { name: "n", value: 0.0 }));
+    assert_eq!(random_zipf_with_rng(5, -1.0, &mut rng), Err(DistributionError::InvalidParameter { name: "s", value: -1.0 }));
+    assert_eq!(random_pareto(1.0, 0.0), invalid("shape", 0.0));
+    assert!(matches!(random_pareto(f64::INFINITY, 1.0), Err(DistributionError::InvalidParameter { name: "scale", .. })));
+    assert_eq!(
+        DistributionError::InvalidParameter { name: "p", value: 1.5 }.to_string(),
+        "invalid distribution parameter p = 1.5"
+    );
+}
+
+#[test]
+fn test_random_distribution_methods_are_reproducible() {
+    let mut a = Random::from_seed(11);
+    let mut b = Random::from_seed(11);
+    assert_eq!(a.int_in(0..100u32), b.int_in(0..100u32));
+    assert_eq!(a.normal(0.0, 1.0), b.normal(0.0, 1.0));
+    assert_eq!(a.log_normal(0.0, 1.0), b.log_normal(0.0, 1.0));
+    assert_eq!(a.exponential(1.0), b.exponential(1.0));
+    assert_eq!(a.poisson(5.0), b.poisson(5.0));
+    assert_eq!(a.binomial(10, 0.5), b.binomial(10, 0.5));
+    assert_eq!(a.geometric(0.5), b.geometric(0.5));
+    assert_eq!(a.zipf(100, 1.2), b.zipf(100, 1.2));
+    assert_eq!(a.pareto(1.0, 2.0), b.pareto(1.0, 2.0));
+}
+
+#[test]
+fn test_charset_alphabets() {
+    assert_eq!(Charset::default(), Charset::Alphanumeric);
+    assert_eq!(Charset::Alphanumeric.chars().len(), 62);
+    assert_eq!(Charset::Alphabetic.chars().len(), 52);
+    assert_eq!(Charset::Digits.chars().iter().collect::<String>(), "0123456789");
+    assert_eq!(Charset::Hex.chars().iter().collect::<String>(), "0123456789abcdef");
+    assert_eq!(Charset::HexUpper.chars().iter().collect::<String>(), "0123456789ABCDEF");
+    assert_eq!(Charset::Base32.chars().iter().collect::<String>(), "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567");
+    assert_eq!(Charset::UrlSafe.chars().len(), 64);
+    assert_eq!(Charset::AlphanumericSymbols.chars().len(), 94);
+    assert_eq!(Charset::Custom("aabbc".to_string()).chars(), vec!['a', 'b', 'c']);
+}
+
+#[test]
+fn test_random_string_from_charsets() {
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+    for charset in [Charset::Digits, Charset::Hex, Charset::Base32, Charset::UrlSafe, Charset::Custom("xyz".to_string())] {
+        let alphabet = charset.chars();
+        let s = random_string_from_with_rng(200, &RandomStringOptions::new(charset), &mut rng).unwrap();
+        assert_eq!(s.chars().count(), 200);
+        assert!(s.chars().all(|c| alphabet.contains(&c)));
+    }
+
+    let unicode = RandomStringOptions::new(Charset::Custom("αβγ".to_string()));
+    let s = random_string_from_with_rng(10, &unicode, &mut rng).unwrap();
+    assert_eq!(s.chars().count(), 10);
+    assert_eq!(random_string_from(0, &unicode), Ok(String::new()));
+}
+
+#[test]
+fn test_random_string_from_excludes_ambiguous() {
+    let options = RandomStringOptions::new(Charset::Alphanumeric).with_exclude_ambiguous(true);
+    let s = random_string_from(1000, &options).unwrap();
+    assert!(s.chars().all(|c| !AMBIGUOUS_CHARS.contains(c)));
+
+    let only_ambiguous = RandomStringOptions::new(Charset::Custom("0O".to_string())).with_exclude_ambiguous(true);
+    assert_eq!(random_string_from(4, &only_ambiguous), Err(RandomStringError::EmptyCharset));
+}
+
+#[test]
+fn test_random_string_from_requires_each_class() {
+    let mut rng = ChaCha8Rng::seed_from_u64(9);
+    let options = RandomStringOptions::new(Charset::AlphanumericSymbols).with_require_each_class(true);
+    for _ in 0..200 {
+        let s = random_string_from_with_rng(4, &options, &mut rng).unwrap();
+        assert!(s.chars().any(|c| c.is_ascii_lowercase()));
+        assert!(s.chars().any(|c| c.is_ascii_uppercase()));
+        assert!(s.chars().any(|c| c.is_ascii_digit()));
+        assert!(s.chars().any(|c| c.is_ascii_punctuation()));
+    }
+
+    // Only the classes present in the alphabet are required
+    let hex = RandomStringOptions::new(Charset::Hex).with_require_each_class(true);
+    let s = random_string_from_with_rng(2, &hex, &mut rng).unwrap();
+    assert!(s.chars().any(|c| c.is_ascii_digit()));
+    assert!(s.chars().any(|c| c.is_ascii_lowercase()));
+
+    assert_eq!(
+        random_string_from(3, &options),
+        Err(RandomStringError::TooShort { length: 3, required: 4 })
+    );
+    assert_eq!(
+        RandomStringError::TooShort { length: 3, required: 4 }.to_string(),
+        "length 3 is too short to include 4 character classes"
+    );
+}
+
+#[test]
+fn test_random_string_from_secure_and_seeded() {
+    let options = RandomStringOptions::new(Charset::UrlSafe).with_secure(true);
+    let a = random_string_from(32, &options).unwrap();
+    let b = random_string_from(32, &options).unwrap();
+    assert_eq!(a.len(), 32);
+    assert_ne!(a, b);
+
+    let mut first = Random::from_seed(5);
+    let mut second = Random::from_seed(5);
+    assert_eq!(first.string_from(16, &options), second.string_from(16, &options));
+}
+
+#[test]
+fn test_random_token() {
+    let token = random_token(43);
+    assert_eq!(token.len(), 43);
+    assert!(token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+    assert_ne!(random_token(22), random_token(22));
+    assert_eq!(random_token(0), "");
+}
+
+#[test]
+fn test_uuid_v7_is_time_ordered() {
+    let before = chrono::Utc::now().timestamp_millis();
+    let id = uuid_v7();
+    let after = chrono::Utc::now().timestamp_millis();
+
+    assert_eq!(id.len(), 36);
+    assert_eq!(&id[14..15], "7");
+    assert!(matches!(&id[19..20], "8" | "9" | "a" | "b"));
+    let millis = uuid_timestamp(&id).unwrap().timestamp_millis();
+    assert!(before <= millis && millis <= after);
+
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    assert_ne!(uuid_v7_with_rng(&mut rng), uuid_v7_with_rng(&mut rng));
+    assert_eq!(Random::from_seed(1).uuid_v7()[14..15].to_string(), "7");
+}
+
+#[test]
+fn test_uuid_timestamp_rejects_other_versions() {
+    assert_eq!(uuid_timestamp(&uuid()), None);
+    assert_eq!(uuid_timestamp("not a uuid"), None);
+    assert_eq!(uuid_timestamp(&uuid_v5(UUID_NAMESPACE_DNS, "example.com").unwrap()), None);
+}
+
+#[test]
+fn test_uuid_v5_is_deterministic() {
+    let a = uuid_v5(UUID_NAMESPACE_URL, "https://example.com/").unwrap();
+    assert_eq!(a, uuid_v5(UUID_NAMESPACE_URL, "https://example.com/").unwrap());
+    assert_ne!(a, uuid_v5(UUID_NAMESPACE_DNS, "https://example.com/").unwrap());
+    assert_eq!(&a[14..15], "5");
+    assert_eq!(
+        uuid_v5(UUID_NAMESPACE_DNS, "www.example.com").unwrap(),
+        "2ed6657d-e927-568b-95e1-2665a8aea6a2"
+    );
+    assert_eq!(uuid_v5("6ba7b810", "www.example.com"), None);
+}
+
+#[test]
+fn test_ulid_format_and_monotonicity() {
+    let ids: Vec<String> = (0..1000).map(|_| ulid()).collect();
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(ids.iter().all(|id| id.len() == 26 && id.chars().all(|c| "0123456789ABCDEFGHJKMNPQRSTVWXYZ".contains(c))));
+
+    let mut generator = UlidGenerator::new();
+    let mut rng = ChaCha8Rng::seed_from_u64(4);
+    let a = generator.generate_with_rng(&mut rng);
+    let b = generator.generate_with_rng(&mut rng);
+    assert!(a < b);
+    // Both IDs were created within a few milliseconds of each other
+    let gap = ulid_timestamp(&b).unwrap() - ulid_timestamp(&a).unwrap();
+    assert!(gap.num_milliseconds() < 1000);
+}
+
+#[test]
+fn test_ulid_timestamp() {
+    let before = chrono::Utc::now().timestamp_millis();
+    let id = ulid();
+    let millis = ulid_timestamp(&id).unwrap().timestamp_millis();
+    assert!(millis >= before && millis <= chrono::Utc::now().timestamp_millis());
+
+    assert_eq!(ulid_timestamp("01arz3ndektsv4rrffq69g5fav"), ulid_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FAV"));
+    assert_eq!(ulid_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FA"), None);
+    assert_eq!(ulid_timestamp("81ARZ3NDEKTSV4RRFFQ69G5FAV"), None);
+    assert_eq!(ulid_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FAU"), None);
+}
+
+#[test]
+fn test_nanoid() {
+    let id = nanoid();
+    assert_eq!(id.len(), 21);
+    assert_ne!(id, nanoid());
+
+    let mut rng = ChaCha8Rng::seed_from_u64(2);
+    let custom = nanoid_custom_with_rng(8, "abc", &mut rng).unwrap();
+    assert_eq!(custom.len(), 8);
+    assert!(custom.chars().all(|c| "abc".contains(c)));
+    assert_eq!(nanoid_custom(5, "").unwrap_err(), RandomStringError::EmptyCharset);
+}
+
+#[test]
+fn test_snowflake_generator() {
+    let epoch = chrono::Utc::now() - chrono::Duration::days(365);
+    let mut generator = SnowflakeGenerator::new(epoch, 1023).unwrap();
+    assert_eq!(generator.epoch(), epoch);
+    assert_eq!(generator.worker_id(), 1023);
+
+    let before = chrono::Utc::now();
+    let ids: Vec<u64> = (0..10_000).map(|_| generator.next_id()).collect();
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(ids.iter().all(|&id| snowflake_worker_id(id) == 1023));
+    assert!(ids.iter().all(|&id| snowflake_sequence(id) <= 4095));
+
+    let time = generator.timestamp_of(ids[0]);
+    assert_eq!(time, snowflake_timestamp(ids[0], epoch));
+    assert!((time - before).num_milliseconds().abs() < 1000);
+}
+
+#[test]
+fn test_snowflake_generator_errors() {
+    let now = chrono::Utc::now();
+    assert_eq!(SnowflakeGenerator::new(now, 1024).unwrap_err(), SnowflakeError::InvalidWorkerId(1024));
+    assert_eq!(
+        SnowflakeGenerator::new(now + chrono::Duration::days(1), 0).unwrap_err(),
+        SnowflakeError::EpochInFuture
+    );
+
+    // The 41-bit timestamp covers about 69.7 years
+    let too_old = now - chrono::Duration::days(365 * 70);
+    assert_eq!(SnowflakeGenerator::new(too_old, 0).unwrap_err(), SnowflakeError::EpochTooOld);
+    let limit = chrono::Duration::milliseconds((1 << 41) - 1);
+    assert_eq!(
+        SnowflakeGenerator::new(now - limit - chrono::Duration::seconds(1), 0).unwrap_err(),
+        SnowflakeError::EpochTooOld
+    );
+    let mut oldest = SnowflakeGenerator::new(now - limit + chrono::Duration::minutes(1), 0).unwrap();
+    let a = oldest.next_id();
+    assert!(oldest.next_id() > a);
+    assert!(a >> 22 > (1 << 41) - 120_000);
+
+    assert_eq!(
+        SnowflakeError::InvalidWorkerId(2000).to_string(),
+        "worker id 2000 is out of range: it must be at most 1023"
+    );
+}
+
+#[test]
+fn test_random_passphrase() {
+    let phrase = random_passphrase(6, " ");
+    let words: Vec<&str> = phrase.split(' ').collect();
+    assert_eq!(words.len(), 6);
+    assert!(words.iter().all(|word| (3..=5).contains(&word.len())));
+    assert_eq!(random_passphrase(0, "+"), "");
+    assert_eq!(random_passphrase(1, "+").matches('+').count(), 0);
+
+    let mut a = ChaCha8Rng::seed_from_u64(8);
+    let mut b = ChaCha8Rng::seed_from_u64(8);
+    assert_eq!(random_passphrase_with_rng(4, ".", &mut a), random_passphrase_with_rng(4, ".", &mut b));
+    assert_eq!(Random::from_seed(3).passphrase(3, "_"), Random::from_seed(3).passphrase(3, "_"));
+}
+
+#[test]
+fn test_random_passphrase_uses_whole_wordlist() {
+    let mut rng = ChaCha8Rng::seed_from_u64(12);
+    let phrase = random_passphrase_with_rng(20_000, " ", &mut rng);
+    let distinct: std::collections::HashSet<&str> = phrase.split(' ').collect();
+    assert_eq!(distinct.len(), 1296);
+}
+
+#[test]
+fn test_passphrase_entropy() {
+    assert!((passphrase_entropy(1) - 1296f64.log2()).abs() < 1e-12);
+    assert!(passphrase_entropy(7) > 72.0);
+}
+
+#[test]
+fn test_random_name() {
+    let mut rng = ChaCha8Rng::seed_from_u64(6);
+    for _ in 0..100 {
+        let name = random_name_with_rng(&mut rng);
+        let parts: Vec<&str> = name.split('-').collect();
+        assert_eq!(parts.len(), 3);
+        assert!(parts[0].chars().all(|c| c.is_ascii_lowercase()));
+        assert!(parts[1].chars().all(|c| c.is_ascii_lowercase()));
+        let number: u32 = parts[2].parse().unwrap();
+        assert!((10..100).contains(&number));
+    }
+    assert_eq!(Random::from_seed(2).name(), Random::from_seed(2).name());
+    assert_ne!(random_name(), "");
+}
+
+#[test]
+fn test_sample_iter_sizes() {
+    assert_eq!(sample_iter(0..100, 0), Vec::<i32>::new());
+    assert_eq!(sample_iter(std::iter::empty::<i32>(), 3), Vec::<i32>::new());
+
+    let mut all = sample_iter(0..4, 10);
+    all.sort();
+    assert_eq!(all, vec![0, 1, 2, 3]);
+
+    let picked = sample_iter(0..10_000, 50);
+    assert_eq!(picked.len(), 50);
+    let distinct: std::collections::HashSet<_> = picked.iter().collect();
+    assert_eq!(distinct.len(), 50);
+    assert!(picked.iter().all(|n| (0..10_000).contains(n)));
+}
+
+#[test]
+fn test_sample_iter_is_uniform() {
+    let mut rng = ChaCha8Rng::seed_from_u64(21);
+    let mut counts = [0usize; 20];
+    for _ in 0..20_000 {
+        for n in sample_iter_with_rng(0..20usize, 5, &mut rng) {
+            counts[n] += 1;
+        }
+    }
+    // Every item is expected 5,000 times
+    assert!(counts.iter().all(|&c| (4_500..5_500).contains(&c)), "{:?}", counts);
+}
+
+#[test]
+fn test_random_choice_iter() {
+    assert_eq!(random_choice_iter(Vec::<u8>::new()), None);
+    assert_eq!(random_choice_iter(vec![7]), Some(7));
+
+    let mut rng = ChaCha8Rng::seed_from_u64(22);
+    let mut counts = [0usize; 4];
+    for _ in 0..8_000 {
+        counts[random_choice_iter_with_rng(0..4usize, &mut rng).unwrap()] += 1;
+    }
+    assert!(counts.iter().all(|&c| (1_800..2_200).contains(&c)), "{:?}", counts);
+
+    let text = "first\nsecond\nthird";
+    let line = random_choice_iter(text.lines()).unwrap();
+    assert!(["first", "second", "third"].contains(&line));
+
+    let mut a = Random::from_seed(4);
+    let mut b = Random::from_seed(4);
+    assert_eq!(a.choice_iter(0..1000), b.choice_iter(0..1000));
+    assert_eq!(a.sample_iter(0..1000, 3), b.sample_iter(0..1000, 3));
+}
+
+#[test]
+fn test_weighted_sample_iter() {
+    let mut rng = ChaCha8Rng::seed_from_u64(23);
+    let items = vec![("a", 1.0), ("b", 0.0), ("c", 3.0)];
+    let mut counts = std::collections::HashMap::new();
+    for _ in 0..4_000 {
+        let picked = weighted_sample_iter_with_rng(items.clone(), 1, &mut rng).unwrap();
+        *counts.entry(picked[0]).or_insert(0) += 1;
+    }
+    assert_eq!(counts.get("b"), None);
+    let c = counts["c"];
+    assert!((2_800..3_200).contains(&c), "{}", c);
+
+    let mut both = weighted_sample_iter(items.clone(), 5).unwrap();
+    both.sort();
+    assert_eq!(both, vec!["a", "c"]);
+    assert_eq!(weighted_sample_iter(items, 0).unwrap(), Vec::<&str>::new());
+    assert_eq!(weighted_sample_iter(Vec::<(u8, f64)>::new(), 2).unwrap(), Vec::<u8>::new());
+}
+
+#[test]
+fn test_weighted_sample_iter_rejects_invalid_weights() {
+    assert_eq!(
+        weighted_sample_iter(vec![(1, 1.0), (2, -1.0)], 1),
+        Err(WeightedError::InvalidWeight { index: 1, weight: -1.0 })
+    );
+    assert!(matches!(
+        weighted_sample_iter(vec![(1, f64::NAN)], 1),
+        Err(WeightedError::InvalidWeight { index: 0, .. })
+    ));
+}